@@ -1,35 +1,59 @@
+use std::collections::BTreeMap;
 use std::ops::Mul;
 
+use cosmwasm_schema::serde::{de::DeserializeOwned, Serialize};
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coin, from_binary, to_binary, Attribute, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut,
-    Env, MessageInfo, Order, Response, StdResult, Uint128, WasmMsg,
+    coin, from_binary, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps,
+    DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult, Storage, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
-use cw_storage_plus::Bound;
-use komple_framework_types::modules::fee::{Fees, FixedPayment, PercentagePayment};
+use cw721::{Cw721QueryMsg, TokensResponse};
+use cw_storage_plus::{Bound, Map};
+use komple_framework_metadata_module::helper::KompleMetadataModule;
+use komple_framework_types::modules::fee::{
+    DiscountConditions, DiscountRule, DistributionModes, Fees, FixedPayment, MintFees, PendingFee,
+    PercentagePayment, RemainderPolicies, RevenueShare, TieredPayment,
+};
 use komple_framework_types::modules::Modules;
 use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
 use komple_framework_utils::check_admin_privileges;
-use komple_framework_utils::funds::{check_single_amount, FundsError};
+use komple_framework_utils::funds::FundsError;
 use komple_framework_utils::response::{EventHelper, ResponseHelper};
 use komple_framework_utils::shared::{execute_lock_execute, execute_update_operators};
+use komple_framework_utils::storage::StorageHelper;
 
 use crate::error::ContractError;
 use crate::msg::{
-    CustomPaymentAddress, ExecuteMsg, FixedFeeResponse, PercentageFeeResponse, QueryMsg, ReceiveMsg,
+    ClaimableBalanceResponse, CustomPaymentAddress, DailyRevenueResponse, ExecuteMsg,
+    FixedFeeResponse, PendingFeeResponse, PendingFixedFeeDenomResponse, PercentageFeeResponse,
+    QueryMsg, ReceiveMsg, TieredFeeResponse,
 };
 use crate::state::{
-    Config, CONFIG, EXECUTE_LOCK, FIXED_FEES, HUB_ADDR, OPERATORS, PERCENTAGE_FEES,
+    ClaimableBalance, Config, PendingFixedFeeDenom, PendingNoticePeriod, CLAIMABLE_BALANCES,
+    CONFIG, DAILY_FEE_REVENUES, DAILY_RECIPIENT_REVENUES, DISCOUNTS, DISTRIBUTION_MODES, DUST,
+    EXECUTE_LOCK, EXEMPTIONS, FEE_REVENUES, FIXED_FEES, FIXED_FEE_DENOMS, HUB_ADDR, NOTICE_PERIODS,
+    OPERATORS, PENDING_FEES, PENDING_FIXED_FEE_DENOMS, PENDING_NOTICE_PERIODS, PERCENTAGE_FEES,
+    RECIPIENT_REVENUES, REMAINDER_CURSORS, REMAINDER_POLICIES, TIERED_FEES,
 };
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:komple-framework-fee-module";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DAY_IN_SECONDS: u64 = 86_400;
+
+const MAX_FEE_GROUP_DEPTH: u32 = 3;
+// Holdings checked for a discount rule are capped
+// to keep the distribution cost bounded
+const MAX_DISCOUNT_RULES: usize = 5;
+const MAX_DISCOUNT_TOKENS: u32 = 30;
+// Maximum number of shares in a revenue split
+const MAX_REVENUE_SHARES: usize = 10;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -80,19 +104,109 @@ pub fn execute(
             module_name,
             fee_name,
         } => execute_remove_fee(deps, env, info, fee_type, module_name, fee_name),
+        ExecuteMsg::SetFixedFeeDenom {
+            module_name,
+            fee_name,
+            denom,
+            value,
+        } => execute_set_fixed_fee_denom(deps, env, info, module_name, fee_name, denom, value),
+        ExecuteMsg::RemoveFixedFeeDenom {
+            module_name,
+            fee_name,
+            denom,
+        } => execute_remove_fixed_fee_denom(deps, env, info, module_name, fee_name, denom),
+        ExecuteMsg::SetDiscount {
+            module_name,
+            fee_name,
+            rules,
+        } => execute_set_discount(deps, env, info, module_name, fee_name, rules),
+        ExecuteMsg::RemoveDiscount {
+            module_name,
+            fee_name,
+        } => execute_remove_discount(deps, env, info, module_name, fee_name),
+        ExecuteMsg::AddExemptions {
+            module_name,
+            fee_name,
+            addrs,
+        } => execute_update_exemptions(deps, env, info, module_name, fee_name, addrs, true),
+        ExecuteMsg::RemoveExemptions {
+            module_name,
+            fee_name,
+            addrs,
+        } => execute_update_exemptions(deps, env, info, module_name, fee_name, addrs, false),
         ExecuteMsg::Distribute {
             fee_type,
             module_name,
             custom_payment_addresses,
-        } => execute_distribute(
-            deps,
-            env,
-            info,
-            fee_type,
+            discount_address,
+        } => {
+            let deposit = _deposit(&info, &None)?;
+            execute_distribute(
+                deps,
+                env,
+                deposit,
+                fee_type,
+                module_name,
+                custom_payment_addresses,
+                discount_address,
+            )
+        }
+        ExecuteMsg::DistributeTiered {
             module_name,
+            sale_amount,
             custom_payment_addresses,
-            None,
-        ),
+            discount_address,
+        } => {
+            let deposit = _deposit(&info, &None)?;
+            execute_distribute_tiered(
+                deps,
+                env,
+                deposit,
+                module_name,
+                sale_amount,
+                custom_payment_addresses,
+                discount_address,
+            )
+        }
+        ExecuteMsg::UpdateDistributionMode { module_name, mode } => {
+            execute_update_distribution_mode(deps, env, info, module_name, mode)
+        }
+        ExecuteMsg::Claim {} => execute_claim(deps, info),
+        ExecuteMsg::UpdateRemainderPolicy {
+            module_name,
+            policy,
+        } => execute_update_remainder_policy(deps, env, info, module_name, policy),
+        ExecuteMsg::SweepDust { recipient } => execute_sweep_dust(deps, env, info, recipient),
+        ExecuteMsg::SetRevenueSplit {
+            collection_id,
+            shares,
+        } => execute_set_revenue_split(deps, env, info, collection_id, shares),
+        ExecuteMsg::ScheduleFee {
+            fee_type,
+            module_name,
+            fee_name,
+            data,
+            activation_time,
+        } => {
+            let pending_fee = PendingFee {
+                fee_type,
+                data: Some(data),
+                activation_time,
+            };
+            execute_schedule_fee(deps, env, info, module_name, fee_name, pending_fee)
+        }
+        ExecuteMsg::CancelPendingFee {
+            fee_type,
+            module_name,
+            fee_name,
+        } => execute_cancel_pending_fee(deps, env, info, fee_type, module_name, fee_name),
+        ExecuteMsg::UpdateNoticePeriod {
+            module_name,
+            notice_period,
+        } => execute_update_notice_period(deps, env, info, module_name, notice_period),
+        ExecuteMsg::ApplyPendingFees { module_name } => {
+            execute_apply_pending_fees(deps, env, module_name)
+        }
         ExecuteMsg::UpdateOperators { addrs } => {
             let config = CONFIG.load(deps.storage)?;
             let res = execute_update_operators(
@@ -127,7 +241,7 @@ pub fn execute(
 }
 
 fn execute_set_fee(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     fee_type: Fees,
@@ -146,21 +260,195 @@ fn execute_set_fee(
         operators,
     )?;
 
-    let mut event_attributes: Vec<Attribute> = vec![];
+    // Due changes are saved first so that the new fee is checked against the active fees
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    _check_fee(deps.as_ref(), &fee_type, &module_name, &data)?;
+
+    // Fee changes are staged for modules with a notice period
+    let notice_period = NOTICE_PERIODS
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(0);
+    let pending_fee = PendingFee {
+        fee_type: fee_type.clone(),
+        data: Some(data.clone()),
+        activation_time: env.block.time.plus_seconds(notice_period),
+    };
+    if notice_period > 0 {
+        return _save_pending_fee(deps, module_name, fee_name, pending_fee);
+    }
+
+    // Staged changes of the fee are kept and applied after the new fee
+    let mut pending_fees = _pending_fees(deps.storage, &module_name)?;
+    pending_fees.push((fee_name.clone(), pending_fee));
+    _check_projected_fees(deps.storage, &module_name, pending_fees)?;
+
+    let event_attributes = _save_fee(deps.storage, &fee_type, &module_name, &fee_name, &data)?;
+
+    Ok(ResponseHelper::new_module("fee", "set_fee").add_event(
+        EventHelper::new("fee_set_fee")
+            .add_attribute("fee_type", fee_type.as_str())
+            .add_attribute("module_name", &module_name)
+            .add_attribute("fee_name", &fee_name)
+            .add_attributes(event_attributes)
+            .get(),
+    ))
+}
 
+/// Validates the payment configuration of a fee.
+///
+/// Total fees of the module are checked separately with the staged changes.
+fn _check_fee(
+    deps: Deps,
+    fee_type: &Fees,
+    module_name: &str,
+    data: &Binary,
+) -> Result<(), ContractError> {
     match fee_type {
         Fees::Fixed => {
-            let fixed_payment: FixedPayment = from_binary(&data)?;
+            let fixed_payment: FixedPayment = from_binary(data)?;
             if fixed_payment.value.is_zero() {
                 return Err(ContractError::InvalidFee {});
             };
 
-            if fixed_payment.address.is_some() {
-                deps.api
-                    .addr_validate(&fixed_payment.address.clone().unwrap())?;
+            _check_payment_recipient(
+                deps,
+                module_name,
+                fixed_payment.address,
+                fixed_payment.group,
+            )?;
+        }
+        Fees::Percentage => {
+            let percentage_payment: PercentagePayment = from_binary(data)?;
+            if percentage_payment.value > Decimal::one() {
+                return Err(ContractError::InvalidFee {});
+            };
+
+            _check_payment_recipient(
+                deps,
+                module_name,
+                percentage_payment.address,
+                percentage_payment.group,
+            )?;
+        }
+        Fees::Tiered => {
+            let tiered_payment: TieredPayment = from_binary(data)?;
+            check_tiered_payment(&tiered_payment)?;
+
+            if let Some(payment_address) = tiered_payment.address {
+                deps.api.addr_validate(&payment_address)?;
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks the total fees of a module after each of the changes.
+///
+/// Changes are applied on top of the saved fees in the order of their activation times,
+/// which is the order they are applied on the distributions.
+/// Total of the percentage fees and the tiered fees cannot reach 1 at any point.
+fn _check_projected_fees(
+    storage: &dyn Storage,
+    module_name: &str,
+    mut pending_fees: Vec<(String, PendingFee)>,
+) -> Result<(), ContractError> {
+    let mut percentages = PERCENTAGE_FEES
+        .prefix(module_name)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(fee_name, percentage_payment)| (fee_name, percentage_payment.value)))
+        .collect::<StdResult<BTreeMap<String, Decimal>>>()?;
+    let mut tiered_payments = TIERED_FEES
+        .prefix(module_name)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<String, TieredPayment>>>()?;
+
+    _sort_pending_fees(&mut pending_fees);
+    for (fee_name, pending_fee) in pending_fees {
+        match (pending_fee.fee_type, pending_fee.data) {
+            (Fees::Fixed, _) => continue,
+            (Fees::Percentage, Some(data)) => {
+                let percentage_payment: PercentagePayment = from_binary(&data)?;
+                percentages.insert(fee_name, percentage_payment.value);
+            }
+            (Fees::Percentage, None) => {
+                percentages.remove(&fee_name);
+            }
+            (Fees::Tiered, Some(data)) => {
+                tiered_payments.insert(fee_name, from_binary(&data)?);
+            }
+            (Fees::Tiered, None) => {
+                tiered_payments.remove(&fee_name);
             }
+        };
+        check_total_fee(
+            percentages.values().copied().sum(),
+            &tiered_payments
+                .values()
+                .cloned()
+                .collect::<Vec<TieredPayment>>(),
+        )?;
+    }
+
+    Ok(())
+}
 
-            FIXED_FEES.save(deps.storage, (&module_name, &fee_name), &fixed_payment)?;
+/// Gets the staged fee changes of a module in the order they are applied.
+fn _pending_fees(storage: &dyn Storage, module_name: &str) -> StdResult<Vec<(String, PendingFee)>> {
+    let mut pending_fees = PENDING_FEES
+        .sub_prefix(module_name)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|((_, fee_name), pending_fee)| (fee_name, pending_fee)))
+        .collect::<StdResult<Vec<(String, PendingFee)>>>()?;
+    _sort_pending_fees(&mut pending_fees);
+    Ok(pending_fees)
+}
+
+/// Sorts the staged fee changes by activation time, fee type and fee name.
+fn _sort_pending_fees(pending_fees: &mut [(String, PendingFee)]) {
+    pending_fees.sort_by(|(a_name, a), (b_name, b)| {
+        (a.activation_time, a.fee_type.as_str(), a_name).cmp(&(
+            b.activation_time,
+            b.fee_type.as_str(),
+            b_name,
+        ))
+    });
+}
+
+/// Validates the payment address or the fee group of a fee.
+///
+/// A fee can be paid to an address or a fee group but not both.
+fn _check_payment_recipient(
+    deps: Deps,
+    module_name: &str,
+    address: Option<String>,
+    group: Option<String>,
+) -> Result<(), ContractError> {
+    match (address, group) {
+        (Some(_), Some(_)) => return Err(ContractError::InvalidFeeGroup {}),
+        (Some(address), None) => {
+            deps.api.addr_validate(&address)?;
+        }
+        (None, Some(group)) => _check_fee_group(deps, module_name, &group)?,
+        (None, None) => {}
+    };
+    Ok(())
+}
+
+fn _save_fee(
+    storage: &mut dyn Storage,
+    fee_type: &Fees,
+    module_name: &str,
+    fee_name: &str,
+    data: &Binary,
+) -> Result<Vec<Attribute>, ContractError> {
+    let mut event_attributes: Vec<Attribute> = vec![];
+
+    match fee_type {
+        Fees::Fixed => {
+            let fixed_payment: FixedPayment = from_binary(data)?;
+            FIXED_FEES.save(storage, (module_name, fee_name), &fixed_payment)?;
 
             event_attributes.push(Attribute {
                 key: "value".to_string(),
@@ -172,36 +460,16 @@ fn execute_set_fee(
                     value: payment_address,
                 });
             }
+            if let Some(group) = fixed_payment.group {
+                event_attributes.push(Attribute {
+                    key: "group".to_string(),
+                    value: group,
+                });
+            }
         }
         Fees::Percentage => {
-            let percentage_payment: PercentagePayment = from_binary(&data)?;
-            if percentage_payment.value > Decimal::one() {
-                return Err(ContractError::InvalidFee {});
-            };
-
-            // Query total fee percentage for a given module
-            // Total fee decimal cannot be equal or higher than 1
-            // If we have an existing state, we need to subtract the current value before additions
-            let current_percentage_payment =
-                PERCENTAGE_FEES.may_load(deps.storage, (&module_name, &fee_name))?;
-            let current_percentage_payment_value = match current_percentage_payment {
-                Some(p) => p.value,
-                None => Decimal::zero(),
-            };
-            let total_fee =
-                query_total_percentage_fees(deps.as_ref(), module_name.clone(), None, None)?;
-            if total_fee.data - current_percentage_payment_value + percentage_payment.value
-                >= Decimal::one()
-            {
-                return Err(ContractError::InvalidTotalFee {});
-            };
-
-            if percentage_payment.address.is_some() {
-                deps.api
-                    .addr_validate(&percentage_payment.address.clone().unwrap())?;
-            };
-
-            PERCENTAGE_FEES.save(deps.storage, (&module_name, &fee_name), &percentage_payment)?;
+            let percentage_payment: PercentagePayment = from_binary(data)?;
+            PERCENTAGE_FEES.save(storage, (module_name, fee_name), &percentage_payment)?;
 
             event_attributes.push(Attribute {
                 key: "value".to_string(),
@@ -213,26 +481,52 @@ fn execute_set_fee(
                     value: payment_address,
                 });
             }
+            if let Some(group) = percentage_payment.group {
+                event_attributes.push(Attribute {
+                    key: "group".to_string(),
+                    value: group,
+                });
+            }
+        }
+        Fees::Tiered => {
+            let tiered_payment: TieredPayment = from_binary(data)?;
+            TIERED_FEES.save(storage, (module_name, fee_name), &tiered_payment)?;
+
+            event_attributes.push(Attribute {
+                key: "tiers".to_string(),
+                value: tiered_payment.tiers.len().to_string(),
+            });
+            if let Some(min) = tiered_payment.min {
+                event_attributes.push(Attribute {
+                    key: "min".to_string(),
+                    value: min.to_string(),
+                });
+            }
+            if let Some(max) = tiered_payment.max {
+                event_attributes.push(Attribute {
+                    key: "max".to_string(),
+                    value: max.to_string(),
+                });
+            }
+            if let Some(payment_address) = tiered_payment.address {
+                event_attributes.push(Attribute {
+                    key: "address".to_string(),
+                    value: payment_address,
+                });
+            }
         }
     }
 
-    Ok(ResponseHelper::new_module("fee", "set_fee").add_event(
-        EventHelper::new("fee_set_fee")
-            .add_attribute("fee_type", fee_type.as_str())
-            .add_attribute("module_name", &module_name)
-            .add_attribute("fee_name", &fee_name)
-            .add_attributes(event_attributes)
-            .get(),
-    ))
+    Ok(event_attributes)
 }
 
-fn execute_remove_fee(
-    deps: DepsMut,
+fn execute_schedule_fee(
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    fee_type: Fees,
     module_name: String,
     fee_name: String,
+    pending_fee: PendingFee,
 ) -> Result<Response, ContractError> {
     let hub_addr = HUB_ADDR.may_load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
@@ -245,252 +539,1863 @@ fn execute_remove_fee(
         operators,
     )?;
 
-    match fee_type {
-        Fees::Fixed => FIXED_FEES.remove(deps.storage, (&module_name, &fee_name)),
-        Fees::Percentage => PERCENTAGE_FEES.remove(deps.storage, (&module_name, &fee_name)),
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    let notice_period = NOTICE_PERIODS
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(0);
+    if pending_fee.activation_time < env.block.time.plus_seconds(notice_period) {
+        return Err(ContractError::InvalidActivationTime {});
     }
 
-    Ok(ResponseHelper::new_module("fee", "remove_fee").add_event(
-        EventHelper::new("fee_remove_fee")
-            .add_attribute("fee_type", fee_type.as_str())
+    if let Some(data) = &pending_fee.data {
+        _check_fee(deps.as_ref(), &pending_fee.fee_type, &module_name, data)?;
+    }
+
+    _save_pending_fee(deps, module_name, fee_name, pending_fee)
+}
+
+/// Saves a staged fee change after checking the totals with the other staged changes.
+///
+/// The current staged change of the fee is replaced.
+fn _save_pending_fee(
+    deps: DepsMut,
+    module_name: String,
+    fee_name: String,
+    pending_fee: PendingFee,
+) -> Result<Response, ContractError> {
+    let mut pending_fees = _pending_fees(deps.storage, &module_name)?;
+    pending_fees
+        .retain(|(name, item)| !(name == &fee_name && item.fee_type == pending_fee.fee_type));
+    pending_fees.push((fee_name.clone(), pending_fee.clone()));
+    _check_projected_fees(deps.storage, &module_name, pending_fees)?;
+
+    PENDING_FEES.save(
+        deps.storage,
+        (&module_name, pending_fee.fee_type.as_str(), &fee_name),
+        &pending_fee,
+    )?;
+
+    Ok(ResponseHelper::new_module("fee", "schedule_fee").add_event(
+        EventHelper::new("fee_schedule_fee")
+            .add_attribute("fee_type", pending_fee.fee_type.as_str())
             .add_attribute("module_name", &module_name)
             .add_attribute("fee_name", &fee_name)
+            .add_attribute("remove", pending_fee.data.is_none().to_string())
+            .add_attribute("activation_time", pending_fee.activation_time.to_string())
             .get(),
     ))
 }
 
-fn execute_distribute(
+fn execute_cancel_pending_fee(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     fee_type: Fees,
     module_name: String,
-    custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
-    cw20_token_amount: Option<Uint128>,
+    fee_name: String,
 ) -> Result<Response, ContractError> {
-    let msgs = match fee_type {
-        Fees::Fixed => _distribute_fixed_fee(
-            deps,
-            info,
-            &module_name,
-            custom_payment_addresses,
-            cw20_token_amount,
-        )?,
-        Fees::Percentage => _distribute_percentage_fee(
-            deps,
-            info,
-            &module_name,
-            custom_payment_addresses,
-            cw20_token_amount,
-        )?,
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    // Denom prices are only staged for fixed fees
+    let pending_denoms = match fee_type {
+        Fees::Fixed => PENDING_FIXED_FEE_DENOMS
+            .prefix((&module_name, &fee_name))
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?,
+        _ => vec![],
     };
+    let key = (module_name.as_str(), fee_type.as_str(), fee_name.as_str());
+    if !PENDING_FEES.has(deps.storage, key) && pending_denoms.is_empty() {
+        return Err(ContractError::PendingFeeNotFound {});
+    }
 
-    Ok(ResponseHelper::new_module("fee", "distribute")
-        .add_messages(msgs)
-        .add_event(
-            EventHelper::new("fee_distribute")
+    // Later changes cannot depend on the cancelled change
+    let mut pending_fees = _pending_fees(deps.storage, &module_name)?;
+    pending_fees.retain(|(name, item)| !(name == &fee_name && item.fee_type == fee_type));
+    _check_projected_fees(deps.storage, &module_name, pending_fees)?;
+
+    PENDING_FEES.remove(deps.storage, key);
+    for denom in pending_denoms {
+        PENDING_FIXED_FEE_DENOMS.remove(deps.storage, (&module_name, &fee_name, &denom));
+    }
+
+    Ok(
+        ResponseHelper::new_module("fee", "cancel_pending_fee").add_event(
+            EventHelper::new("fee_cancel_pending_fee")
                 .add_attribute("fee_type", fee_type.as_str())
                 .add_attribute("module_name", &module_name)
+                .add_attribute("fee_name", &fee_name)
                 .get(),
-        ))
+        ),
+    )
 }
 
-fn _distribute_fixed_fee(
-    deps: DepsMut,
+fn execute_update_notice_period(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    module_name: &str,
-    custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
-    cw20_token_amount: Option<Uint128>,
-) -> Result<Vec<CosmosMsg>, ContractError> {
-    let mut msgs: Vec<CosmosMsg> = vec![];
+    module_name: String,
+    notice_period: u64,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
 
-    // All of the available amounts to distribute fee
-    let amounts = FIXED_FEES
-        .prefix(&module_name)
-        .range(deps.storage, None, None, Order::Ascending)
-        .map(|item| {
-            let (fee_name, fixed_payment) = item.unwrap();
-            FixedFeeResponse {
-                module_name: module_name.to_string(),
-                fee_name,
-                address: fixed_payment.address,
-                value: fixed_payment.value,
-            }
-        })
-        .collect::<Vec<FixedFeeResponse>>();
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    // Reductions are staged behind the current notice period
+    // so that the fee changes cannot skip the notice
+    let current_notice_period = NOTICE_PERIODS
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(0);
+    let activation_time = match notice_period < current_notice_period {
+        true => {
+            let activation_time = env.block.time.plus_seconds(current_notice_period);
+            PENDING_NOTICE_PERIODS.save(
+                deps.storage,
+                &module_name,
+                &PendingNoticePeriod {
+                    notice_period,
+                    activation_time,
+                },
+            )?;
+            activation_time
+        }
+        false => {
+            NOTICE_PERIODS.save(deps.storage, &module_name, &notice_period)?;
+            PENDING_NOTICE_PERIODS.remove(deps.storage, &module_name);
+            env.block.time
+        }
+    };
 
-    if amounts.is_empty() {
-        return Err(ContractError::NoPaymentsFound {});
+    Ok(
+        ResponseHelper::new_module("fee", "update_notice_period").add_event(
+            EventHelper::new("fee_update_notice_period")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("notice_period", notice_period.to_string())
+                .add_attribute("activation_time", activation_time.to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_apply_pending_fees(
+    deps: DepsMut,
+    env: Env,
+    module_name: String,
+) -> Result<Response, ContractError> {
+    let event_attributes = _apply_pending_fees(deps, &env, &module_name)?;
+
+    Ok(
+        ResponseHelper::new_module("fee", "apply_pending_fees").add_event(
+            EventHelper::new("fee_apply_pending_fees")
+                .add_attribute("module_name", &module_name)
+                .add_attributes(event_attributes)
+                .get(),
+        ),
+    )
+}
+
+fn _apply_pending_fees(
+    deps: DepsMut,
+    env: &Env,
+    module_name: &str,
+) -> Result<Vec<Attribute>, ContractError> {
+    let mut event_attributes: Vec<Attribute> = vec![];
+
+    if let Some(pending_notice_period) =
+        PENDING_NOTICE_PERIODS.may_load(deps.storage, module_name)?
+    {
+        if pending_notice_period.activation_time <= env.block.time {
+            NOTICE_PERIODS.save(
+                deps.storage,
+                module_name,
+                &pending_notice_period.notice_period,
+            )?;
+            PENDING_NOTICE_PERIODS.remove(deps.storage, module_name);
+            event_attributes.push(Attribute::new(
+                "notice_period",
+                pending_notice_period.notice_period.to_string(),
+            ));
+        }
     }
 
-    // Total amount
-    let total_amount = amounts.iter().map(|item| item.value).sum::<Uint128>();
-    if cw20_token_amount.is_none() {
-        check_single_amount(&info, total_amount)?;
-    } else {
-        if cw20_token_amount.unwrap() != total_amount {
-            return Err(FundsError::InvalidFunds {
-                got: cw20_token_amount.unwrap().to_string(),
-                expected: total_amount.to_string(),
-            }
-            .into());
-        };
+    let due_fees = _pending_fees(deps.storage, module_name)?
+        .into_iter()
+        .filter(|(_, pending_fee)| pending_fee.activation_time <= env.block.time);
+
+    for (fee_name, pending_fee) in due_fees {
+        PENDING_FEES.remove(
+            deps.storage,
+            (module_name, pending_fee.fee_type.as_str(), &fee_name),
+        );
+
+        match pending_fee.data {
+            Some(ref data) => {
+                // Changes are checked when they are staged, but a change that
+                // no longer fits the fees is dropped instead of failing the distribution
+                let res = _check_fee(deps.as_ref(), &pending_fee.fee_type, module_name, data)
+                    .and_then(|_| {
+                        _check_projected_fees(
+                            deps.storage,
+                            module_name,
+                            vec![(fee_name.clone(), pending_fee.clone())],
+                        )
+                    });
+                if res.is_err() {
+                    event_attributes.push(Attribute::new("rejected", fee_name));
+                    continue;
+                }
+                _save_fee(
+                    deps.storage,
+                    &pending_fee.fee_type,
+                    module_name,
+                    &fee_name,
+                    data,
+                )?;
+                event_attributes.push(Attribute::new("applied", fee_name));
+            }
+            None => {
+                _remove_fee(deps.storage, &pending_fee.fee_type, module_name, &fee_name)?;
+                event_attributes.push(Attribute::new("removed", fee_name));
+            }
+        }
     }
 
-    // Make bank send messages based on fee percentage
-    for amount in amounts {
-        let mut is_custom_address = false;
-
-        // If we have some custom addresses find and replace the address
-        if let Some(custom_payment_addresses) = custom_payment_addresses.clone() {
-            // Find the correct custom address based on share name
-            let custom_payment_address = custom_payment_addresses
-                .iter()
-                .find(|item| amount.fee_name == item.fee_name);
-            if let Some(custom_payment_address) = custom_payment_address {
-                is_custom_address = true;
-                let msg = match cw20_token_amount.is_none() {
-                    true => CosmosMsg::Bank(BankMsg::Send {
-                        to_address: custom_payment_address.address.to_string(),
-                        amount: vec![coin(amount.value.u128(), info.funds[0].denom.clone())],
-                    }),
-                    false => CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: info.sender.to_string(),
-                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                            recipient: custom_payment_address.address.to_string(),
-                            amount: amount.value,
-                        })?,
-                        funds: vec![],
-                    }),
-                };
-                msgs.push(msg);
-            }
-        }
-
-        // Carry on without replacing the addresses
-        if !is_custom_address {
-            if let Some(payment_address) = amount.address {
-                let msg = match cw20_token_amount.is_none() {
-                    true => CosmosMsg::Bank(BankMsg::Send {
-                        to_address: payment_address.to_string(),
-                        amount: vec![coin(amount.value.u128(), info.funds[0].denom.clone())],
-                    }),
-                    false => CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: info.sender.to_string(),
-                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                            recipient: payment_address.to_string(),
-                            amount: amount.value,
-                        })?,
-                        funds: vec![],
-                    }),
-                };
-                msgs.push(msg);
-            };
+    let due_denoms = PENDING_FIXED_FEE_DENOMS
+        .sub_prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, pending_denom)) => pending_denom.activation_time <= env.block.time,
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<((String, String), PendingFixedFeeDenom)>>>()?;
+
+    for ((fee_name, denom), pending_denom) in due_denoms {
+        PENDING_FIXED_FEE_DENOMS.remove(deps.storage, (module_name, &fee_name, &denom));
+
+        match pending_denom.value {
+            Some(value) => {
+                // Prices of removed fees are dropped
+                if !FIXED_FEES.has(deps.storage, (module_name, &fee_name)) {
+                    continue;
+                }
+                FIXED_FEE_DENOMS.save(deps.storage, (module_name, &fee_name, &denom), &value)?;
+                event_attributes.push(Attribute::new(
+                    "applied_denom",
+                    format!("{}/{}", fee_name, denom),
+                ));
+            }
+            None => {
+                FIXED_FEE_DENOMS.remove(deps.storage, (module_name, &fee_name, &denom));
+                event_attributes.push(Attribute::new(
+                    "removed_denom",
+                    format!("{}/{}", fee_name, denom),
+                ));
+            }
         }
     }
 
-    Ok(msgs)
+    Ok(event_attributes)
 }
 
-fn _distribute_percentage_fee(
-    deps: DepsMut,
-    info: MessageInfo,
+/// Removes a fee together with its denom prices and their staged changes.
+fn _remove_fee(
+    storage: &mut dyn Storage,
+    fee_type: &Fees,
     module_name: &str,
-    custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
-    cw20_token_amount: Option<Uint128>,
-) -> Result<Vec<CosmosMsg>, ContractError> {
+    fee_name: &str,
+) -> StdResult<()> {
+    match fee_type {
+        Fees::Fixed => {
+            FIXED_FEES.remove(storage, (module_name, fee_name));
+
+            let denoms = FIXED_FEE_DENOMS
+                .prefix((module_name, fee_name))
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<String>>>()?;
+            for denom in denoms {
+                FIXED_FEE_DENOMS.remove(storage, (module_name, fee_name, &denom));
+            }
+            let pending_denoms = PENDING_FIXED_FEE_DENOMS
+                .prefix((module_name, fee_name))
+                .keys(storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<String>>>()?;
+            for denom in pending_denoms {
+                PENDING_FIXED_FEE_DENOMS.remove(storage, (module_name, fee_name, &denom));
+            }
+        }
+        Fees::Percentage => PERCENTAGE_FEES.remove(storage, (module_name, fee_name)),
+        Fees::Tiered => TIERED_FEES.remove(storage, (module_name, fee_name)),
+    };
+    Ok(())
+}
+
+fn execute_set_revenue_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    shares: Vec<RevenueShare>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+
+    // Mint Module of the hub updates the splits for the collection creators
+    let mint_module_addr = match &hub_addr {
+        Some(hub_addr) => {
+            StorageHelper::query_module_address(&deps.querier, hub_addr, Modules::Mint.to_string())
+                .ok()
+        }
+        None => None,
+    };
+    if mint_module_addr.as_ref() != Some(&info.sender) {
+        let config = CONFIG.load(deps.storage)?;
+        let operators = OPERATORS.may_load(deps.storage)?;
+        check_admin_privileges(
+            &info.sender,
+            &env.contract.address,
+            &config.admin,
+            hub_addr,
+            operators,
+        )?;
+    }
+
+    let module_name = MintFees::new_revenue(collection_id);
+
+    if shares.len() > MAX_REVENUE_SHARES {
+        return Err(ContractError::InvalidRevenueSplit {});
+    }
+    // Whole revenue must be split so that the shares are paid as they are set
+    let total_share = shares.iter().map(|share| share.share).sum::<Decimal>();
+    if !shares.is_empty() && total_share != Decimal::one() {
+        return Err(ContractError::InvalidRevenueSplit {});
+    }
+
+    let mut percentage_payments: BTreeMap<String, PercentagePayment> = BTreeMap::new();
+    for share in shares {
+        if share.share.is_zero() || (share.address.is_none() && share.group.is_none()) {
+            return Err(ContractError::InvalidRevenueSplit {});
+        }
+        _check_payment_recipient(
+            deps.as_ref(),
+            &module_name,
+            share.address.clone(),
+            share.group.clone(),
+        )?;
+
+        let percentage_payment = PercentagePayment {
+            address: share.address,
+            value: share.share,
+            group: share.group,
+        };
+        if percentage_payments
+            .insert(share.name, percentage_payment)
+            .is_some()
+        {
+            return Err(ContractError::InvalidRevenueSplit {});
+        }
+    }
+
+    let fee_names = PERCENTAGE_FEES
+        .prefix(&module_name)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for fee_name in fee_names {
+        PERCENTAGE_FEES.remove(deps.storage, (&module_name, &fee_name));
+    }
+    let pending_fee_names = PENDING_FEES
+        .prefix((&module_name, Fees::Percentage.as_str()))
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for fee_name in pending_fee_names {
+        PENDING_FEES.remove(
+            deps.storage,
+            (&module_name, Fees::Percentage.as_str(), &fee_name),
+        );
+    }
+
+    let shares_count = percentage_payments.len();
+    for (fee_name, percentage_payment) in percentage_payments {
+        PERCENTAGE_FEES.save(deps.storage, (&module_name, &fee_name), &percentage_payment)?;
+    }
+
+    Ok(
+        ResponseHelper::new_module("fee", "set_revenue_split").add_event(
+            EventHelper::new("fee_set_revenue_split")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("module_name", &module_name)
+                .add_attribute("shares", shares_count.to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_remove_fee(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fee_type: Fees,
+    module_name: String,
+    fee_name: String,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    // Removals are staged like the other fee changes
+    let notice_period = NOTICE_PERIODS
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(0);
+    if notice_period > 0 {
+        let pending_fee = PendingFee {
+            fee_type,
+            data: None,
+            activation_time: env.block.time.plus_seconds(notice_period),
+        };
+        return _save_pending_fee(deps, module_name, fee_name, pending_fee);
+    }
+
+    _remove_fee(deps.storage, &fee_type, &module_name, &fee_name)?;
+    PENDING_FEES.remove(deps.storage, (&module_name, fee_type.as_str(), &fee_name));
+
+    Ok(ResponseHelper::new_module("fee", "remove_fee").add_event(
+        EventHelper::new("fee_remove_fee")
+            .add_attribute("fee_type", fee_type.as_str())
+            .add_attribute("module_name", &module_name)
+            .add_attribute("fee_name", &fee_name)
+            .get(),
+    ))
+}
+
+fn execute_set_fixed_fee_denom(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    fee_name: String,
+    denom: String,
+    value: Uint128,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    if !FIXED_FEES.has(deps.storage, (&module_name, &fee_name)) {
+        return Err(ContractError::FeeNotFound {});
+    }
+    if value.is_zero() {
+        return Err(ContractError::InvalidFee {});
+    }
+
+    if let Some(res) = _stage_fixed_fee_denom(
+        deps.storage,
+        &env,
+        &module_name,
+        &fee_name,
+        &denom,
+        Some(value),
+    )? {
+        return Ok(res);
+    }
+    FIXED_FEE_DENOMS.save(deps.storage, (&module_name, &fee_name, &denom), &value)?;
+
+    Ok(
+        ResponseHelper::new_module("fee", "set_fixed_fee_denom").add_event(
+            EventHelper::new("fee_set_fixed_fee_denom")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("fee_name", &fee_name)
+                .add_attribute("denom", &denom)
+                .add_attribute("value", value.to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_remove_fixed_fee_denom(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    fee_name: String,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    if let Some(res) =
+        _stage_fixed_fee_denom(deps.storage, &env, &module_name, &fee_name, &denom, None)?
+    {
+        return Ok(res);
+    }
+    FIXED_FEE_DENOMS.remove(deps.storage, (&module_name, &fee_name, &denom));
+
+    Ok(
+        ResponseHelper::new_module("fee", "remove_fixed_fee_denom").add_event(
+            EventHelper::new("fee_remove_fixed_fee_denom")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("fee_name", &fee_name)
+                .add_attribute("denom", &denom)
+                .get(),
+        ),
+    )
+}
+
+/// Stages a denom price change for modules with a notice period.
+///
+/// Returns the response if the change is staged.
+fn _stage_fixed_fee_denom(
+    storage: &mut dyn Storage,
+    env: &Env,
+    module_name: &str,
+    fee_name: &str,
+    denom: &str,
+    value: Option<Uint128>,
+) -> StdResult<Option<Response>> {
+    let notice_period = NOTICE_PERIODS.may_load(storage, module_name)?.unwrap_or(0);
+    if notice_period == 0 {
+        return Ok(None);
+    }
+
+    let activation_time = env.block.time.plus_seconds(notice_period);
+    PENDING_FIXED_FEE_DENOMS.save(
+        storage,
+        (module_name, fee_name, denom),
+        &PendingFixedFeeDenom {
+            value,
+            activation_time,
+        },
+    )?;
+
+    Ok(Some(
+        ResponseHelper::new_module("fee", "schedule_fixed_fee_denom").add_event(
+            EventHelper::new("fee_schedule_fixed_fee_denom")
+                .add_attribute("module_name", module_name)
+                .add_attribute("fee_name", fee_name)
+                .add_attribute("denom", denom)
+                .check_add_attribute(&value, "value", value.unwrap_or_default().to_string())
+                .add_attribute("activation_time", activation_time.to_string())
+                .get(),
+        ),
+    ))
+}
+
+fn execute_set_discount(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    fee_name: String,
+    rules: Vec<DiscountRule>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    if rules.is_empty() || rules.len() > MAX_DISCOUNT_RULES {
+        return Err(ContractError::InvalidDiscount {});
+    }
+    let mut event_attributes: Vec<Attribute> = vec![];
+    for rule in rules.iter() {
+        if rule.value.is_zero() || rule.value > Decimal::one() {
+            return Err(ContractError::InvalidDiscount {});
+        }
+        if let DiscountConditions::MinTokens { amount } = rule.condition {
+            if amount == 0 || amount > MAX_DISCOUNT_TOKENS {
+                return Err(ContractError::InvalidDiscount {});
+            }
+        }
+        deps.api.addr_validate(&rule.collection_addr)?;
+
+        event_attributes.push(Attribute {
+            key: "rule".to_string(),
+            value: format!(
+                "{}/{}/{}",
+                rule.collection_addr,
+                rule.condition.as_str(),
+                rule.value
+            ),
+        });
+    }
+
+    DISCOUNTS.save(deps.storage, (&module_name, &fee_name), &rules)?;
+
+    Ok(ResponseHelper::new_module("fee", "set_discount").add_event(
+        EventHelper::new("fee_set_discount")
+            .add_attribute("module_name", &module_name)
+            .add_attribute("fee_name", &fee_name)
+            .add_attributes(event_attributes)
+            .get(),
+    ))
+}
+
+fn execute_remove_discount(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    fee_name: String,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    DISCOUNTS.remove(deps.storage, (&module_name, &fee_name));
+
+    Ok(
+        ResponseHelper::new_module("fee", "remove_discount").add_event(
+            EventHelper::new("fee_remove_discount")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("fee_name", &fee_name)
+                .get(),
+        ),
+    )
+}
+
+fn execute_update_exemptions(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    fee_name: String,
+    addrs: Vec<String>,
+    is_exempt: bool,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    let mut event_attributes: Vec<Attribute> = vec![];
+
+    for addr in addrs {
+        let addr = deps.api.addr_validate(&addr)?;
+        match is_exempt {
+            true => EXEMPTIONS.save(deps.storage, (&module_name, &fee_name, &addr), &true)?,
+            false => EXEMPTIONS.remove(deps.storage, (&module_name, &fee_name, &addr)),
+        };
+        event_attributes.push(Attribute {
+            key: "addrs".to_string(),
+            value: addr.to_string(),
+        });
+    }
+
+    let action = match is_exempt {
+        true => "add_exemptions",
+        false => "remove_exemptions",
+    };
+    Ok(ResponseHelper::new_module("fee", action).add_event(
+        EventHelper::new(format!("fee_{}", action))
+            .add_attribute("module_name", &module_name)
+            .add_attribute("fee_name", &fee_name)
+            .add_attributes(event_attributes)
+            .get(),
+    ))
+}
+
+fn execute_distribute(
+    mut deps: DepsMut,
+    env: Env,
+    deposit: Deposit,
+    fee_type: Fees,
+    module_name: String,
+    custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+    discount_address: Option<String>,
+) -> Result<Response, ContractError> {
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    let funds = deposit.funds;
+
+    let policy = REMAINDER_POLICIES
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(RemainderPolicies::Keep);
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut payments: Vec<Payment> = vec![];
+    let mut remainders: Vec<String> = vec![];
+
+    match fee_type {
+        // Fixed fees are paid with a single denom
+        Fees::Fixed => {
+            if funds.len() != 1 {
+                return Err(FundsError::MissingFunds {}.into());
+            }
+            let unpaid_amount;
+            (payments, unpaid_amount) = _distribute_fixed_fee(
+                deps.as_ref(),
+                &module_name,
+                &custom_payment_addresses,
+                &discount_address,
+                &funds[0],
+            )?;
+            if !unpaid_amount.is_zero() {
+                _add_dust(deps.storage, &funds[0], unpaid_amount)?;
+            }
+        }
+        // Every sent coin is split with the same percentages
+        Fees::Percentage => {
+            for fund in funds.iter() {
+                let (mut fund_payments, remainder, unpaid_amount) = _distribute_percentage_fee(
+                    deps.as_ref(),
+                    &module_name,
+                    &custom_payment_addresses,
+                    &discount_address,
+                    fund,
+                )?;
+                if !unpaid_amount.is_zero() {
+                    _add_dust(deps.storage, fund, unpaid_amount)?;
+                }
+                if !remainder.is_zero() {
+                    if let Some(msg) = _handle_remainder(
+                        deps.storage,
+                        &module_name,
+                        &policy,
+                        &mut fund_payments,
+                        fund,
+                        remainder,
+                        &deposit.refund_address,
+                    )? {
+                        msgs.push(msg);
+                    }
+                    remainders.push(format!("{}{}", remainder, fund.denom));
+                }
+                payments.append(&mut fund_payments);
+            }
+        }
+        Fees::Tiered => return Err(ContractError::MissingSaleAmount {}),
+    };
+
+    let (mode, mut payment_msgs) = _process_payments(deps, &env, &module_name, payments)?;
+    msgs.append(&mut payment_msgs);
+
+    let remainder = match remainders.is_empty() {
+        true => Uint128::zero().to_string(),
+        false => remainders.join(","),
+    };
+
+    Ok(ResponseHelper::new_module("fee", "distribute")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("fee_distribute")
+                .add_attribute("fee_type", fee_type.as_str())
+                .add_attribute("module_name", &module_name)
+                .check_add_attribute(
+                    &discount_address,
+                    "discount_address",
+                    discount_address.as_ref().unwrap_or(&String::from("")),
+                )
+                .add_attribute("distribution_mode", mode.as_str())
+                .add_attribute("remainder", remainder)
+                .add_attribute("remainder_policy", policy.as_str())
+                .get(),
+        ))
+}
+
+fn execute_distribute_tiered(
+    mut deps: DepsMut,
+    env: Env,
+    deposit: Deposit,
+    module_name: String,
+    sale_amount: Uint128,
+    custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+    discount_address: Option<String>,
+) -> Result<Response, ContractError> {
+    _apply_pending_fees(deps.branch(), &env, &module_name)?;
+
+    // Tiered fees are paid with a single denom
+    let funds = deposit.funds;
+    if funds.len() != 1 {
+        return Err(FundsError::MissingFunds {}.into());
+    }
+
+    let (payments, unpaid_amount) = _distribute_tiered_fee(
+        deps.as_ref(),
+        &module_name,
+        sale_amount,
+        &custom_payment_addresses,
+        &discount_address,
+        &funds[0],
+    )?;
+    if !unpaid_amount.is_zero() {
+        _add_dust(deps.storage, &funds[0], unpaid_amount)?;
+    }
+
+    let (mode, msgs) = _process_payments(deps, &env, &module_name, payments)?;
+
+    Ok(ResponseHelper::new_module("fee", "distribute")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("fee_distribute")
+                .add_attribute("fee_type", Fees::Tiered.as_str())
+                .add_attribute("module_name", &module_name)
+                .add_attribute("sale_amount", sale_amount.to_string())
+                .check_add_attribute(
+                    &discount_address,
+                    "discount_address",
+                    discount_address.as_ref().unwrap_or(&String::from("")),
+                )
+                .add_attribute("distribution_mode", mode.as_str())
+                .get(),
+        ))
+}
+
+/// Collects the funds sent for a distribution.
+///
+/// Native funds are keyed by denom and cw20 tokens by the token address.
+/// Remainders are refunded to the cw20 sender or the message sender.
+fn _deposit(
+    info: &MessageInfo,
+    cw20_receive_msg: &Option<Cw20ReceiveMsg>,
+) -> Result<Deposit, ContractError> {
+    let funds = match cw20_receive_msg {
+        Some(msg) => vec![Fund {
+            is_native: false,
+            denom: info.sender.to_string(),
+            amount: msg.amount,
+        }],
+        None => info
+            .funds
+            .iter()
+            .map(|coin| Fund {
+                is_native: true,
+                denom: coin.denom.to_string(),
+                amount: coin.amount,
+            })
+            .collect::<Vec<Fund>>(),
+    };
+    if funds.is_empty() {
+        return Err(FundsError::MissingFunds {}.into());
+    }
+    let refund_address = match cw20_receive_msg {
+        Some(msg) => msg.sender.to_string(),
+        None => info.sender.to_string(),
+    };
+    Ok(Deposit {
+        funds,
+        refund_address,
+    })
+}
+
+/// Sends or credits the payments based on the distribution mode of the module.
+fn _process_payments(
+    deps: DepsMut,
+    env: &Env,
+    module_name: &str,
+    payments: Vec<Payment>,
+) -> Result<(DistributionModes, Vec<CosmosMsg>), ContractError> {
     let mut msgs: Vec<CosmosMsg> = vec![];
 
-    // All of the available percentages to distribute fee
-    let percentages = PERCENTAGE_FEES
-        .prefix(&module_name)
+    let mut unpaid_payments: Vec<Payment> = vec![];
+    let payments = _split_fee_groups(deps.as_ref(), payments, 0, &mut unpaid_payments)?;
+    for payment in unpaid_payments {
+        let fund = Fund {
+            is_native: payment.is_native,
+            denom: payment.denom,
+            amount: payment.amount,
+        };
+        _add_dust(deps.storage, &fund, fund.amount)?;
+    }
+
+    let day = env.block.time.seconds() / DAY_IN_SECONDS;
+    for payment in payments.iter() {
+        if payment.amount.is_zero() {
+            continue;
+        }
+        let payment_address = deps.api.addr_validate(&payment.address)?;
+        _record_revenue(deps.storage, day, module_name, &payment_address, payment)?;
+    }
+
+    let mode = DISTRIBUTION_MODES
+        .may_load(deps.storage, module_name)?
+        .unwrap_or(DistributionModes::Push);
+
+    match mode {
+        DistributionModes::Push => {
+            for payment in payments {
+                // Fully discounted fees have nothing to send
+                if payment.amount.is_zero() {
+                    continue;
+                }
+                msgs.push(_payment_msg(
+                    payment.is_native,
+                    &payment.denom,
+                    &payment.address,
+                    payment.amount,
+                )?);
+            }
+        }
+        DistributionModes::Pull => {
+            for payment in payments {
+                if payment.amount.is_zero() {
+                    continue;
+                }
+                let payment_address = deps.api.addr_validate(&payment.address)?;
+                CLAIMABLE_BALANCES.update(
+                    deps.storage,
+                    (&payment_address, &payment.denom),
+                    |balance| -> StdResult<ClaimableBalance> {
+                        let current_amount = match balance {
+                            Some(balance) => balance.amount,
+                            None => Uint128::zero(),
+                        };
+                        Ok(ClaimableBalance {
+                            is_native: payment.is_native,
+                            amount: current_amount.checked_add(payment.amount)?,
+                        })
+                    },
+                )?;
+            }
+        }
+    };
+
+    Ok((mode, msgs))
+}
+
+/// Adds a payment to the revenue totals and the daily buckets.
+fn _record_revenue(
+    storage: &mut dyn Storage,
+    day: u64,
+    module_name: &str,
+    payment_address: &Addr,
+    payment: &Payment,
+) -> StdResult<()> {
+    let add_amount = |amount: Option<Uint128>| -> StdResult<Uint128> {
+        Ok(amount.unwrap_or_default().checked_add(payment.amount)?)
+    };
+    let add_coin = |coins: Option<Vec<Coin>>| -> StdResult<Vec<Coin>> {
+        let mut coins = coins.unwrap_or_default();
+        match coins.iter_mut().find(|c| c.denom == payment.denom) {
+            Some(c) => c.amount = c.amount.checked_add(payment.amount)?,
+            None => coins.push(coin(payment.amount.u128(), &payment.denom)),
+        };
+        Ok(coins)
+    };
+
+    FEE_REVENUES.update(
+        storage,
+        (module_name, &payment.fee_name, &payment.denom),
+        add_amount,
+    )?;
+    DAILY_FEE_REVENUES.update(storage, (module_name, &payment.fee_name, day), add_coin)?;
+    RECIPIENT_REVENUES.update(storage, (payment_address, &payment.denom), add_amount)?;
+    DAILY_RECIPIENT_REVENUES.update(storage, (payment_address, day), add_coin)?;
+    Ok(())
+}
+
+/// Funds sent for a distribution.
+///
+/// For cw20 tokens the token contract address is used as the denom.
+struct Fund {
+    is_native: bool,
+    denom: String,
+    amount: Uint128,
+}
+
+/// Funds of a distribution and the address for the refunds.
+struct Deposit {
+    funds: Vec<Fund>,
+    refund_address: String,
+}
+
+/// A single payment made in a distribution.
+///
+/// Payments to a fee group have an empty address until they are split.
+struct Payment {
+    fee_name: String,
+    address: String,
+    group: Option<String>,
+    is_native: bool,
+    denom: String,
+    amount: Uint128,
+}
+
+/// Applies the remainder policy to the payments of a single fund.
+///
+/// Returns the refund message if the remainder is sent back.
+fn _handle_remainder(
+    storage: &mut dyn Storage,
+    module_name: &str,
+    policy: &RemainderPolicies,
+    payments: &mut [Payment],
+    fund: &Fund,
+    remainder: Uint128,
+    refund_address: &str,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    match policy {
+        RemainderPolicies::Keep => _add_dust(storage, fund, remainder)?,
+        RemainderPolicies::FeeName { fee_name } => {
+            match payments.iter_mut().find(|item| &item.fee_name == fee_name) {
+                Some(payment) => payment.amount += remainder,
+                None => _add_dust(storage, fund, remainder)?,
+            }
+        }
+        RemainderPolicies::RoundRobin => {
+            if payments.is_empty() {
+                _add_dust(storage, fund, remainder)?;
+            } else {
+                // Remainder is lower than the number of fees, but fees without
+                // an address are skipped, so it can exceed the number of payments
+                // and the cursor wraps around to give some payments more than one unit
+                let cursor = REMAINDER_CURSORS
+                    .may_load(storage, module_name)?
+                    .unwrap_or(0) as usize;
+                let remainder = remainder.u128() as usize;
+                let payments_len = payments.len();
+                for index in cursor..cursor + remainder {
+                    payments[index % payments_len].amount += Uint128::one();
+                }
+                REMAINDER_CURSORS.save(
+                    storage,
+                    module_name,
+                    &(((cursor + remainder) % payments_len) as u32),
+                )?;
+            }
+        }
+        RemainderPolicies::Refund => {
+            return Ok(Some(_payment_msg(
+                fund.is_native,
+                &fund.denom,
+                refund_address,
+                remainder,
+            )?))
+        }
+    };
+    Ok(None)
+}
+
+fn _add_dust(storage: &mut dyn Storage, fund: &Fund, amount: Uint128) -> StdResult<()> {
+    DUST.update(
+        storage,
+        &fund.denom,
+        |dust| -> StdResult<ClaimableBalance> {
+            let current_amount = match dust {
+                Some(dust) => dust.amount,
+                None => Uint128::zero(),
+            };
+            Ok(ClaimableBalance {
+                is_native: fund.is_native,
+                amount: current_amount.checked_add(amount)?,
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// Finds the payment address for a fee.
+///
+/// Custom payment addresses take precedence over the saved address.
+fn _find_payment_address(
+    fee_name: &str,
+    address: Option<String>,
+    custom_payment_addresses: &Option<Vec<CustomPaymentAddress>>,
+) -> Option<String> {
+    if let Some(custom_payment_addresses) = custom_payment_addresses {
+        let custom_payment_address = custom_payment_addresses
+            .iter()
+            .find(|item| fee_name == item.fee_name);
+        if let Some(custom_payment_address) = custom_payment_address {
+            return Some(custom_payment_address.address.to_string());
+        }
+    }
+    address
+}
+
+/// Creates the payment of a fee if it has an address or a fee group.
+///
+/// Payment addresses take precedence over the fee group.
+fn _fee_payment(
+    fee_name: String,
+    payment_address: Option<String>,
+    group: Option<String>,
+    is_native: bool,
+    denom: &str,
+    amount: Uint128,
+) -> Option<Payment> {
+    let (address, group) = match (payment_address, group) {
+        (Some(address), _) => (address, None),
+        (None, Some(group)) => (String::new(), Some(group)),
+        (None, None) => return None,
+    };
+    Some(Payment {
+        fee_name,
+        address,
+        group,
+        is_native,
+        denom: denom.to_string(),
+        amount,
+    })
+}
+
+/// Splits the payments to fee groups between the percentage fees of the groups.
+///
+/// Split payments keep the fee name of the top level fee.
+/// Rounding remainder of a split is added to the first payment of the group.
+/// Shares of groups without fees and group fees without an address are kept as dust.
+fn _split_fee_groups(
+    deps: Deps,
+    payments: Vec<Payment>,
+    depth: u32,
+    unpaid_payments: &mut Vec<Payment>,
+) -> Result<Vec<Payment>, ContractError> {
+    let mut split_payments: Vec<Payment> = vec![];
+
+    for payment in payments {
+        let group = match payment.group.clone() {
+            Some(group) => group,
+            None => {
+                split_payments.push(payment);
+                continue;
+            }
+        };
+        if depth >= MAX_FEE_GROUP_DEPTH {
+            return Err(ContractError::MaxFeeGroupDepth {});
+        }
+
+        let percentages = PERCENTAGE_FEES
+            .prefix(&group)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| item.map(|(_, percentage_payment)| percentage_payment))
+            .collect::<StdResult<Vec<PercentagePayment>>>()?;
+        let total_fee = percentages.iter().map(|item| item.value).sum::<Decimal>();
+        if total_fee.is_zero() {
+            unpaid_payments.push(Payment {
+                group: None,
+                ..payment
+            });
+            continue;
+        }
+
+        let amounts = percentages
+            .iter()
+            .map(|percentage| {
+                payment
+                    .amount
+                    .multiply_ratio(percentage.value.atomics(), total_fee.atomics())
+            })
+            .collect::<Vec<Uint128>>();
+        let remainder = payment
+            .amount
+            .checked_sub(amounts.iter().copied().sum::<Uint128>())?;
+
+        let mut group_payments: Vec<Payment> = vec![];
+        for (index, (percentage, mut amount)) in percentages.into_iter().zip(amounts).enumerate() {
+            // Rounding remainder goes to the first fee of the group
+            if index == 0 {
+                amount += remainder;
+            }
+
+            // Custom payment addresses are not used inside fee groups
+            match _fee_payment(
+                payment.fee_name.clone(),
+                percentage.address,
+                percentage.group,
+                payment.is_native,
+                &payment.denom,
+                amount,
+            ) {
+                Some(group_payment) => group_payments.push(group_payment),
+                None => unpaid_payments.push(Payment {
+                    fee_name: payment.fee_name.clone(),
+                    address: String::new(),
+                    group: None,
+                    is_native: payment.is_native,
+                    denom: payment.denom.to_string(),
+                    amount,
+                }),
+            }
+        }
+
+        split_payments.append(&mut _split_fee_groups(
+            deps,
+            group_payments,
+            depth + 1,
+            unpaid_payments,
+        )?);
+    }
+
+    Ok(split_payments)
+}
+
+/// Checks the fee group of a fee for cycles and the maximum depth.
+///
+/// Depth is the longest chain of fee groups that goes through the fee.
+fn _check_fee_group(deps: Deps, module_name: &str, group: &str) -> Result<(), ContractError> {
+    let depth_above = _fee_group_depth_above(deps, module_name, 0)?;
+    let depth_below = _fee_group_depth_below(deps, module_name, group, 0)?;
+    if depth_above + 1 + depth_below > MAX_FEE_GROUP_DEPTH {
+        return Err(ContractError::MaxFeeGroupDepth {});
+    }
+    Ok(())
+}
+
+/// Returns the fee groups used by the fees of a module.
+fn _fee_groups(deps: Deps, module_name: &str) -> StdResult<Vec<String>> {
+    let mut groups = PERCENTAGE_FEES
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((_, percentage_payment)) => percentage_payment.group.map(Ok),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+    let mut fixed_groups = FIXED_FEES
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok((_, fixed_payment)) => fixed_payment.group.map(Ok),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+    groups.append(&mut fixed_groups);
+    Ok(groups)
+}
+
+/// Returns the longest chain of fee groups below a group.
+///
+/// Reaching the module of the new fee means the group would form a cycle.
+fn _fee_group_depth_below(
+    deps: Deps,
+    module_name: &str,
+    group: &str,
+    depth: u32,
+) -> Result<u32, ContractError> {
+    if group == module_name {
+        return Err(ContractError::FeeGroupCycle {});
+    }
+    if depth >= MAX_FEE_GROUP_DEPTH {
+        return Err(ContractError::MaxFeeGroupDepth {});
+    }
+
+    let mut depth_below = 0;
+    for sub_group in _fee_groups(deps, group)? {
+        let sub_depth = _fee_group_depth_below(deps, module_name, &sub_group, depth + 1)?;
+        depth_below = depth_below.max(sub_depth + 1);
+    }
+    Ok(depth_below)
+}
+
+/// Returns the longest chain of fee groups above a module.
+fn _fee_group_depth_above(deps: Deps, module_name: &str, depth: u32) -> Result<u32, ContractError> {
+    if depth >= MAX_FEE_GROUP_DEPTH {
+        return Err(ContractError::MaxFeeGroupDepth {});
+    }
+
+    let mut parents = PERCENTAGE_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok(((parent, _), percentage_payment)) => {
+                match percentage_payment.group.as_deref() == Some(module_name) {
+                    true => Some(Ok(parent)),
+                    false => None,
+                }
+            }
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+    let mut fixed_parents = FIXED_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|item| match item {
+            Ok(((parent, _), fixed_payment)) => {
+                match fixed_payment.group.as_deref() == Some(module_name) {
+                    true => Some(Ok(parent)),
+                    false => None,
+                }
+            }
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<StdResult<Vec<String>>>()?;
+    parents.append(&mut fixed_parents);
+
+    let mut depth_above = 0;
+    for parent in parents {
+        let parent_depth = _fee_group_depth_above(deps, &parent, depth + 1)?;
+        depth_above = depth_above.max(parent_depth + 1);
+    }
+    Ok(depth_above)
+}
+
+fn _payment_msg(
+    is_native: bool,
+    denom: &str,
+    payment_address: &str,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    let msg = match is_native {
+        true => CosmosMsg::Bank(BankMsg::Send {
+            to_address: payment_address.to_string(),
+            amount: vec![coin(amount.u128(), denom)],
+        }),
+        false => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: denom.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: payment_address.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    };
+    Ok(msg)
+}
+
+fn _distribute_fixed_fee(
+    deps: Deps,
+    module_name: &str,
+    custom_payment_addresses: &Option<Vec<CustomPaymentAddress>>,
+    discount_address: &Option<String>,
+    fund: &Fund,
+) -> Result<(Vec<Payment>, Uint128), ContractError> {
+    let mut payments: Vec<Payment> = vec![];
+    let mut unpaid_amount = Uint128::zero();
+
+    // All of the available amounts to distribute fee
+    // Denom prices take precedence over the default value
+    let amounts = FIXED_FEES
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (fee_name, fixed_payment) = item?;
+            let mut value = FIXED_FEE_DENOMS
+                .may_load(deps.storage, (module_name, &fee_name, &fund.denom))?
+                .unwrap_or(fixed_payment.value);
+            if let Some(discount_address) = discount_address {
+                let discount = _discount(deps, module_name, &fee_name, discount_address)?;
+                value = value.mul(Decimal::one() - discount);
+            }
+            Ok(FixedFeeResponse {
+                module_name: module_name.to_string(),
+                fee_name,
+                address: fixed_payment.address,
+                value,
+                group: fixed_payment.group,
+            })
+        })
+        .collect::<StdResult<Vec<FixedFeeResponse>>>()?;
+
+    if amounts.is_empty() {
+        return Err(ContractError::NoPaymentsFound {});
+    }
+
+    // Total amount
+    let total_amount = amounts.iter().map(|item| item.value).sum::<Uint128>();
+    if fund.amount != total_amount {
+        return Err(FundsError::InvalidFunds {
+            got: fund.amount.to_string(),
+            expected: total_amount.to_string(),
+        }
+        .into());
+    };
+
+    // Collect the payments for each address or fee group
+    for amount in amounts {
+        let payment_address =
+            _find_payment_address(&amount.fee_name, amount.address, custom_payment_addresses);
+        match _fee_payment(
+            amount.fee_name,
+            payment_address,
+            amount.group,
+            fund.is_native,
+            &fund.denom,
+            amount.value,
+        ) {
+            Some(payment) => payments.push(payment),
+            // Fees without an address or a group are kept as dust
+            None => unpaid_amount += amount.value,
+        };
+    }
+
+    Ok((payments, unpaid_amount))
+}
+
+fn _distribute_percentage_fee(
+    deps: Deps,
+    module_name: &str,
+    custom_payment_addresses: &Option<Vec<CustomPaymentAddress>>,
+    discount_address: &Option<String>,
+    fund: &Fund,
+) -> Result<(Vec<Payment>, Uint128, Uint128), ContractError> {
+    let mut payments: Vec<Payment> = vec![];
+    let mut unpaid_amount = Uint128::zero();
+
+    // All of the available percentages to distribute fee
+    let percentages = PERCENTAGE_FEES
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (fee_name, percentage_payment) = item?;
+            let mut value = percentage_payment.value;
+            if let Some(discount_address) = discount_address {
+                let discount = _discount(deps, module_name, &fee_name, discount_address)?;
+                value *= Decimal::one() - discount;
+            }
+            Ok(PercentageFeeResponse {
+                module_name: module_name.to_string(),
+                fee_name,
+                address: percentage_payment.address,
+                value,
+                group: percentage_payment.group,
+            })
+        })
+        .collect::<StdResult<Vec<PercentageFeeResponse>>>()?;
+
+    if percentages.is_empty() {
+        return Err(ContractError::NoPaymentsFound {});
+    }
+
+    // Total amount of fee percentage
+    let total_fee = percentages.iter().map(|item| item.value).sum::<Decimal>();
+    if total_fee.is_zero() {
+        return Err(ContractError::NoPaymentsFound {});
+    }
+
+    let mut distributed_amount = Uint128::zero();
+
+    // Collect the payments for each address based on fee percentage
+    for percentage in percentages {
+        // Payment amount is total_funds * percentage / total_fee
+        let payment_amount = fund
+            .amount
+            .multiply_ratio(percentage.value.atomics(), total_fee.atomics());
+
+        distributed_amount += payment_amount;
+
+        let payment_address = _find_payment_address(
+            &percentage.fee_name,
+            percentage.address,
+            custom_payment_addresses,
+        );
+        match _fee_payment(
+            percentage.fee_name,
+            payment_address,
+            percentage.group,
+            fund.is_native,
+            &fund.denom,
+            payment_amount,
+        ) {
+            Some(payment) => payments.push(payment),
+            // Fees without an address or a group are kept as dust
+            None => unpaid_amount += payment_amount,
+        };
+    }
+
+    // Remainder left from the integer division
+    let remainder = fund.amount.checked_sub(distributed_amount)?;
+
+    Ok((payments, remainder, unpaid_amount))
+}
+
+fn _distribute_tiered_fee(
+    deps: Deps,
+    module_name: &str,
+    sale_amount: Uint128,
+    custom_payment_addresses: &Option<Vec<CustomPaymentAddress>>,
+    discount_address: &Option<String>,
+    fund: &Fund,
+) -> Result<(Vec<Payment>, Uint128), ContractError> {
+    let mut payments: Vec<Payment> = vec![];
+    let mut unpaid_amount = Uint128::zero();
+
+    // All of the available tiered fees with the amounts for the sale amount
+    let amounts = TIERED_FEES
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (fee_name, tiered_payment) = item?;
+            let mut amount = tiered_payment.amount(sale_amount);
+            if let Some(discount_address) = discount_address {
+                let discount = _discount(deps, module_name, &fee_name, discount_address)?;
+                amount = amount.mul(Decimal::one() - discount);
+            }
+            Ok((fee_name, tiered_payment.address, amount))
+        })
+        .collect::<StdResult<Vec<(String, Option<String>, Uint128)>>>()?;
+
+    if amounts.is_empty() {
+        return Err(ContractError::NoPaymentsFound {});
+    }
+
+    // Total amount
+    let total_amount = amounts.iter().map(|item| item.2).sum::<Uint128>();
+    if fund.amount != total_amount {
+        return Err(FundsError::InvalidFunds {
+            got: fund.amount.to_string(),
+            expected: total_amount.to_string(),
+        }
+        .into());
+    };
+
+    // Collect the payments for each address
+    for (fee_name, address, amount) in amounts {
+        if amount.is_zero() {
+            continue;
+        }
+        let payment_address = _find_payment_address(&fee_name, address, custom_payment_addresses);
+        match payment_address {
+            Some(payment_address) => payments.push(Payment {
+                fee_name,
+                address: payment_address,
+                group: None,
+                is_native: fund.is_native,
+                denom: fund.denom.to_string(),
+                amount,
+            }),
+            // Fees without an address are kept as dust
+            None => unpaid_amount += amount,
+        };
+    }
+
+    Ok((payments, unpaid_amount))
+}
+
+/// Finds the highest discount of the address for a fee.
+///
+/// Exempt addresses get the full discount.
+fn _discount(deps: Deps, module_name: &str, fee_name: &str, address: &str) -> StdResult<Decimal> {
+    let addr = deps.api.addr_validate(address)?;
+    if EXEMPTIONS.has(deps.storage, (module_name, fee_name, &addr)) {
+        return Ok(Decimal::one());
+    }
+
+    let rules = DISCOUNTS
+        .may_load(deps.storage, (module_name, fee_name))?
+        .unwrap_or_default();
+
+    let mut discount = Decimal::zero();
+    for rule in rules {
+        if rule.value > discount && _is_eligible(deps, &rule, address)? {
+            discount = rule.value;
+        }
+    }
+    Ok(discount)
+}
+
+/// Checks the holdings of the address against the discount rule.
+///
+/// Holdings are read from the collection with the cw721 tokens query.
+/// Only the first `MAX_DISCOUNT_TOKENS` tokens of the address are checked.
+fn _is_eligible(deps: Deps, rule: &DiscountRule, address: &str) -> StdResult<bool> {
+    let collection_addr = deps.api.addr_validate(&rule.collection_addr)?;
+
+    let res: TokensResponse = deps.querier.query_wasm_smart(
+        &collection_addr,
+        &Cw721QueryMsg::Tokens {
+            owner: address.to_string(),
+            start_after: None,
+            limit: Some(MAX_DISCOUNT_TOKENS),
+        },
+    )?;
+
+    match &rule.condition {
+        DiscountConditions::MinTokens { amount } => Ok(res.tokens.len() as u32 >= *amount),
+        DiscountConditions::Trait { trait_type, value } => {
+            if res.tokens.is_empty() {
+                return Ok(false);
+            }
+            let metadata_module =
+                match StorageHelper::query_token_sub_modules(&deps.querier, &collection_addr)?
+                    .metadata
+                {
+                    Some(metadata_addr) => KompleMetadataModule(metadata_addr),
+                    None => return Ok(false),
+                };
+            for token_id in res.tokens.iter() {
+                let token_id = match token_id.parse::<u32>() {
+                    Ok(token_id) => token_id,
+                    Err(_) => continue,
+                };
+                // Tokens without metadata do not match the trait
+                let metadata = metadata_module.query_metadata(&deps.querier, token_id);
+                if let Ok(metadata) = metadata {
+                    if metadata
+                        .metadata
+                        .attributes
+                        .iter()
+                        .any(|attr| &attr.trait_type == trait_type && &attr.value == value)
+                    {
+                        return Ok(true);
+                    }
+                }
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// Checks that the tiers are sorted, start from zero and the caps are valid.
+fn check_tiered_payment(tiered_payment: &TieredPayment) -> Result<(), ContractError> {
+    if tiered_payment.tiers.is_empty() || !tiered_payment.tiers[0].min_amount.is_zero() {
+        return Err(ContractError::InvalidTiers {});
+    }
+    for tier in tiered_payment.tiers.iter() {
+        if tier.value > Decimal::one() {
+            return Err(ContractError::InvalidFee {});
+        }
+    }
+    for tiers in tiered_payment.tiers.windows(2) {
+        if tiers[0].min_amount >= tiers[1].min_amount {
+            return Err(ContractError::InvalidTiers {});
+        }
+    }
+    if let (Some(min), Some(max)) = (tiered_payment.min, tiered_payment.max) {
+        if min > max {
+            return Err(ContractError::InvalidTiers {});
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the total of the percentage fees and the tiered fees
+/// stays below 1 for every sale amount.
+///
+/// Tier values only change at the tier boundaries,
+/// so checking every boundary covers all the sale amounts.
+fn check_total_fee(
+    total_percentage: Decimal,
+    tiered_payments: &[TieredPayment],
+) -> Result<(), ContractError> {
+    let mut boundaries = tiered_payments
+        .iter()
+        .flat_map(|tiered_payment| tiered_payment.tiers.iter().map(|tier| tier.min_amount))
+        .collect::<Vec<Uint128>>();
+    if boundaries.is_empty() {
+        boundaries.push(Uint128::zero());
+    }
+
+    for boundary in boundaries {
+        let total_fee = tiered_payments
+            .iter()
+            .filter_map(|tiered_payment| tiered_payment.tier(boundary))
+            .map(|tier| tier.value)
+            .sum::<Decimal>()
+            + total_percentage;
+        if total_fee >= Decimal::one() {
+            return Err(ContractError::InvalidTotalFee {});
+        }
+    }
+    Ok(())
+}
+
+fn execute_update_distribution_mode(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    mode: DistributionModes,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    DISTRIBUTION_MODES.save(deps.storage, &module_name, &mode)?;
+
+    Ok(
+        ResponseHelper::new_module("fee", "update_distribution_mode").add_event(
+            EventHelper::new("fee_update_distribution_mode")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("mode", mode.as_str())
+                .get(),
+        ),
+    )
+}
+
+fn execute_claim(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let balances = CLAIMABLE_BALANCES
+        .prefix(&info.sender)
         .range(deps.storage, None, None, Order::Ascending)
-        .map(|item| {
-            let (fee_name, percentage_payment) = item.unwrap();
-            PercentageFeeResponse {
-                module_name: module_name.to_string(),
-                fee_name,
-                address: percentage_payment.address,
-                value: percentage_payment.value,
-            }
-        })
-        .collect::<Vec<PercentageFeeResponse>>();
+        .collect::<StdResult<Vec<(String, ClaimableBalance)>>>()?;
 
-    if percentages.is_empty() {
-        return Err(ContractError::NoPaymentsFound {});
+    if balances.is_empty() {
+        return Err(ContractError::NoClaimableBalance {});
     }
 
-    // Total amount of fee percentage
-    let total_fee = percentages
-        .iter()
-        .map(|item| item.value)
-        .sum::<Decimal>()
-        .mul(Uint128::new(100));
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut event_attributes: Vec<Attribute> = vec![];
 
-    // Make bank send messages based on fee percentage
-    for percentage in percentages {
-        let mut is_custom_address = false;
+    for (denom, balance) in balances {
+        CLAIMABLE_BALANCES.remove(deps.storage, (&info.sender, &denom));
+
+        msgs.push(_payment_msg(
+            balance.is_native,
+            &denom,
+            info.sender.as_str(),
+            balance.amount,
+        )?);
+        event_attributes.push(Attribute {
+            key: "amount".to_string(),
+            value: format!("{}{}", balance.amount, denom),
+        });
+    }
 
-        // Payment amount is total_funds * percentage / total_fee
-        let payment_amount = match cw20_token_amount {
-            Some(amount) => amount
-                .mul(percentage.value.mul(Uint128::new(100)))
-                .checked_div(total_fee)?,
-            None => info.funds[0]
-                .amount
-                .mul(percentage.value.mul(Uint128::new(100)))
-                .checked_div(total_fee)?,
-        };
+    Ok(ResponseHelper::new_module("fee", "claim")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("fee_claim")
+                .add_attribute("recipient", info.sender)
+                .add_attributes(event_attributes)
+                .get(),
+        ))
+}
 
-        // If we have some custom addresses find and replace the address
-        if let Some(custom_payment_addresses) = custom_payment_addresses.clone() {
-            // Find the correct custom address based on share name
-            let custom_payment_address = custom_payment_addresses
-                .iter()
-                .find(|item| percentage.fee_name == item.fee_name);
-            if let Some(custom_payment_address) = custom_payment_address {
-                is_custom_address = true;
-                let msg = match cw20_token_amount.is_none() {
-                    true => CosmosMsg::Bank(BankMsg::Send {
-                        to_address: custom_payment_address.address.to_string(),
-                        amount: vec![coin(payment_amount.u128(), info.funds[0].denom.clone())],
-                    }),
-                    false => CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: info.sender.to_string(),
-                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                            recipient: custom_payment_address.address.to_string(),
-                            amount: payment_amount,
-                        })?,
-                        funds: vec![],
-                    }),
-                };
-                msgs.push(msg);
-            }
-        }
-
-        // Carry on without replacing the addresses
-        if !is_custom_address {
-            if let Some(payment_address) = percentage.address {
-                let msg = match cw20_token_amount.is_none() {
-                    true => CosmosMsg::Bank(BankMsg::Send {
-                        to_address: payment_address.to_string(),
-                        amount: vec![coin(payment_amount.u128(), info.funds[0].denom.clone())],
-                    }),
-                    false => CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: info.sender.to_string(),
-                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                            recipient: payment_address.to_string(),
-                            amount: payment_amount,
-                        })?,
-                        funds: vec![],
-                    }),
-                };
-                msgs.push(msg);
-            };
+fn execute_update_remainder_policy(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    module_name: String,
+    policy: RemainderPolicies,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    if let RemainderPolicies::FeeName { fee_name } = &policy {
+        if !PERCENTAGE_FEES.has(deps.storage, (&module_name, fee_name)) {
+            return Err(ContractError::FeeNotFound {});
         }
     }
 
-    Ok(msgs)
+    REMAINDER_POLICIES.save(deps.storage, &module_name, &policy)?;
+    REMAINDER_CURSORS.remove(deps.storage, &module_name);
+
+    Ok(
+        ResponseHelper::new_module("fee", "update_remainder_policy").add_event(
+            EventHelper::new("fee_update_remainder_policy")
+                .add_attribute("module_name", &module_name)
+                .add_attribute("policy", policy.as_str())
+                .get(),
+        ),
+    )
+}
+
+fn execute_sweep_dust(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    let recipient = deps.api.addr_validate(&recipient)?;
+
+    let dust = DUST
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, ClaimableBalance)>>>()?;
+
+    if dust.is_empty() {
+        return Err(ContractError::NoDust {});
+    }
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut event_attributes: Vec<Attribute> = vec![];
+
+    for (denom, balance) in dust {
+        DUST.remove(deps.storage, &denom);
+
+        msgs.push(_payment_msg(
+            balance.is_native,
+            &denom,
+            recipient.as_str(),
+            balance.amount,
+        )?);
+        event_attributes.push(Attribute {
+            key: "amount".to_string(),
+            value: format!("{}{}", balance.amount, denom),
+        });
+    }
+
+    Ok(ResponseHelper::new_module("fee", "sweep_dust")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("fee_sweep_dust")
+                .add_attribute("recipient", recipient)
+                .add_attributes(event_attributes)
+                .get(),
+        ))
 }
 
 fn execute_receive(
@@ -500,43 +2405,98 @@ fn execute_receive(
     cw20_receive_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     let msg: ReceiveMsg = from_binary(&cw20_receive_msg.msg)?;
-    let amount = cw20_receive_msg.amount;
 
     match msg {
         ReceiveMsg::Distribute {
             fee_type,
             module_name,
             custom_payment_addresses,
-        } => execute_distribute(
-            deps,
-            env,
-            info,
-            fee_type,
+            discount_address,
+        } => {
+            let deposit = _deposit(&info, &Some(cw20_receive_msg))?;
+            execute_distribute(
+                deps,
+                env,
+                deposit,
+                fee_type,
+                module_name,
+                custom_payment_addresses,
+                discount_address,
+            )
+        }
+        ReceiveMsg::DistributeTiered {
             module_name,
+            sale_amount,
             custom_payment_addresses,
-            Some(amount),
-        ),
+            discount_address,
+        } => {
+            let deposit = _deposit(&info, &Some(cw20_receive_msg))?;
+            execute_distribute_tiered(
+                deps,
+                env,
+                deposit,
+                module_name,
+                sale_amount,
+                custom_payment_addresses,
+                discount_address,
+            )
+        }
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::PercentageFee {
             module_name,
             fee_name,
-        } => to_binary(&query_percentage_fee(deps, module_name, fee_name)?),
+        } => to_binary(&query_percentage_fee(deps, env, module_name, fee_name)?),
         QueryMsg::FixedFee {
             module_name,
             fee_name,
-        } => to_binary(&query_fixed_fee(deps, module_name, fee_name)?),
+        } => to_binary(&query_fixed_fee(deps, env, module_name, fee_name)?),
+        QueryMsg::FixedFeeDenoms {
+            module_name,
+            fee_name,
+        } => to_binary(&query_fixed_fee_denoms(deps, env, module_name, fee_name)?),
+        QueryMsg::DiscountRules {
+            module_name,
+            fee_name,
+        } => to_binary(&query_discount_rules(deps, module_name, fee_name)?),
+        QueryMsg::Discount {
+            module_name,
+            fee_name,
+            address,
+        } => to_binary(&query_discount(deps, module_name, fee_name, address)?),
+        QueryMsg::Exemptions {
+            module_name,
+            fee_name,
+            start_after,
+            limit,
+        } => to_binary(&query_exemptions(
+            deps,
+            module_name,
+            fee_name,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::IsExempt {
+            module_name,
+            fee_name,
+            address,
+        } => to_binary(&query_is_exempt(deps, module_name, fee_name, address)?),
+        QueryMsg::TieredFee {
+            module_name,
+            fee_name,
+        } => to_binary(&query_tiered_fee(deps, env, module_name, fee_name)?),
         QueryMsg::PercentageFees {
             module_name,
             start_after,
             limit,
         } => to_binary(&query_percentage_fees(
             deps,
+            env,
             module_name,
             start_after,
             limit,
@@ -545,23 +2505,71 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             module_name,
             start_after,
             limit,
-        } => to_binary(&query_fixed_fees(deps, module_name, start_after, limit)?),
+        } => to_binary(&query_fixed_fees(
+            deps,
+            env,
+            module_name,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TieredFees {
+            module_name,
+            start_after,
+            limit,
+        } => to_binary(&query_tiered_fees(
+            deps,
+            env,
+            module_name,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::TotalTieredFees {
+            module_name,
+            sale_amount,
+        } => to_binary(&query_total_tiered_fees(
+            deps,
+            env,
+            module_name,
+            sale_amount,
+        )?),
+        QueryMsg::TotalDiscountedTieredFees {
+            module_name,
+            sale_amount,
+            address,
+        } => to_binary(&query_total_discounted_tiered_fees(
+            deps,
+            env,
+            module_name,
+            sale_amount,
+            address,
+        )?),
         QueryMsg::TotalPercentageFees {
             module_name,
             start_after,
             limit,
         } => to_binary(&query_total_percentage_fees(
             deps,
+            env,
             module_name,
             start_after,
             limit,
         )?),
+        QueryMsg::TotalDiscountedPercentageFees {
+            module_name,
+            address,
+        } => to_binary(&query_total_discounted_percentage_fees(
+            deps,
+            env,
+            module_name,
+            address,
+        )?),
         QueryMsg::TotalFixedFees {
             module_name,
             start_after,
             limit,
         } => to_binary(&query_total_fixed_fees(
             deps,
+            env,
             module_name,
             start_after,
             limit,
@@ -572,72 +2580,252 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
         } => to_binary(&query_keys(deps, fee_type, start_after, limit)?),
         QueryMsg::Operators {} => to_binary(&query_operators(deps)?),
+        QueryMsg::DistributionMode { module_name } => {
+            to_binary(&query_distribution_mode(deps, module_name)?)
+        }
+        QueryMsg::RemainderPolicy { module_name } => {
+            to_binary(&query_remainder_policy(deps, module_name)?)
+        }
+        QueryMsg::Dust { start_after, limit } => to_binary(&query_dust(deps, start_after, limit)?),
+        QueryMsg::ClaimableBalances {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&query_claimable_balances(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::FeeRevenue {
+            module_name,
+            fee_name,
+        } => to_binary(&query_fee_revenue(deps, module_name, fee_name)?),
+        QueryMsg::DailyFeeRevenue {
+            module_name,
+            fee_name,
+            start_after,
+            limit,
+        } => to_binary(&query_daily_fee_revenue(
+            deps,
+            module_name,
+            fee_name,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::RecipientRevenue { address } => {
+            to_binary(&query_recipient_revenue(deps, address)?)
+        }
+        QueryMsg::DailyRecipientRevenue {
+            address,
+            start_after,
+            limit,
+        } => to_binary(&query_daily_recipient_revenue(
+            deps,
+            address,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::NoticePeriod { module_name } => {
+            to_binary(&query_notice_period(deps, env, module_name)?)
+        }
+        QueryMsg::PendingNoticePeriod { module_name } => {
+            to_binary(&query_pending_notice_period(deps, module_name)?)
+        }
+        QueryMsg::PendingFees {
+            fee_type,
+            module_name,
+            start_after,
+            limit,
+        } => to_binary(&query_pending_fees(
+            deps,
+            fee_type,
+            module_name,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::PendingFixedFeeDenoms {
+            module_name,
+            fee_name,
+        } => to_binary(&query_pending_fixed_fee_denoms(
+            deps,
+            module_name,
+            fee_name,
+        )?),
     }
 }
 
-fn query_config(deps: Deps) -> StdResult<ResponseWrapper<Config>> {
-    let config = CONFIG.load(deps.storage)?;
-    Ok(ResponseWrapper {
-        query: "config".to_string(),
-        data: config,
-    })
+fn query_config(deps: Deps) -> StdResult<ResponseWrapper<Config>> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(ResponseWrapper {
+        query: "config".to_string(),
+        data: config,
+    })
+}
+
+fn query_percentage_fee(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<PercentageFeeResponse>> {
+    let percentage_fee = _active_fee(
+        deps,
+        &env,
+        PERCENTAGE_FEES,
+        &Fees::Percentage,
+        &module_name,
+        &fee_name,
+    )?
+    .ok_or_else(|| StdError::not_found("Percentage fee"))?;
+    Ok(ResponseWrapper {
+        query: "percentage_fee".to_string(),
+        data: PercentageFeeResponse {
+            module_name,
+            fee_name,
+            address: percentage_fee.address,
+            value: percentage_fee.value,
+            group: percentage_fee.group,
+        },
+    })
+}
+
+fn query_fixed_fee(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<FixedFeeResponse>> {
+    let fixed_fee = _active_fee(
+        deps,
+        &env,
+        FIXED_FEES,
+        &Fees::Fixed,
+        &module_name,
+        &fee_name,
+    )?
+    .ok_or_else(|| StdError::not_found("Fixed fee"))?;
+    Ok(ResponseWrapper {
+        query: "fixed_fee".to_string(),
+        data: FixedFeeResponse {
+            module_name,
+            fee_name,
+            address: fixed_fee.address,
+            value: fixed_fee.value,
+            group: fixed_fee.group,
+        },
+    })
+}
+
+fn query_fixed_fee_denoms(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<Vec<Coin>>> {
+    let prices = _active_fixed_fee_denoms(deps, &env, &module_name, &fee_name)?
+        .into_iter()
+        .map(|(denom, value)| coin(value.u128(), denom))
+        .collect::<Vec<Coin>>();
+    Ok(ResponseWrapper::new("fixed_fee_denoms", prices))
+}
+
+fn query_discount_rules(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<Vec<DiscountRule>>> {
+    let rules = DISCOUNTS
+        .may_load(deps.storage, (&module_name, &fee_name))?
+        .unwrap_or_default();
+    Ok(ResponseWrapper::new("discount_rules", rules))
+}
+
+fn query_discount(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+    address: String,
+) -> StdResult<ResponseWrapper<Decimal>> {
+    let discount = _discount(deps, &module_name, &fee_name, &address)?;
+    Ok(ResponseWrapper::new("discount", discount))
+}
+
+fn query_exemptions(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<String>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let addrs = EXEMPTIONS
+        .prefix((&module_name, &fee_name))
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|addr| addr.to_string()))
+        .collect::<StdResult<Vec<String>>>()?;
+    Ok(ResponseWrapper::new("exemptions", addrs))
 }
 
-fn query_percentage_fee(
+fn query_is_exempt(
     deps: Deps,
     module_name: String,
     fee_name: String,
-) -> StdResult<ResponseWrapper<PercentageFeeResponse>> {
-    let percentage_fee = PERCENTAGE_FEES.load(deps.storage, (&module_name, &fee_name))?;
-    Ok(ResponseWrapper {
-        query: "percentage_fee".to_string(),
-        data: PercentageFeeResponse {
-            module_name,
-            fee_name,
-            address: percentage_fee.address,
-            value: percentage_fee.value,
-        },
-    })
+    address: String,
+) -> StdResult<ResponseWrapper<bool>> {
+    let addr = deps.api.addr_validate(&address)?;
+    let is_exempt = EXEMPTIONS.has(deps.storage, (&module_name, &fee_name, &addr));
+    Ok(ResponseWrapper::new("is_exempt", is_exempt))
 }
 
-fn query_fixed_fee(
+fn query_tiered_fee(
     deps: Deps,
+    env: Env,
     module_name: String,
     fee_name: String,
-) -> StdResult<ResponseWrapper<FixedFeeResponse>> {
-    let fixed_fee = FIXED_FEES.load(deps.storage, (&module_name, &fee_name))?;
-    Ok(ResponseWrapper {
-        query: "fixed_fee".to_string(),
-        data: FixedFeeResponse {
+) -> StdResult<ResponseWrapper<TieredFeeResponse>> {
+    let tiered_fee = _active_fee(
+        deps,
+        &env,
+        TIERED_FEES,
+        &Fees::Tiered,
+        &module_name,
+        &fee_name,
+    )?
+    .ok_or_else(|| StdError::not_found("Tiered fee"))?;
+    Ok(ResponseWrapper::new(
+        "tiered_fee",
+        TieredFeeResponse {
             module_name,
             fee_name,
-            address: fixed_fee.address,
-            value: fixed_fee.value,
+            address: tiered_fee.address,
+            tiers: tiered_fee.tiers,
+            min: tiered_fee.min,
+            max: tiered_fee.max,
         },
-    })
+    ))
 }
 
 fn query_percentage_fees(
     deps: Deps,
+    env: Env,
     module_name: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<ResponseWrapper<Vec<PercentageFeeResponse>>> {
-    let limit = limit.unwrap_or(30) as usize;
-    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let percentage_fees =
+        _active_fees(deps, &env, PERCENTAGE_FEES, &Fees::Percentage, &module_name)?;
 
-    let percentage_fees = PERCENTAGE_FEES
-        .prefix(&module_name)
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (fee_name, percentage_payment) = item.unwrap();
-            PercentageFeeResponse {
-                module_name: module_name.clone(),
-                fee_name,
-                address: percentage_payment.address,
-                value: percentage_payment.value,
-            }
+    let percentage_fees = _paginate(percentage_fees, start_after, limit)
+        .map(|(fee_name, percentage_payment)| PercentageFeeResponse {
+            module_name: module_name.clone(),
+            fee_name,
+            address: percentage_payment.address,
+            value: percentage_payment.value,
+            group: percentage_payment.group,
         })
         .collect::<Vec<PercentageFeeResponse>>();
 
@@ -649,25 +2837,20 @@ fn query_percentage_fees(
 
 fn query_fixed_fees(
     deps: Deps,
+    env: Env,
     module_name: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<ResponseWrapper<Vec<FixedFeeResponse>>> {
-    let limit = limit.unwrap_or(30) as usize;
-    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let fixed_fees = _active_fees(deps, &env, FIXED_FEES, &Fees::Fixed, &module_name)?;
 
-    let fixed_fees = FIXED_FEES
-        .prefix(&module_name)
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (fee_name, fixed_payment) = item.unwrap();
-            FixedFeeResponse {
-                module_name: module_name.clone(),
-                fee_name,
-                address: fixed_payment.address,
-                value: fixed_payment.value,
-            }
+    let fixed_fees = _paginate(fixed_fees, start_after, limit)
+        .map(|(fee_name, fixed_payment)| FixedFeeResponse {
+            module_name: module_name.clone(),
+            fee_name,
+            address: fixed_payment.address,
+            value: fixed_payment.value,
+            group: fixed_payment.group,
         })
         .collect::<Vec<FixedFeeResponse>>();
 
@@ -677,23 +2860,80 @@ fn query_fixed_fees(
     })
 }
 
+fn query_tiered_fees(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<TieredFeeResponse>>> {
+    let tiered_fees = _active_fees(deps, &env, TIERED_FEES, &Fees::Tiered, &module_name)?;
+
+    let tiered_fees = _paginate(tiered_fees, start_after, limit)
+        .map(|(fee_name, tiered_payment)| TieredFeeResponse {
+            module_name: module_name.clone(),
+            fee_name,
+            address: tiered_payment.address,
+            tiers: tiered_payment.tiers,
+            min: tiered_payment.min,
+            max: tiered_payment.max,
+        })
+        .collect::<Vec<TieredFeeResponse>>();
+
+    Ok(ResponseWrapper::new("tiered_fees", tiered_fees))
+}
+
+fn query_total_tiered_fees(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    sale_amount: Uint128,
+) -> StdResult<ResponseWrapper<Uint128>> {
+    let total_tiered = _active_fees(deps, &env, TIERED_FEES, &Fees::Tiered, &module_name)?
+        .into_values()
+        .map(|tiered_payment| tiered_payment.amount(sale_amount))
+        .sum::<Uint128>();
+
+    Ok(ResponseWrapper::new("total_tiered_fees", total_tiered))
+}
+
+fn query_total_discounted_tiered_fees(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    sale_amount: Uint128,
+    address: String,
+) -> StdResult<ResponseWrapper<Uint128>> {
+    let total_tiered = _active_fees(deps, &env, TIERED_FEES, &Fees::Tiered, &module_name)?
+        .into_iter()
+        .map(|(fee_name, tiered_payment)| {
+            let discount = _discount(deps, &module_name, &fee_name, &address)?;
+            Ok(tiered_payment
+                .amount(sale_amount)
+                .mul(Decimal::one() - discount))
+        })
+        .collect::<StdResult<Vec<Uint128>>>()?
+        .into_iter()
+        .sum::<Uint128>();
+
+    Ok(ResponseWrapper::new(
+        "total_discounted_tiered_fees",
+        total_tiered,
+    ))
+}
+
 fn query_total_percentage_fees(
     deps: Deps,
+    env: Env,
     module_name: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<ResponseWrapper<Decimal>> {
-    let limit = limit.unwrap_or(30) as usize;
-    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let percentage_fees =
+        _active_fees(deps, &env, PERCENTAGE_FEES, &Fees::Percentage, &module_name)?;
 
-    let total_percentage = PERCENTAGE_FEES
-        .prefix(&module_name)
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (_, percentage_payment) = item.unwrap();
-            percentage_payment.value
-        })
+    let total_percentage = _paginate(percentage_fees, start_after, limit)
+        .map(|(_, percentage_payment)| percentage_payment.value)
         .sum::<Decimal>();
 
     Ok(ResponseWrapper {
@@ -702,23 +2942,40 @@ fn query_total_percentage_fees(
     })
 }
 
+fn query_total_discounted_percentage_fees(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+    address: String,
+) -> StdResult<ResponseWrapper<Decimal>> {
+    let total_percentage =
+        _active_fees(deps, &env, PERCENTAGE_FEES, &Fees::Percentage, &module_name)?
+            .into_iter()
+            .map(|(fee_name, percentage_payment)| {
+                let discount = _discount(deps, &module_name, &fee_name, &address)?;
+                Ok(percentage_payment.value * (Decimal::one() - discount))
+            })
+            .collect::<StdResult<Vec<Decimal>>>()?
+            .into_iter()
+            .sum::<Decimal>();
+
+    Ok(ResponseWrapper::new(
+        "total_discounted_percentage_fees",
+        total_percentage,
+    ))
+}
+
 fn query_total_fixed_fees(
     deps: Deps,
+    env: Env,
     module_name: String,
     start_after: Option<String>,
     limit: Option<u32>,
 ) -> StdResult<ResponseWrapper<Uint128>> {
-    let limit = limit.unwrap_or(30) as usize;
-    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+    let fixed_fees = _active_fees(deps, &env, FIXED_FEES, &Fees::Fixed, &module_name)?;
 
-    let total_fixed = FIXED_FEES
-        .prefix(&module_name)
-        .range(deps.storage, start, None, Order::Ascending)
-        .take(limit)
-        .map(|item| {
-            let (_, fixed_payment) = item.unwrap();
-            fixed_payment.value
-        })
+    let total_fixed = _paginate(fixed_fees, start_after, limit)
+        .map(|(_, fixed_payment)| fixed_payment.value)
         .sum::<Uint128>();
 
     Ok(ResponseWrapper {
@@ -755,6 +3012,14 @@ fn query_keys(
                 module_name
             })
             .collect::<Vec<String>>(),
+        Fees::Tiered => TIERED_FEES
+            .keys(deps.storage, None, None, Order::Descending)
+            .take(limit)
+            .map(|item| {
+                let (module_name, _) = item.unwrap();
+                module_name
+            })
+            .collect::<Vec<String>>(),
     };
 
     Ok(ResponseWrapper {
@@ -771,3 +3036,322 @@ fn query_operators(deps: Deps) -> StdResult<ResponseWrapper<Vec<String>>> {
     };
     Ok(ResponseWrapper::new("operators", addrs))
 }
+
+fn query_distribution_mode(
+    deps: Deps,
+    module_name: String,
+) -> StdResult<ResponseWrapper<DistributionModes>> {
+    let mode = DISTRIBUTION_MODES
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(DistributionModes::Push);
+    Ok(ResponseWrapper::new("distribution_mode", mode))
+}
+
+fn query_claimable_balances(
+    deps: Deps,
+    address: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<ClaimableBalanceResponse>>> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let balances = CLAIMABLE_BALANCES
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (denom, balance) = item?;
+            Ok(ClaimableBalanceResponse {
+                denom,
+                is_native: balance.is_native,
+                amount: balance.amount,
+            })
+        })
+        .collect::<StdResult<Vec<ClaimableBalanceResponse>>>()?;
+
+    Ok(ResponseWrapper::new("claimable_balances", balances))
+}
+
+fn query_remainder_policy(
+    deps: Deps,
+    module_name: String,
+) -> StdResult<ResponseWrapper<RemainderPolicies>> {
+    let policy = REMAINDER_POLICIES
+        .may_load(deps.storage, &module_name)?
+        .unwrap_or(RemainderPolicies::Keep);
+    Ok(ResponseWrapper::new("remainder_policy", policy))
+}
+
+fn query_dust(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<ClaimableBalanceResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let dust = DUST
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (denom, balance) = item?;
+            Ok(ClaimableBalanceResponse {
+                denom,
+                is_native: balance.is_native,
+                amount: balance.amount,
+            })
+        })
+        .collect::<StdResult<Vec<ClaimableBalanceResponse>>>()?;
+
+    Ok(ResponseWrapper::new("dust", dust))
+}
+
+fn query_notice_period(
+    deps: Deps,
+    env: Env,
+    module_name: String,
+) -> StdResult<ResponseWrapper<u64>> {
+    let pending_notice_period = PENDING_NOTICE_PERIODS.may_load(deps.storage, &module_name)?;
+    let notice_period = match pending_notice_period {
+        Some(pending_notice_period) if pending_notice_period.activation_time <= env.block.time => {
+            pending_notice_period.notice_period
+        }
+        _ => NOTICE_PERIODS
+            .may_load(deps.storage, &module_name)?
+            .unwrap_or(0),
+    };
+    Ok(ResponseWrapper::new("notice_period", notice_period))
+}
+
+fn query_pending_notice_period(
+    deps: Deps,
+    module_name: String,
+) -> StdResult<ResponseWrapper<Option<PendingNoticePeriod>>> {
+    let pending_notice_period = PENDING_NOTICE_PERIODS.may_load(deps.storage, &module_name)?;
+    Ok(ResponseWrapper::new(
+        "pending_notice_period",
+        pending_notice_period,
+    ))
+}
+
+fn query_pending_fees(
+    deps: Deps,
+    fee_type: Fees,
+    module_name: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<PendingFeeResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+    let pending_fees = PENDING_FEES
+        .prefix((&module_name, fee_type.as_str()))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (fee_name, pending_fee) = item?;
+            Ok(PendingFeeResponse {
+                module_name: module_name.clone(),
+                fee_name,
+                fee_type: pending_fee.fee_type,
+                data: pending_fee.data,
+                activation_time: pending_fee.activation_time,
+            })
+        })
+        .collect::<StdResult<Vec<PendingFeeResponse>>>()?;
+
+    Ok(ResponseWrapper::new("pending_fees", pending_fees))
+}
+
+fn query_pending_fixed_fee_denoms(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<Vec<PendingFixedFeeDenomResponse>>> {
+    let pending_denoms = PENDING_FIXED_FEE_DENOMS
+        .prefix((&module_name, &fee_name))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, pending_denom) = item?;
+            Ok(PendingFixedFeeDenomResponse {
+                denom,
+                value: pending_denom.value,
+                activation_time: pending_denom.activation_time,
+            })
+        })
+        .collect::<StdResult<Vec<PendingFixedFeeDenomResponse>>>()?;
+    Ok(ResponseWrapper::new(
+        "pending_fixed_fee_denoms",
+        pending_denoms,
+    ))
+}
+
+/// Gets a fee of a module with its due pending change resolved.
+///
+/// Due changes are saved on the next distribution,
+/// so the queries resolve them to return the active fees.
+fn _active_fee<'a, T>(
+    deps: Deps,
+    env: &Env,
+    fees: Map<'a, (&'a str, &'a str), T>,
+    fee_type: &Fees,
+    module_name: &'a str,
+    fee_name: &'a str,
+) -> StdResult<Option<T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    match PENDING_FEES.may_load(deps.storage, (module_name, fee_type.as_str(), fee_name))? {
+        Some(pending_fee) if pending_fee.is_due(fee_type, env.block.time) => {
+            pending_fee.data.map(|data| from_binary(&data)).transpose()
+        }
+        _ => fees.may_load(deps.storage, (module_name, fee_name)),
+    }
+}
+
+/// Gets the fees of a module with the due pending changes resolved.
+fn _active_fees<'a, T>(
+    deps: Deps,
+    env: &Env,
+    fees: Map<'a, (&'a str, &'a str), T>,
+    fee_type: &Fees,
+    module_name: &'a str,
+) -> StdResult<BTreeMap<String, T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut active_fees = fees
+        .prefix(module_name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<String, T>>>()?;
+
+    let due_fees = PENDING_FEES
+        .prefix((module_name, fee_type.as_str()))
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, pending_fee)) => pending_fee.is_due(fee_type, env.block.time),
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<(String, PendingFee)>>>()?;
+    for (fee_name, pending_fee) in due_fees {
+        match pending_fee.data {
+            Some(data) => active_fees.insert(fee_name, from_binary(&data)?),
+            None => active_fees.remove(&fee_name),
+        };
+    }
+
+    Ok(active_fees)
+}
+
+/// Gets the denom prices of a fixed fee with the due pending changes resolved.
+fn _active_fixed_fee_denoms(
+    deps: Deps,
+    env: &Env,
+    module_name: &str,
+    fee_name: &str,
+) -> StdResult<BTreeMap<String, Uint128>> {
+    let fixed_fee = _active_fee(deps, env, FIXED_FEES, &Fees::Fixed, module_name, fee_name)?;
+    if fixed_fee.is_none() {
+        return Ok(BTreeMap::new());
+    }
+
+    let mut prices = FIXED_FEE_DENOMS
+        .prefix((module_name, fee_name))
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<BTreeMap<String, Uint128>>>()?;
+
+    let due_denoms = PENDING_FIXED_FEE_DENOMS
+        .prefix((module_name, fee_name))
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((_, pending_denom)) => pending_denom.activation_time <= env.block.time,
+            Err(_) => true,
+        })
+        .collect::<StdResult<Vec<(String, PendingFixedFeeDenom)>>>()?;
+    for (denom, pending_denom) in due_denoms {
+        match pending_denom.value {
+            Some(value) => prices.insert(denom, value),
+            None => prices.remove(&denom),
+        };
+    }
+
+    Ok(prices)
+}
+
+fn _paginate<T>(
+    fees: BTreeMap<String, T>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> impl Iterator<Item = (String, T)> {
+    let limit = limit.unwrap_or(30) as usize;
+    fees.into_iter()
+        .filter(move |(fee_name, _)| match &start_after {
+            Some(start_after) => fee_name > start_after,
+            None => true,
+        })
+        .take(limit)
+}
+
+fn query_fee_revenue(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+) -> StdResult<ResponseWrapper<Vec<Coin>>> {
+    let revenue = FEE_REVENUES
+        .prefix((&module_name, &fee_name))
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(ResponseWrapper::new("fee_revenue", revenue))
+}
+
+fn query_daily_fee_revenue(
+    deps: Deps,
+    module_name: String,
+    fee_name: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<DailyRevenueResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let revenue = DAILY_FEE_REVENUES
+        .prefix((&module_name, &fee_name))
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(day, amounts)| DailyRevenueResponse { day, amounts }))
+        .collect::<StdResult<Vec<DailyRevenueResponse>>>()?;
+    Ok(ResponseWrapper::new("daily_fee_revenue", revenue))
+}
+
+fn query_recipient_revenue(deps: Deps, address: String) -> StdResult<ResponseWrapper<Vec<Coin>>> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let revenue = RECIPIENT_REVENUES
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| coin(amount.u128(), denom)))
+        .collect::<StdResult<Vec<Coin>>>()?;
+    Ok(ResponseWrapper::new("recipient_revenue", revenue))
+}
+
+fn query_daily_recipient_revenue(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<Vec<DailyRevenueResponse>>> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let revenue = DAILY_RECIPIENT_REVENUES
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(day, amounts)| DailyRevenueResponse { day, amounts }))
+        .collect::<StdResult<Vec<DailyRevenueResponse>>>()?;
+    Ok(ResponseWrapper::new("daily_recipient_revenue", revenue))
+}
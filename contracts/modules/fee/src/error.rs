@@ -1,4 +1,4 @@
-use cosmwasm_std::{DivideByZeroError, StdError};
+use cosmwasm_std::{DivideByZeroError, OverflowError, StdError};
 use komple_framework_utils::{funds::FundsError, shared::SharedError, UtilError};
 use thiserror::Error;
 
@@ -31,9 +31,45 @@ pub enum ContractError {
     #[error("No payments found for distribution")]
     NoPaymentsFound {},
 
+    #[error("Invalid fee tiers")]
+    InvalidTiers {},
+
+    #[error("Sale amount is required for tiered fees")]
+    MissingSaleAmount {},
+
+    #[error("No claimable balance found")]
+    NoClaimableBalance {},
+
+    #[error("No dust found")]
+    NoDust {},
+
+    #[error("Invalid discount")]
+    InvalidDiscount {},
+
+    #[error("Activation time is before the notice period")]
+    InvalidActivationTime {},
+
+    #[error("Pending fee not found")]
+    PendingFeeNotFound {},
+
+    #[error("Invalid fee group")]
+    InvalidFeeGroup {},
+
+    #[error("Fee groups cannot form a cycle")]
+    FeeGroupCycle {},
+
+    #[error("Fee groups exceed the maximum depth")]
+    MaxFeeGroupDepth {},
+
+    #[error("Invalid revenue split")]
+    InvalidRevenueSplit {},
+
     #[error("{0}")]
     DivideByZeroError(#[from] DivideByZeroError),
 
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
     #[error("{0}")]
     UtilError(#[from] UtilError),
 
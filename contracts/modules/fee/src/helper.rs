@@ -1,7 +1,8 @@
-use crate::msg::{CustomPaymentAddress, ExecuteMsg, QueryMsg};
+use crate::msg::{CustomPaymentAddress, ExecuteMsg, QueryMsg, ReceiveMsg};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_binary, Addr, Coin, Decimal, QuerierWrapper, StdResult, Uint128, WasmMsg};
-use komple_framework_types::modules::fee::Fees;
+use cw20::Cw20ExecuteMsg;
+use komple_framework_types::modules::fee::{Fees, FundInfo, RevenueShare};
 use komple_framework_types::shared::query::ResponseWrapper;
 
 /// Helper methods for the fee module.
@@ -19,12 +20,14 @@ impl KompleFeeModule {
         fee_type: Fees,
         module_name: String,
         custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        discount_address: Option<String>,
         funds: Vec<Coin>,
     ) -> StdResult<WasmMsg> {
         let msg = ExecuteMsg::Distribute {
             fee_type,
             module_name,
             custom_payment_addresses,
+            discount_address,
         };
         Ok(WasmMsg::Execute {
             contract_addr: self.0.to_string(),
@@ -33,6 +36,118 @@ impl KompleFeeModule {
         })
     }
 
+    /// Creates the percentage distribute message for an amount of the fund info.
+    ///
+    /// Native funds are attached to the message and cw20 tokens are sent through the token contract.
+    pub fn distribute_funds_msg(
+        &self,
+        fund_info: &FundInfo,
+        module_name: String,
+        amount: Uint128,
+        discount_address: Option<String>,
+    ) -> StdResult<WasmMsg> {
+        match fund_info.is_native {
+            true => self.distribute_msg(
+                Fees::Percentage,
+                module_name,
+                None,
+                discount_address,
+                vec![Coin {
+                    denom: fund_info.denom.to_string(),
+                    amount,
+                }],
+            ),
+            false => Ok(WasmMsg::Execute {
+                contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: self.0.to_string(),
+                    amount,
+                    msg: to_binary(&ReceiveMsg::Distribute {
+                        fee_type: Fees::Percentage,
+                        module_name,
+                        custom_payment_addresses: None,
+                        discount_address,
+                    })?,
+                })?,
+                funds: vec![],
+            }),
+        }
+    }
+
+    /// Creates the fixed distribute message for an amount of the fund info.
+    ///
+    /// Amount must match the total fixed fees of the module name.
+    pub fn distribute_fixed_funds_msg(
+        &self,
+        fund_info: &FundInfo,
+        module_name: String,
+        amount: Uint128,
+    ) -> StdResult<WasmMsg> {
+        match fund_info.is_native {
+            true => self.distribute_msg(
+                Fees::Fixed,
+                module_name,
+                None,
+                None,
+                vec![Coin {
+                    denom: fund_info.denom.to_string(),
+                    amount,
+                }],
+            ),
+            false => Ok(WasmMsg::Execute {
+                contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: self.0.to_string(),
+                    amount,
+                    msg: to_binary(&ReceiveMsg::Distribute {
+                        fee_type: Fees::Fixed,
+                        module_name,
+                        custom_payment_addresses: None,
+                        discount_address: None,
+                    })?,
+                })?,
+                funds: vec![],
+            }),
+        }
+    }
+
+    pub fn distribute_tiered_msg(
+        &self,
+        module_name: String,
+        sale_amount: Uint128,
+        custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        discount_address: Option<String>,
+        funds: Vec<Coin>,
+    ) -> StdResult<WasmMsg> {
+        let msg = ExecuteMsg::DistributeTiered {
+            module_name,
+            sale_amount,
+            custom_payment_addresses,
+            discount_address,
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds,
+        })
+    }
+
+    pub fn set_revenue_split_msg(
+        &self,
+        collection_id: u32,
+        shares: Vec<RevenueShare>,
+    ) -> StdResult<WasmMsg> {
+        let msg = ExecuteMsg::SetRevenueSplit {
+            collection_id,
+            shares,
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+
     // Queries
     pub fn query_total_percentage_fees(
         &self,
@@ -49,6 +164,38 @@ impl KompleFeeModule {
         Ok(res.data)
     }
 
+    pub fn query_total_discounted_percentage_fees(
+        &self,
+        querier: &QuerierWrapper,
+        module_name: &str,
+        address: &str,
+    ) -> StdResult<Decimal> {
+        let msg = QueryMsg::TotalDiscountedPercentageFees {
+            module_name: module_name.to_string(),
+            address: address.to_string(),
+        };
+        let res: ResponseWrapper<Decimal> =
+            querier.query_wasm_smart(self.addr().to_string(), &msg)?;
+        Ok(res.data)
+    }
+
+    pub fn query_discount(
+        &self,
+        querier: &QuerierWrapper,
+        module_name: &str,
+        fee_name: &str,
+        address: &str,
+    ) -> StdResult<Decimal> {
+        let msg = QueryMsg::Discount {
+            module_name: module_name.to_string(),
+            fee_name: fee_name.to_string(),
+            address: address.to_string(),
+        };
+        let res: ResponseWrapper<Decimal> =
+            querier.query_wasm_smart(self.addr().to_string(), &msg)?;
+        Ok(res.data)
+    }
+
     pub fn query_total_fixed_fees(
         &self,
         querier: &QuerierWrapper,
@@ -63,4 +210,36 @@ impl KompleFeeModule {
             querier.query_wasm_smart(self.addr().to_string(), &msg)?;
         Ok(res.data)
     }
+
+    pub fn query_total_tiered_fees(
+        &self,
+        querier: &QuerierWrapper,
+        module_name: &str,
+        sale_amount: Uint128,
+    ) -> StdResult<Uint128> {
+        let msg = QueryMsg::TotalTieredFees {
+            module_name: module_name.to_string(),
+            sale_amount,
+        };
+        let res: ResponseWrapper<Uint128> =
+            querier.query_wasm_smart(self.addr().to_string(), &msg)?;
+        Ok(res.data)
+    }
+
+    pub fn query_total_discounted_tiered_fees(
+        &self,
+        querier: &QuerierWrapper,
+        module_name: &str,
+        sale_amount: Uint128,
+        address: &str,
+    ) -> StdResult<Uint128> {
+        let msg = QueryMsg::TotalDiscountedTieredFees {
+            module_name: module_name.to_string(),
+            sale_amount,
+            address: address.to_string(),
+        };
+        let res: ResponseWrapper<Uint128> =
+            querier.query_wasm_smart(self.addr().to_string(), &msg)?;
+        Ok(res.data)
+    }
 }
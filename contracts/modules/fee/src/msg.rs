@@ -1,8 +1,10 @@
-use crate::state::Config;
+use crate::state::{Config, PendingNoticePeriod};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Decimal, Uint128};
+use cosmwasm_std::{Binary, Coin, Decimal, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
-use komple_framework_types::modules::fee::Fees;
+use komple_framework_types::modules::fee::{
+    DiscountRule, DistributionModes, FeeTier, Fees, RemainderPolicies, RevenueShare,
+};
 use komple_framework_types::shared::execute::SharedExecuteMsg;
 use komple_framework_types::shared::query::ResponseWrapper;
 
@@ -12,6 +14,7 @@ pub enum ExecuteMsg {
     ///
     /// Creates a new fee configuration.
     /// Fees are tied to a module with a fee name.
+    /// Staged until the notice period passes if the module has one.
     SetFee {
         fee_type: Fees,
         module_name: String,
@@ -21,20 +24,166 @@ pub enum ExecuteMsg {
     /// Admin message.
     ///
     /// Removes a fee configuration.
+    /// Staged until the notice period passes if the module has one.
     RemoveFee {
         fee_type: Fees,
         module_name: String,
         fee_name: String,
     },
+    /// Admin message.
+    ///
+    /// Sets the price of a fixed fee for a denom.
+    /// Denoms without a price use the fixed fee value.
+    /// Staged until the notice period passes if the module has one.
+    SetFixedFeeDenom {
+        module_name: String,
+        fee_name: String,
+        denom: String,
+        value: Uint128,
+    },
+    /// Admin message.
+    ///
+    /// Removes the price of a fixed fee for a denom.
+    /// Staged until the notice period passes if the module has one.
+    RemoveFixedFeeDenom {
+        module_name: String,
+        fee_name: String,
+        denom: String,
+    },
+    /// Admin message.
+    ///
+    /// Sets the holder discount rules of a fee.
+    /// Replaces the existing rules of the fee.
+    /// A fee can have at most 5 rules.
+    SetDiscount {
+        module_name: String,
+        fee_name: String,
+        rules: Vec<DiscountRule>,
+    },
+    /// Admin message.
+    ///
+    /// Removes the holder discount rules of a fee.
+    RemoveDiscount {
+        module_name: String,
+        fee_name: String,
+    },
     /// Public message.
     ///
     /// Distributes the sent funds according to the fee configuration.
+    /// Multiple coins can be sent for percentage fees
+    /// and each coin is split with the same percentages.
     /// Custom payment addresses can be specified for
     /// overriding the default payment addresses.
+    /// If a discount address is specified, the fees are
+    /// reduced with the discounts of that address.
     Distribute {
         fee_type: Fees,
         module_name: String,
         custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        #[serde(default)]
+        discount_address: Option<String>,
+    },
+    /// Public message.
+    ///
+    /// Distributes the sent funds according to the tiered fee configuration.
+    /// Sale amount is used for picking the tier of each fee and
+    /// the sent funds must match the total of the tiered fees.
+    /// If a discount address is specified, the fees are
+    /// reduced with the discounts of that address.
+    DistributeTiered {
+        module_name: String,
+        sale_amount: Uint128,
+        custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        #[serde(default)]
+        discount_address: Option<String>,
+    },
+    /// Admin message.
+    ///
+    /// Updates the distribution mode of a module.
+    /// In pull mode the distributed funds are credited
+    /// to the payment addresses instead of being sent.
+    UpdateDistributionMode {
+        module_name: String,
+        mode: DistributionModes,
+    },
+    /// Public message.
+    ///
+    /// Sends every balance credited to the sender in pull mode.
+    Claim {},
+    /// Admin message.
+    ///
+    /// Updates the way the rounding remainder of
+    /// percentage distributions is handled for a module.
+    UpdateRemainderPolicy {
+        module_name: String,
+        policy: RemainderPolicies,
+    },
+    /// Admin message.
+    ///
+    /// Sends all the kept remainders to the recipient.
+    SweepDust {
+        recipient: String,
+    },
+    /// Admin message.
+    ///
+    /// Exempts the addresses from a fee.
+    AddExemptions {
+        module_name: String,
+        fee_name: String,
+        addrs: Vec<String>,
+    },
+    /// Admin message.
+    ///
+    /// Removes the fee exemptions of the addresses.
+    RemoveExemptions {
+        module_name: String,
+        fee_name: String,
+        addrs: Vec<String>,
+    },
+    /// Admin and Mint Module message.
+    ///
+    /// Replaces the percentage fees of a collection revenue module name
+    /// with the shares of a revenue split. Shares must add up to exactly one.
+    /// Staged percentage fee changes of the module are dropped.
+    /// Sending an empty list removes the split.
+    SetRevenueSplit {
+        collection_id: u32,
+        shares: Vec<RevenueShare>,
+    },
+    /// Admin message.
+    ///
+    /// Stages a fee configuration to be applied at the activation time.
+    /// Activation time cannot be earlier than the notice period of the module.
+    ScheduleFee {
+        fee_type: Fees,
+        module_name: String,
+        fee_name: String,
+        data: Binary,
+        activation_time: Timestamp,
+    },
+    /// Admin message.
+    ///
+    /// Removes a staged fee configuration and the staged denom prices of the fee.
+    CancelPendingFee {
+        fee_type: Fees,
+        module_name: String,
+        fee_name: String,
+    },
+    /// Admin message.
+    ///
+    /// Updates the notice period of a module in seconds.
+    /// Fee changes are staged while the notice period is not zero.
+    /// Reductions are staged until the current notice period passes.
+    UpdateNoticePeriod {
+        module_name: String,
+        notice_period: u64,
+    },
+    /// Public message.
+    ///
+    /// Applies the staged fee configurations of a module that are due.
+    /// Distributions apply them automatically before calculating the fees.
+    ApplyPendingFees {
+        module_name: String,
     },
     /// Admin message.
     ///
@@ -65,6 +214,15 @@ pub enum ReceiveMsg {
         fee_type: Fees,
         module_name: String,
         custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        #[serde(default)]
+        discount_address: Option<String>,
+    },
+    DistributeTiered {
+        module_name: String,
+        sale_amount: Uint128,
+        custom_payment_addresses: Option<Vec<CustomPaymentAddress>>,
+        #[serde(default)]
+        discount_address: Option<String>,
     },
 }
 
@@ -86,6 +244,46 @@ pub enum QueryMsg {
         module_name: String,
         fee_name: String,
     },
+    /// Gets the denom prices of a fixed fee.
+    #[returns(ResponseWrapper<Vec<Coin>>)]
+    FixedFeeDenoms {
+        module_name: String,
+        fee_name: String,
+    },
+    /// Gets the holder discount rules of a fee.
+    #[returns(ResponseWrapper<Vec<DiscountRule>>)]
+    DiscountRules {
+        module_name: String,
+        fee_name: String,
+    },
+    /// Gets the discount of an address for a fee.
+    #[returns(ResponseWrapper<Decimal>)]
+    Discount {
+        module_name: String,
+        fee_name: String,
+        address: String,
+    },
+    /// Gets the addresses exempt from a fee with pagination.
+    #[returns(ResponseWrapper<Vec<String>>)]
+    Exemptions {
+        module_name: String,
+        fee_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets if an address is exempt from a fee.
+    #[returns(ResponseWrapper<bool>)]
+    IsExempt {
+        module_name: String,
+        fee_name: String,
+        address: String,
+    },
+    /// Gets the fee configuration for a module and fee name. Used for tiered fees.
+    #[returns(ResponseWrapper<TieredFeeResponse>)]
+    TieredFee {
+        module_name: String,
+        fee_name: String,
+    },
     /// Gets the fee configurations for a module with pagination. Used for percentage fees.
     #[returns(ResponseWrapper<Vec<PercentageFeeResponse>>)]
     PercentageFees {
@@ -100,6 +298,13 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the fee configurations for a module with pagination. Used for tiered fees.
+    #[returns(ResponseWrapper<Vec<TieredFeeResponse>>)]
+    TieredFees {
+        module_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Gets the sum of all the percentages for a given module.
     #[returns(ResponseWrapper<Decimal>)]
     TotalPercentageFees {
@@ -107,6 +312,13 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the sum of all the percentages for a given module
+    /// after applying the discounts of an address.
+    #[returns(ResponseWrapper<Decimal>)]
+    TotalDiscountedPercentageFees {
+        module_name: String,
+        address: String,
+    },
     /// Gets the sum of all the fixed amounts for a given module.
     #[returns(ResponseWrapper<Uint128>)]
     TotalFixedFees {
@@ -114,6 +326,20 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u32>,
     },
+    /// Gets the sum of all the tiered fee amounts for a given module and sale amount.
+    #[returns(ResponseWrapper<Uint128>)]
+    TotalTieredFees {
+        module_name: String,
+        sale_amount: Uint128,
+    },
+    /// Gets the sum of all the tiered fee amounts for a given module and sale amount
+    /// after applying the discounts of an address.
+    #[returns(ResponseWrapper<Uint128>)]
+    TotalDiscountedTieredFees {
+        module_name: String,
+        sale_amount: Uint128,
+        address: String,
+    },
     /// Gets all the module names and fee names for a given fee type.
     #[returns(ResponseWrapper<Vec<String>>)]
     Keys {
@@ -124,6 +350,71 @@ pub enum QueryMsg {
     /// Get the operators of this contract.
     #[returns(ResponseWrapper<Vec<String>>)]
     Operators {},
+    /// Gets the distribution mode of a module.
+    #[returns(ResponseWrapper<DistributionModes>)]
+    DistributionMode { module_name: String },
+    /// Gets the remainder policy of a module.
+    #[returns(ResponseWrapper<RemainderPolicies>)]
+    RemainderPolicy { module_name: String },
+    /// Gets the remainders kept in the contract for each denom with pagination.
+    #[returns(ResponseWrapper<Vec<ClaimableBalanceResponse>>)]
+    Dust {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the total amounts distributed for a fee.
+    #[returns(ResponseWrapper<Vec<Coin>>)]
+    FeeRevenue {
+        module_name: String,
+        fee_name: String,
+    },
+    /// Gets the amounts distributed for a fee by day with pagination.
+    /// Days are counted from the unix epoch.
+    #[returns(ResponseWrapper<Vec<DailyRevenueResponse>>)]
+    DailyFeeRevenue {
+        module_name: String,
+        fee_name: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the total amounts paid to an address.
+    #[returns(ResponseWrapper<Vec<Coin>>)]
+    RecipientRevenue { address: String },
+    /// Gets the amounts paid to an address by day with pagination.
+    /// Days are counted from the unix epoch.
+    #[returns(ResponseWrapper<Vec<DailyRevenueResponse>>)]
+    DailyRecipientRevenue {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the notice period of a module in seconds.
+    #[returns(ResponseWrapper<u64>)]
+    NoticePeriod { module_name: String },
+    /// Gets the staged notice period reduction of a module.
+    #[returns(ResponseWrapper<Option<PendingNoticePeriod>>)]
+    PendingNoticePeriod { module_name: String },
+    /// Gets the staged fee configurations of a fee type for a module with pagination.
+    #[returns(ResponseWrapper<Vec<PendingFeeResponse>>)]
+    PendingFees {
+        fee_type: Fees,
+        module_name: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Gets the staged denom prices of a fixed fee.
+    #[returns(ResponseWrapper<Vec<PendingFixedFeeDenomResponse>>)]
+    PendingFixedFeeDenoms {
+        module_name: String,
+        fee_name: String,
+    },
+    /// Gets the balances waiting to be claimed by an address with pagination.
+    #[returns(ResponseWrapper<Vec<ClaimableBalanceResponse>>)]
+    ClaimableBalances {
+        address: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -132,6 +423,7 @@ pub struct PercentageFeeResponse {
     pub fee_name: String,
     pub address: Option<String>,
     pub value: Decimal,
+    pub group: Option<String>,
 }
 
 #[cw_serde]
@@ -140,6 +432,46 @@ pub struct FixedFeeResponse {
     pub fee_name: String,
     pub address: Option<String>,
     pub value: Uint128,
+    pub group: Option<String>,
+}
+
+#[cw_serde]
+pub struct TieredFeeResponse {
+    pub module_name: String,
+    pub fee_name: String,
+    pub address: Option<String>,
+    pub tiers: Vec<FeeTier>,
+    pub min: Option<Uint128>,
+    pub max: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct ClaimableBalanceResponse {
+    pub denom: String,
+    pub is_native: bool,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct PendingFeeResponse {
+    pub module_name: String,
+    pub fee_name: String,
+    pub fee_type: Fees,
+    pub data: Option<Binary>,
+    pub activation_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct PendingFixedFeeDenomResponse {
+    pub denom: String,
+    pub value: Option<Uint128>,
+    pub activation_time: Timestamp,
+}
+
+#[cw_serde]
+pub struct DailyRevenueResponse {
+    pub day: u64,
+    pub amounts: Vec<Coin>,
 }
 
 /// Used for overriding the default payment addresses.
@@ -3,10 +3,17 @@ use komple_framework_types::shared::{
     CONFIG_NAMESPACE, EXECUTE_LOCK_NAMESPACE, OPERATORS_NAMESPACE, PARENT_ADDR_NAMESPACE,
 };
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Coin, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 use komple_framework_types::modules::fee::{
-    FixedPayment, PercentagePayment, FIXED_FEES_NAMESPACE, PERCENTAGE_FEES_NAMESPACE,
+    DiscountRule, DistributionModes, FixedPayment, PendingFee, PercentagePayment,
+    RemainderPolicies, TieredPayment, CLAIMABLE_BALANCES_NAMESPACE, DAILY_FEE_REVENUES_NAMESPACE,
+    DAILY_RECIPIENT_REVENUES_NAMESPACE, DISCOUNTS_NAMESPACE, DISTRIBUTION_MODES_NAMESPACE,
+    DUST_NAMESPACE, EXEMPTIONS_NAMESPACE, FEE_REVENUES_NAMESPACE, FIXED_FEES_NAMESPACE,
+    FIXED_FEE_DENOMS_NAMESPACE, NOTICE_PERIODS_NAMESPACE, PENDING_FEES_NAMESPACE,
+    PENDING_FIXED_FEE_DENOMS_NAMESPACE, PENDING_NOTICE_PERIODS_NAMESPACE,
+    PERCENTAGE_FEES_NAMESPACE, RECIPIENT_REVENUES_NAMESPACE, REMAINDER_CURSORS_NAMESPACE,
+    REMAINDER_POLICIES_NAMESPACE, TIERED_FEES_NAMESPACE,
 };
 
 /// General config for the contract.
@@ -29,6 +36,18 @@ pub const PERCENTAGE_FEES: Map<(&str, &str), PercentagePayment> =
 /// ```FixedPayment``` is the value.
 pub const FIXED_FEES: Map<(&str, &str), FixedPayment> = Map::new(FIXED_FEES_NAMESPACE);
 
+/// The denom prices of the fixed fees.
+///
+/// Module name, fee name and denom are used as the key.
+/// Fixed fees without a price for the sent denom use ```FixedPayment``` value.
+pub const FIXED_FEE_DENOMS: Map<(&str, &str, &str), Uint128> = Map::new(FIXED_FEE_DENOMS_NAMESPACE);
+
+/// The fees that are tiered percentages.
+///
+/// Module name and fee name are used as the key.
+/// ```TieredPayment``` is the value.
+pub const TIERED_FEES: Map<(&str, &str), TieredPayment> = Map::new(TIERED_FEES_NAMESPACE);
+
 /// Hub module address.
 pub const HUB_ADDR: Item<Addr> = Item::new(PARENT_ADDR_NAMESPACE);
 
@@ -37,3 +56,115 @@ pub const EXECUTE_LOCK: Item<bool> = Item::new(EXECUTE_LOCK_NAMESPACE);
 
 /// Operators of this contract.
 pub const OPERATORS: Item<Vec<Addr>> = Item::new(OPERATORS_NAMESPACE);
+
+/// The distribution modes of the modules.
+///
+/// Module name is used as the key.
+/// Modules without a saved mode use ```DistributionModes::Push```.
+pub const DISTRIBUTION_MODES: Map<&str, DistributionModes> = Map::new(DISTRIBUTION_MODES_NAMESPACE);
+
+/// Funds credited to a payment address in pull mode.
+#[cw_serde]
+pub struct ClaimableBalance {
+    pub is_native: bool,
+    pub amount: Uint128,
+}
+
+/// The balances waiting to be claimed.
+///
+/// Payment address and denom are used as the key.
+/// For cw20 tokens the token contract address is used as the denom.
+pub const CLAIMABLE_BALANCES: Map<(&Addr, &str), ClaimableBalance> =
+    Map::new(CLAIMABLE_BALANCES_NAMESPACE);
+
+/// The remainder policies of the modules.
+///
+/// Module name is used as the key.
+/// Modules without a saved policy use ```RemainderPolicies::Keep```.
+pub const REMAINDER_POLICIES: Map<&str, RemainderPolicies> = Map::new(REMAINDER_POLICIES_NAMESPACE);
+
+/// The next payment index for the round robin remainder policy.
+///
+/// Module name is used as the key.
+pub const REMAINDER_CURSORS: Map<&str, u32> = Map::new(REMAINDER_CURSORS_NAMESPACE);
+
+/// The remainders kept in the contract.
+///
+/// Denom is used as the key, which is the token contract address for cw20 tokens.
+pub const DUST: Map<&str, ClaimableBalance> = Map::new(DUST_NAMESPACE);
+
+/// The holder discount rules of the fees.
+///
+/// Module name and fee name are used as the key.
+/// The highest discount of the matching rules is applied.
+pub const DISCOUNTS: Map<(&str, &str), Vec<DiscountRule>> = Map::new(DISCOUNTS_NAMESPACE);
+
+/// The notice periods of the modules in seconds.
+///
+/// Module name is used as the key.
+/// Fee changes of modules without a saved period are applied immediately.
+pub const NOTICE_PERIODS: Map<&str, u64> = Map::new(NOTICE_PERIODS_NAMESPACE);
+
+/// The staged fee changes.
+///
+/// Module name, fee type and fee name are used as the key.
+/// The current fee keeps applying until the change is activated.
+pub const PENDING_FEES: Map<(&str, &str, &str), PendingFee> = Map::new(PENDING_FEES_NAMESPACE);
+
+/// A denom price change of a fixed fee waiting for its activation time.
+///
+/// If the value is empty, the denom price is removed.
+#[cw_serde]
+pub struct PendingFixedFeeDenom {
+    pub value: Option<Uint128>,
+    pub activation_time: Timestamp,
+}
+
+/// The staged denom price changes of the fixed fees.
+///
+/// Module name, fee name and denom are used as the key.
+pub const PENDING_FIXED_FEE_DENOMS: Map<(&str, &str, &str), PendingFixedFeeDenom> =
+    Map::new(PENDING_FIXED_FEE_DENOMS_NAMESPACE);
+
+/// A notice period reduction waiting for its activation time.
+#[cw_serde]
+pub struct PendingNoticePeriod {
+    pub notice_period: u64,
+    pub activation_time: Timestamp,
+}
+
+/// The staged notice period reductions of the modules.
+///
+/// Module name is used as the key.
+/// Reductions are activated after the current notice period.
+pub const PENDING_NOTICE_PERIODS: Map<&str, PendingNoticePeriod> =
+    Map::new(PENDING_NOTICE_PERIODS_NAMESPACE);
+
+/// The total amounts distributed for the fees.
+///
+/// Module name, fee name and denom are used as the key.
+/// For cw20 tokens the token contract address is used as the denom.
+pub const FEE_REVENUES: Map<(&str, &str, &str), Uint128> = Map::new(FEE_REVENUES_NAMESPACE);
+
+/// The amounts distributed for the fees in a day.
+///
+/// Module name, fee name and the number of days since the unix epoch are used as the key.
+pub const DAILY_FEE_REVENUES: Map<(&str, &str, u64), Vec<Coin>> =
+    Map::new(DAILY_FEE_REVENUES_NAMESPACE);
+
+/// The total amounts paid to the payment addresses.
+///
+/// Payment address and denom are used as the key.
+pub const RECIPIENT_REVENUES: Map<(&Addr, &str), Uint128> = Map::new(RECIPIENT_REVENUES_NAMESPACE);
+
+/// The amounts paid to the payment addresses in a day.
+///
+/// Payment address and the number of days since the unix epoch are used as the key.
+pub const DAILY_RECIPIENT_REVENUES: Map<(&Addr, u64), Vec<Coin>> =
+    Map::new(DAILY_RECIPIENT_REVENUES_NAMESPACE);
+
+/// The addresses that are exempt from the fees.
+///
+/// Module name, fee name and address are used as the key.
+/// Exempt addresses get the full discount on the fee.
+pub const EXEMPTIONS: Map<(&str, &str, &Addr), bool> = Map::new(EXEMPTIONS_NAMESPACE);
@@ -1,13 +1,19 @@
-use crate::msg::{CustomPaymentAddress, FixedFeeResponse, PercentageFeeResponse};
+use crate::msg::{
+    ClaimableBalanceResponse, CustomPaymentAddress, DailyRevenueResponse, FixedFeeResponse,
+    PendingFeeResponse, PendingFixedFeeDenomResponse, PercentageFeeResponse, TieredFeeResponse,
+};
 use crate::msg::{ExecuteMsg, QueryMsg};
-use crate::state::Config;
+use crate::state::{Config, PendingNoticePeriod};
 use crate::ContractError;
 use cosmwasm_std::Decimal;
 use cosmwasm_std::StdError;
-use cosmwasm_std::{coin, Addr, Empty, Uint128};
-use cosmwasm_std::{to_binary, Binary};
+use cosmwasm_std::{coin, Addr, Coin, Empty, Timestamp, Uint128};
+use cosmwasm_std::{from_slice, to_binary, Binary};
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
-use komple_framework_types::modules::fee::{Fees, FixedPayment, PercentagePayment};
+use komple_framework_types::modules::fee::{
+    DiscountConditions, DiscountRule, DistributionModes, FeeTier, Fees, FixedPayment, MintFees,
+    PercentagePayment, RemainderPolicies, RevenueShare, TieredPayment,
+};
 use komple_framework_types::modules::Modules;
 use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
@@ -18,6 +24,7 @@ const KOMPLE: &str = "juno..komple";
 const COMMUNITY: &str = "juno..community";
 const PAYMENT: &str = "juno..test";
 const NATIVE_DENOM: &str = "native_denom";
+const OTHER_DENOM: &str = "other_denom";
 
 pub fn fee_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -35,7 +42,7 @@ fn mock_app() -> App {
             .init_balance(
                 storage,
                 &Addr::unchecked(ADMIN),
-                vec![coin(2_000_000, NATIVE_DENOM)],
+                vec![coin(2_000_000, NATIVE_DENOM), coin(2_000_000, OTHER_DENOM)],
             )
             .unwrap();
     })
@@ -115,6 +122,7 @@ mod actions {
                     data: to_binary(&PercentagePayment {
                         address: Some(KOMPLE.to_string()),
                         value: Decimal::from_str("0.04").unwrap(),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -146,6 +154,7 @@ mod actions {
                     data: to_binary(&PercentagePayment {
                         address: Some(KOMPLE.to_string()),
                         value: Decimal::from_str("0.04").unwrap(),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -170,6 +179,7 @@ mod actions {
                     data: to_binary(&PercentagePayment {
                         address: Some(KOMPLE.to_string()),
                         value: Decimal::from_str("1.1").unwrap(),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -196,6 +206,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.4").unwrap(),
                         address: Some("address_1".to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -208,6 +219,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.5").unwrap(),
                         address: Some("address_2".to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -219,6 +231,7 @@ mod actions {
                     data: to_binary(&PercentagePayment {
                         address: Some(KOMPLE.to_string()),
                         value: Decimal::from_str("0.2").unwrap(),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -237,6 +250,7 @@ mod actions {
                     data: to_binary(&PercentagePayment {
                         address: Some(KOMPLE.to_string()),
                         value: Decimal::from_str("0.3").unwrap(),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -265,6 +279,7 @@ mod actions {
                     data: to_binary(&FixedPayment {
                         address: Some(COMMUNITY.to_string()),
                         value: Uint128::new(1_000_000),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -296,6 +311,7 @@ mod actions {
                     data: to_binary(&FixedPayment {
                         address: Some(COMMUNITY.to_string()),
                         value: Uint128::new(1_000_000),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -320,6 +336,7 @@ mod actions {
                     data: to_binary(&FixedPayment {
                         address: Some(COMMUNITY.to_string()),
                         value: Uint128::new(0),
+                        group: None,
                     })
                     .unwrap(),
                 };
@@ -332,6 +349,204 @@ mod actions {
                 )
             }
         }
+
+        mod tiered {
+            use super::*;
+
+            fn get_tiered_payment(values: [&str; 2]) -> TieredPayment {
+                TieredPayment {
+                    address: Some(KOMPLE.to_string()),
+                    tiers: vec![
+                        FeeTier {
+                            min_amount: Uint128::zero(),
+                            value: Decimal::from_str(values[0]).unwrap(),
+                        },
+                        FeeTier {
+                            min_amount: Uint128::new(1_000),
+                            value: Decimal::from_str(values[1]).unwrap(),
+                        },
+                    ],
+                    min: None,
+                    max: None,
+                }
+            }
+
+            #[test]
+            fn test_happy_path() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                let mut tiered_payment = get_tiered_payment(["0.05", "0.025"]);
+                tiered_payment.min = Some(Uint128::new(10));
+                tiered_payment.max = Some(Uint128::new(100));
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    "komple",
+                    to_binary(&tiered_payment).unwrap(),
+                );
+
+                let msg = QueryMsg::TieredFee {
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                };
+                let res: ResponseWrapper<TieredFeeResponse> =
+                    app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+                assert_eq!(res.data.tiers, tiered_payment.tiers);
+                assert_eq!(res.data.min, Some(Uint128::new(10)));
+                assert_eq!(res.data.max, Some(Uint128::new(100)));
+
+                // 5% of 100 is below the minimum
+                let msg = QueryMsg::TotalTieredFees {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(100),
+                };
+                let res: ResponseWrapper<Uint128> =
+                    app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+                assert_eq!(res.data, Uint128::new(10));
+
+                let msg = QueryMsg::TotalTieredFees {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(999),
+                };
+                let res: ResponseWrapper<Uint128> =
+                    app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+                assert_eq!(res.data, Uint128::new(49));
+
+                let msg = QueryMsg::TotalTieredFees {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(2_000),
+                };
+                let res: ResponseWrapper<Uint128> =
+                    app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+                assert_eq!(res.data, Uint128::new(50));
+
+                // 2.5% of 10_000 is above the maximum
+                let msg = QueryMsg::TotalTieredFees {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(10_000),
+                };
+                let res: ResponseWrapper<Uint128> =
+                    app.wrap().query_wasm_smart(addr, &msg).unwrap();
+                assert_eq!(res.data, Uint128::new(100));
+            }
+
+            #[test]
+            fn test_invalid_tiers() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                let mut tiered_payments = vec![];
+
+                let mut tiered_payment = get_tiered_payment(["0.05", "0.025"]);
+                tiered_payment.tiers = vec![];
+                tiered_payments.push(tiered_payment);
+
+                let mut tiered_payment = get_tiered_payment(["0.05", "0.025"]);
+                tiered_payment.tiers[0].min_amount = Uint128::new(1);
+                tiered_payments.push(tiered_payment);
+
+                let mut tiered_payment = get_tiered_payment(["0.05", "0.025"]);
+                tiered_payment.tiers[1].min_amount = Uint128::zero();
+                tiered_payments.push(tiered_payment);
+
+                let mut tiered_payment = get_tiered_payment(["0.05", "0.025"]);
+                tiered_payment.min = Some(Uint128::new(100));
+                tiered_payment.max = Some(Uint128::new(10));
+                tiered_payments.push(tiered_payment);
+
+                for tiered_payment in tiered_payments {
+                    let msg = ExecuteMsg::SetFee {
+                        fee_type: Fees::Tiered,
+                        module_name: Modules::Marketplace.to_string(),
+                        fee_name: "komple".to_string(),
+                        data: to_binary(&tiered_payment).unwrap(),
+                    };
+                    let err = app
+                        .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().to_string(),
+                        ContractError::InvalidTiers {}.to_string()
+                    );
+                }
+            }
+
+            #[test]
+            fn test_invalid_total_fee_value() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    "percentage_1",
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str("0.5").unwrap(),
+                        address: Some("address_1".to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    "tiered_1",
+                    to_binary(&get_tiered_payment(["0.4", "0.1"])).unwrap(),
+                );
+
+                // Only the lowest tier goes over the total
+                let msg = ExecuteMsg::SetFee {
+                    fee_type: Fees::Tiered,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                    data: to_binary(&get_tiered_payment(["0.1", "0.1"])).unwrap(),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidTotalFee {}.to_string()
+                );
+
+                // Updating the existing tiered fee replaces its tiers
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    "tiered_1",
+                    to_binary(&get_tiered_payment(["0.3", "0.1"])).unwrap(),
+                );
+
+                // Percentage fees are checked against the tiers as well
+                let msg = ExecuteMsg::SetFee {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "percentage_1".to_string(),
+                    data: to_binary(&PercentagePayment {
+                        address: Some(KOMPLE.to_string()),
+                        value: Decimal::from_str("0.7").unwrap(),
+                        group: None,
+                    })
+                    .unwrap(),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidTotalFee {}.to_string()
+                );
+            }
+        }
     }
 
     mod remove_fee {
@@ -351,6 +566,7 @@ mod actions {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.5").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -387,6 +603,7 @@ mod actions {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -423,6 +640,7 @@ mod actions {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -462,6 +680,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -474,6 +693,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.02").unwrap(),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -486,6 +706,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.03").unwrap(),
                         address: Some(PAYMENT.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -494,6 +715,7 @@ mod actions {
                     fee_type: Fees::Percentage,
                     module_name: Modules::Marketplace.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -521,6 +743,7 @@ mod actions {
                     fee_type: Fees::Percentage,
                     module_name: Modules::Marketplace.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -545,6 +768,63 @@ mod actions {
                 assert_eq!(balance.amount, Uint128::new(330_000));
             }
 
+            #[test]
+            fn test_multiple_coins() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    "komple",
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str("0.04").unwrap(),
+                        address: Some(KOMPLE.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    "community",
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str("0.02").unwrap(),
+                        address: Some(COMMUNITY.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+
+                let msg = ExecuteMsg::Distribute {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr,
+                        &msg,
+                        &[coin(60_000, NATIVE_DENOM), coin(300_000, OTHER_DENOM)],
+                    )
+                    .unwrap();
+
+                let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(40_000));
+                let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(20_000));
+                let balance = app.wrap().query_balance(KOMPLE, OTHER_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(200_000));
+                let balance = app.wrap().query_balance(COMMUNITY, OTHER_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(100_000));
+            }
+
             #[test]
             fn test_custom_addresses() {
                 let mut app = mock_app();
@@ -559,6 +839,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -571,6 +852,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.02").unwrap(),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -583,6 +865,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.03").unwrap(),
                         address: Some(PAYMENT.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -603,6 +886,7 @@ mod actions {
                             address: NEW_PAYMENT.to_string(),
                         },
                     ]),
+                    discount_address: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -640,6 +924,7 @@ mod actions {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -648,6 +933,7 @@ mod actions {
                     fee_type: Fees::Percentage,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let err = app
                     .execute_contract(
@@ -683,6 +969,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -695,6 +982,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(500_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -707,6 +995,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(250_000),
                         address: Some(PAYMENT.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -715,6 +1004,7 @@ mod actions {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -745,6 +1035,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -757,6 +1048,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(500_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -769,6 +1061,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(250_000),
                         address: Some(PAYMENT.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -793,6 +1086,7 @@ mod actions {
                             address: NEW_PAYMENT.to_string(),
                         },
                     ]),
+                    discount_address: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -830,6 +1124,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -838,6 +1133,7 @@ mod actions {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Mint.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let err = app
                     .execute_contract(
@@ -867,6 +1163,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -879,6 +1176,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(500_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -891,6 +1189,7 @@ mod actions {
                     to_binary(&FixedPayment {
                         value: Uint128::new(250_000),
                         address: Some(PAYMENT.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -899,6 +1198,7 @@ mod actions {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let err = app
                     .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
@@ -912,6 +1212,7 @@ mod actions {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 };
                 let err = app
                     .execute_contract(
@@ -930,6 +1231,2324 @@ mod actions {
                     .to_string()
                 );
             }
+
+            #[test]
+            fn test_denom_prices() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Fixed,
+                    Modules::Hub.as_str(),
+                    "creation",
+                    to_binary(&FixedPayment {
+                        value: Uint128::new(1_000_000),
+                        address: Some(COMMUNITY.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Fixed,
+                    Modules::Hub.as_str(),
+                    "random",
+                    to_binary(&FixedPayment {
+                        value: Uint128::new(250_000),
+                        address: Some(PAYMENT.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+
+                let msg = ExecuteMsg::SetFixedFeeDenom {
+                    module_name: Modules::Hub.to_string(),
+                    fee_name: "creation".to_string(),
+                    denom: OTHER_DENOM.to_string(),
+                    value: Uint128::new(100_000),
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let msg = QueryMsg::FixedFeeDenoms {
+                    module_name: Modules::Hub.to_string(),
+                    fee_name: "creation".to_string(),
+                };
+                let res: ResponseWrapper<Vec<Coin>> =
+                    app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+                assert_eq!(res.data, vec![coin(100_000, OTHER_DENOM)]);
+
+                // Fees without a denom price fall back to the default value
+                let msg = ExecuteMsg::Distribute {
+                    fee_type: Fees::Fixed,
+                    module_name: Modules::Hub.to_string(),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(1_250_000, OTHER_DENOM)],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    FundsError::InvalidFunds {
+                        got: "1250000".to_string(),
+                        expected: "350000".to_string()
+                    }
+                    .to_string()
+                );
+
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(350_000, OTHER_DENOM)],
+                    )
+                    .unwrap();
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(1_250_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                let balance = app.wrap().query_balance(COMMUNITY, OTHER_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(100_000));
+                let balance = app.wrap().query_balance(PAYMENT, OTHER_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(250_000));
+                let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(1_000_000));
+
+                // Multiple coins are not accepted for fixed fees
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(350_000, OTHER_DENOM), coin(500_000, NATIVE_DENOM)],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    FundsError::MissingFunds {}.to_string()
+                );
+
+                // Denom prices are removed with the fee
+                let msg = ExecuteMsg::RemoveFee {
+                    fee_type: Fees::Fixed,
+                    module_name: Modules::Hub.to_string(),
+                    fee_name: "creation".to_string(),
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let msg = QueryMsg::FixedFeeDenoms {
+                    module_name: Modules::Hub.to_string(),
+                    fee_name: "creation".to_string(),
+                };
+                let res: ResponseWrapper<Vec<Coin>> =
+                    app.wrap().query_wasm_smart(addr, &msg).unwrap();
+                assert!(res.data.is_empty());
+            }
+
+            #[test]
+            fn test_denom_price_without_fee() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                let msg = ExecuteMsg::SetFixedFeeDenom {
+                    module_name: Modules::Hub.to_string(),
+                    fee_name: "creation".to_string(),
+                    denom: OTHER_DENOM.to_string(),
+                    value: Uint128::new(100_000),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::FeeNotFound {}.to_string()
+                );
+            }
+        }
+
+        mod tiered {
+            use super::*;
+            use komple_framework_utils::funds::FundsError;
+
+            #[test]
+            fn test_happy_path() {
+                let mut app = mock_app();
+                let addr = setup_fee_contract(&mut app);
+
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    "komple",
+                    to_binary(&TieredPayment {
+                        address: Some(KOMPLE.to_string()),
+                        tiers: vec![
+                            FeeTier {
+                                min_amount: Uint128::zero(),
+                                value: Decimal::from_str("0.05").unwrap(),
+                            },
+                            FeeTier {
+                                min_amount: Uint128::new(1_000_000),
+                                value: Decimal::from_str("0.025").unwrap(),
+                            },
+                        ],
+                        min: None,
+                        max: None,
+                    })
+                    .unwrap(),
+                );
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    "community",
+                    to_binary(&TieredPayment {
+                        address: Some(COMMUNITY.to_string()),
+                        tiers: vec![FeeTier {
+                            min_amount: Uint128::zero(),
+                            value: Decimal::from_str("0.01").unwrap(),
+                        }],
+                        min: None,
+                        max: Some(Uint128::new(15_000)),
+                    })
+                    .unwrap(),
+                );
+
+                // 500_000 sale: 25_000 for komple, 5_000 for community
+                let msg = ExecuteMsg::DistributeTiered {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(500_000),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(30_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(25_000));
+                let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(5_000));
+
+                // 2_000_000 sale: 50_000 for komple, 15_000 for community
+                let msg = ExecuteMsg::DistributeTiered {
+                    module_name: Modules::Marketplace.to_string(),
+                    sale_amount: Uint128::new(2_000_000),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(70_000, NATIVE_DENOM)],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    FundsError::InvalidFunds {
+                        got: "70000".to_string(),
+                        expected: "65000".to_string(),
+                    }
+                    .to_string()
+                );
+
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr.clone(),
+                        &msg,
+                        &[coin(65_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(75_000));
+                let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(20_000));
+
+                let msg = ExecuteMsg::Distribute {
+                    fee_type: Fees::Tiered,
+                    module_name: Modules::Marketplace.to_string(),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        addr,
+                        &msg,
+                        &[coin(65_000, NATIVE_DENOM)],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::MissingSaleAmount {}.to_string()
+                );
+            }
+        }
+    }
+
+    mod pull_distribution {
+        use super::*;
+
+        fn setup_pull_fees(app: &mut App, addr: Addr) {
+            setup_fee(
+                app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.04").unwrap(),
+                    address: Some(KOMPLE.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+            setup_fee(
+                app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "community",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.02").unwrap(),
+                    address: Some(COMMUNITY.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+
+            let msg = ExecuteMsg::UpdateDistributionMode {
+                module_name: Modules::Marketplace.to_string(),
+                mode: DistributionModes::Pull,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap();
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_pull_fees(&mut app, addr.clone());
+
+            let msg = QueryMsg::DistributionMode {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let res: ResponseWrapper<DistributionModes> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, DistributionModes::Pull);
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(60_000, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(30_000, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            // Nothing is sent until the balances are claimed
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+            let balance = app
+                .wrap()
+                .query_balance(addr.clone(), NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount, Uint128::new(90_000));
+
+            let msg = QueryMsg::ClaimableBalances {
+                address: KOMPLE.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![ClaimableBalanceResponse {
+                    denom: NATIVE_DENOM.to_string(),
+                    is_native: true,
+                    amount: Uint128::new(60_000),
+                }]
+            );
+
+            let msg = ExecuteMsg::Claim {};
+            let _ = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(60_000));
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+
+            let msg = QueryMsg::ClaimableBalances {
+                address: KOMPLE.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert!(res.data.is_empty());
+
+            let msg = ExecuteMsg::Claim {};
+            let _ = app
+                .execute_contract(Addr::unchecked(COMMUNITY), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(30_000));
+
+            // Switching back to push mode sends the funds directly
+            let msg = ExecuteMsg::UpdateDistributionMode {
+                module_name: Modules::Marketplace.to_string(),
+                mode: DistributionModes::Push,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr,
+                    &msg,
+                    &[coin(60_000, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(100_000));
+        }
+
+        #[test]
+        fn test_nothing_to_claim() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_pull_fees(&mut app, addr.clone());
+
+            let msg = ExecuteMsg::Claim {};
+            let err = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoClaimableBalance {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = ExecuteMsg::UpdateDistributionMode {
+                module_name: Modules::Marketplace.to_string(),
+                mode: DistributionModes::Pull,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(COMMUNITY), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+
+    mod remainder {
+        use super::*;
+
+        fn setup_remainder_fees(app: &mut App, addr: Addr, policy: Option<RemainderPolicies>) {
+            for (fee_name, value, address) in [
+                ("komple", "0.04", KOMPLE),
+                ("community", "0.02", COMMUNITY),
+                ("payment", "0.03", PAYMENT),
+            ] {
+                setup_fee(
+                    app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    fee_name,
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str(value).unwrap(),
+                        address: Some(address.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+            }
+
+            if let Some(policy) = policy {
+                let msg = ExecuteMsg::UpdateRemainderPolicy {
+                    module_name: Modules::Marketplace.to_string(),
+                    policy,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                    .unwrap();
+            }
+        }
+
+        fn distribute(app: &mut App, addr: Addr, amount: u128) {
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr,
+                    &msg,
+                    &[coin(amount, NATIVE_DENOM)],
+                )
+                .unwrap();
+        }
+
+        #[test]
+        fn test_keep_and_sweep() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_remainder_fees(&mut app, addr.clone(), None);
+
+            // 44 for komple, 22 for community, 33 for payment
+            distribute(&mut app, addr.clone(), 100);
+            distribute(&mut app, addr.clone(), 100);
+
+            let msg = QueryMsg::Dust {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![ClaimableBalanceResponse {
+                    denom: NATIVE_DENOM.to_string(),
+                    is_native: true,
+                    amount: Uint128::new(2),
+                }]
+            );
+
+            let msg = ExecuteMsg::SweepDust {
+                recipient: "juno..treasury".to_string(),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let balance = app
+                .wrap()
+                .query_balance("juno..treasury", NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount, Uint128::new(2));
+            let balance = app
+                .wrap()
+                .query_balance(addr.clone(), NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoDust {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_fee_without_address() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            for (fee_name, value, address) in
+                [("komple", "0.04", Some(KOMPLE)), ("unset", "0.06", None)]
+            {
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    fee_name,
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str(value).unwrap(),
+                        address: address.map(|address| address.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+            }
+
+            // Share of the fee without an address is kept as dust
+            distribute(&mut app, addr.clone(), 100);
+
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(40));
+
+            let msg = QueryMsg::Dust {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![ClaimableBalanceResponse {
+                    denom: NATIVE_DENOM.to_string(),
+                    is_native: true,
+                    amount: Uint128::new(60),
+                }]
+            );
+        }
+
+        #[test]
+        fn test_fee_name() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_remainder_fees(
+                &mut app,
+                addr.clone(),
+                Some(RemainderPolicies::FeeName {
+                    fee_name: "komple".to_string(),
+                }),
+            );
+
+            distribute(&mut app, addr.clone(), 100);
+
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(45));
+            let balance = app
+                .wrap()
+                .query_balance(addr.clone(), NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+
+            let msg = ExecuteMsg::UpdateRemainderPolicy {
+                module_name: Modules::Marketplace.to_string(),
+                policy: RemainderPolicies::FeeName {
+                    fee_name: "random".to_string(),
+                },
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::FeeNotFound {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_round_robin() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_remainder_fees(&mut app, addr.clone(), Some(RemainderPolicies::RoundRobin));
+
+            // Remainder of 2 goes to community and komple
+            distribute(&mut app, addr.clone(), 101);
+
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(23));
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(45));
+            let balance = app.wrap().query_balance(PAYMENT, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(33));
+
+            // Remainder of 1 continues with payment
+            distribute(&mut app, addr.clone(), 100);
+
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(45));
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(89));
+            let balance = app.wrap().query_balance(PAYMENT, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(67));
+
+            let balance = app.wrap().query_balance(addr, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+        }
+
+        #[test]
+        fn test_refund() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            setup_remainder_fees(&mut app, addr.clone(), Some(RemainderPolicies::Refund));
+
+            distribute(&mut app, addr.clone(), 100);
+
+            let balance = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(1_999_901));
+            let balance = app
+                .wrap()
+                .query_balance(addr.clone(), NATIVE_DENOM)
+                .unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+
+            let msg = QueryMsg::RemainderPolicy {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let res: ResponseWrapper<RemainderPolicies> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(res.data, RemainderPolicies::Refund);
+        }
+    }
+
+    mod discount {
+        use super::*;
+
+        fn get_rule(condition: DiscountConditions, value: &str) -> DiscountRule {
+            DiscountRule {
+                collection_addr: "contract5".to_string(),
+                condition,
+                value: Decimal::from_str(value).unwrap(),
+            }
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let rules = vec![
+                get_rule(DiscountConditions::MinTokens { amount: 3 }, "0.1"),
+                get_rule(
+                    DiscountConditions::Trait {
+                        trait_type: "tier".to_string(),
+                        value: "gold".to_string(),
+                    },
+                    "0.25",
+                ),
+            ];
+            let msg = ExecuteMsg::SetDiscount {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                rules: rules.clone(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::DiscountRules {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: ResponseWrapper<Vec<DiscountRule>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, rules);
+
+            let msg = ExecuteMsg::RemoveDiscount {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::DiscountRules {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: ResponseWrapper<Vec<DiscountRule>> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert!(res.data.is_empty());
+        }
+
+        #[test]
+        fn test_distribute_without_discount_address() {
+            let msg: ExecuteMsg = from_slice(
+                br#"{"distribute":{"fee_type":"percentage","module_name":"marketplace","custom_payment_addresses":null}}"#,
+            )
+            .unwrap();
+            assert_eq!(
+                msg,
+                ExecuteMsg::Distribute {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    custom_payment_addresses: None,
+                    discount_address: None,
+                }
+            );
+        }
+
+        #[test]
+        fn test_invalid_discount() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let invalid_rules = vec![
+                vec![],
+                vec![get_rule(DiscountConditions::MinTokens { amount: 1 }, "1.1")],
+                vec![get_rule(DiscountConditions::MinTokens { amount: 1 }, "0")],
+                vec![get_rule(DiscountConditions::MinTokens { amount: 0 }, "0.1")],
+                vec![get_rule(
+                    DiscountConditions::MinTokens { amount: 31 },
+                    "0.1",
+                )],
+                vec![get_rule(DiscountConditions::MinTokens { amount: 1 }, "0.1"); 6],
+            ];
+            for rules in invalid_rules {
+                let msg = ExecuteMsg::SetDiscount {
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                    rules,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidDiscount {}.to_string()
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = ExecuteMsg::SetDiscount {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                rules: vec![get_rule(DiscountConditions::MinTokens { amount: 1 }, "0.1")],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(PAYMENT), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+
+    mod exemptions {
+        use super::*;
+
+        const LAUNCHPAD: &str = "juno..launchpad";
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            for (fee_name, value, address) in
+                [("komple", "0.04", KOMPLE), ("community", "0.02", COMMUNITY)]
+            {
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    fee_name,
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str(value).unwrap(),
+                        address: Some(address.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+            }
+
+            let msg = ExecuteMsg::AddExemptions {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                addrs: vec![LAUNCHPAD.to_string(), PAYMENT.to_string()],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::Exemptions {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<String>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, vec![LAUNCHPAD.to_string(), PAYMENT.to_string()]);
+
+            let msg = QueryMsg::TotalDiscountedPercentageFees {
+                module_name: Modules::Marketplace.to_string(),
+                address: LAUNCHPAD.to_string(),
+            };
+            let res: ResponseWrapper<Decimal> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, Decimal::from_str("0.02").unwrap());
+
+            // Exempt fee is skipped in the distribution
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: Some(LAUNCHPAD.to_string()),
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(200, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(200));
+
+            let msg = ExecuteMsg::RemoveExemptions {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                addrs: vec![LAUNCHPAD.to_string()],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::IsExempt {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                address: LAUNCHPAD.to_string(),
+            };
+            let res: ResponseWrapper<bool> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert!(!res.data);
+
+            let msg = QueryMsg::IsExempt {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                address: PAYMENT.to_string(),
+            };
+            let res: ResponseWrapper<bool> = app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert!(res.data);
+        }
+
+        #[test]
+        fn test_tiered_fees() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            for (fee_name, value, address) in
+                [("komple", "0.04", KOMPLE), ("community", "0.02", COMMUNITY)]
+            {
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Tiered,
+                    Modules::Marketplace.as_str(),
+                    fee_name,
+                    to_binary(&TieredPayment {
+                        address: Some(address.to_string()),
+                        tiers: vec![FeeTier {
+                            min_amount: Uint128::zero(),
+                            value: Decimal::from_str(value).unwrap(),
+                        }],
+                        min: None,
+                        max: None,
+                    })
+                    .unwrap(),
+                );
+            }
+
+            let msg = ExecuteMsg::AddExemptions {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                addrs: vec![LAUNCHPAD.to_string()],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::TotalDiscountedTieredFees {
+                module_name: Modules::Marketplace.to_string(),
+                sale_amount: Uint128::new(10_000),
+                address: LAUNCHPAD.to_string(),
+            };
+            let res: ResponseWrapper<Uint128> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, Uint128::new(200));
+
+            // Exempt fee is skipped in the distribution
+            let msg = ExecuteMsg::DistributeTiered {
+                module_name: Modules::Marketplace.to_string(),
+                sale_amount: Uint128::new(10_000),
+                custom_payment_addresses: None,
+                discount_address: Some(LAUNCHPAD.to_string()),
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr,
+                    &msg,
+                    &[coin(200, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(200));
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = ExecuteMsg::AddExemptions {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                addrs: vec![LAUNCHPAD.to_string()],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(LAUNCHPAD), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+
+    mod pending_fees {
+        use super::*;
+
+        fn percentage_data(value: &str) -> Binary {
+            to_binary(&PercentagePayment {
+                value: Decimal::from_str(value).unwrap(),
+                address: Some(KOMPLE.to_string()),
+                group: None,
+            })
+            .unwrap()
+        }
+
+        fn update_notice_period(app: &mut App, addr: Addr, notice_period: u64) {
+            let msg = ExecuteMsg::UpdateNoticePeriod {
+                module_name: Modules::Marketplace.to_string(),
+                notice_period,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap();
+        }
+
+        fn query_komple_fee(app: &App, addr: Addr) -> Decimal {
+            let msg = QueryMsg::PercentageFee {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: ResponseWrapper<PercentageFeeResponse> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            res.data.value
+        }
+
+        fn query_pending_fees(app: &App, addr: Addr) -> Vec<PendingFeeResponse> {
+            let msg = QueryMsg::PendingFees {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<PendingFeeResponse>> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            res.data
+        }
+
+        #[test]
+        fn test_set_fee_with_notice_period() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.05"),
+            );
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "community",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.05").unwrap(),
+                    address: Some(COMMUNITY.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+            update_notice_period(&mut app, addr.clone(), 3600);
+
+            let msg = QueryMsg::NoticePeriod {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let res: ResponseWrapper<u64> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, 3600);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.1"),
+            );
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.05").unwrap()
+            );
+
+            let activation_time = app.block_info().time.plus_seconds(3600);
+            assert_eq!(
+                query_pending_fees(&app, addr.clone()),
+                vec![PendingFeeResponse {
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                    fee_type: Fees::Percentage,
+                    data: Some(percentage_data("0.1")),
+                    activation_time,
+                }]
+            );
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(1_000, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(500));
+
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+            // Queries resolve the due fee change before it is saved
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.1").unwrap()
+            );
+            assert_eq!(query_pending_fees(&app, addr.clone()).len(), 1);
+
+            // Distribution applies the due fee change first
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(1_000, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(1_166));
+            let balance = app.wrap().query_balance(COMMUNITY, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(833));
+
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.1").unwrap()
+            );
+            assert!(query_pending_fees(&app, addr).is_empty());
+        }
+
+        #[test]
+        fn test_schedule_and_apply() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.05"),
+            );
+            update_notice_period(&mut app, addr.clone(), 3600);
+
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                data: percentage_data("0.2"),
+                activation_time: app.block_info().time.plus_seconds(1800),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidActivationTime {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                data: percentage_data("1.1"),
+                activation_time: app.block_info().time.plus_seconds(7200),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidFee {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                data: percentage_data("0.2"),
+                activation_time: app.block_info().time.plus_seconds(7200),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::ApplyPendingFees {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                .unwrap();
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.05").unwrap()
+            );
+            assert_eq!(query_pending_fees(&app, addr.clone()).len(), 1);
+
+            app.update_block(|block| block.time = block.time.plus_seconds(7200));
+
+            let _ = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                .unwrap();
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.2").unwrap()
+            );
+            assert!(query_pending_fees(&app, addr).is_empty());
+        }
+
+        #[test]
+        fn test_cancel_pending_fee() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            update_notice_period(&mut app, addr.clone(), 3600);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.05"),
+            );
+
+            let msg = ExecuteMsg::CancelPendingFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            assert!(query_pending_fees(&app, addr.clone()).is_empty());
+
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::PendingFeeNotFound {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_invalid_pending_fee() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                data: percentage_data("0.6"),
+                activation_time: app.block_info().time.plus_seconds(100),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            // New fees are checked together with the staged changes
+            let msg = ExecuteMsg::SetFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "community".to_string(),
+                data: percentage_data("0.5"),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidTotalFee {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "community".to_string(),
+                data: percentage_data("0.6"),
+                activation_time: app.block_info().time.plus_seconds(200),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidTotalFee {}.to_string()
+            );
+            assert_eq!(query_pending_fees(&app, addr.clone()).len(), 1);
+
+            let msg = ExecuteMsg::CancelPendingFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "community",
+                percentage_data("0.5"),
+            );
+
+            let msg = QueryMsg::PercentageFee {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: Result<PercentageFeeResponse, StdError> =
+                app.wrap().query_wasm_smart(addr, &msg);
+            assert!(res.is_err());
+        }
+
+        #[test]
+        fn test_multiple_pending_fees() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "community",
+                percentage_data("0.6"),
+            );
+
+            let schedule_msg =
+                |fee_name: &str, value: &str, activation_time: Timestamp| ExecuteMsg::ScheduleFee {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: fee_name.to_string(),
+                    data: percentage_data(value),
+                    activation_time,
+                };
+            let time = app.block_info().time;
+
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &schedule_msg("community", "0.1", time.plus_seconds(200)),
+                    &[],
+                )
+                .unwrap();
+
+            // Komple fee would be active before the community fee is lowered
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &schedule_msg("komple", "0.5", time.plus_seconds(100)),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidTotalFee {}.to_string()
+            );
+
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &schedule_msg("komple", "0.5", time.plus_seconds(300)),
+                    &[],
+                )
+                .unwrap();
+
+            // Fixed fee with the same name does not replace the percentage change
+            let msg = ExecuteMsg::ScheduleFee {
+                fee_type: Fees::Fixed,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                data: to_binary(&FixedPayment {
+                    value: Uint128::new(100),
+                    address: Some(KOMPLE.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+                activation_time: time.plus_seconds(300),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            assert_eq!(query_pending_fees(&app, addr.clone()).len(), 2);
+
+            // Komple fee depends on the lowered community fee
+            let msg = ExecuteMsg::CancelPendingFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "community".to_string(),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidTotalFee {}.to_string()
+            );
+
+            app.update_block(|block| block.time = block.time.plus_seconds(300));
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(600, NATIVE_DENOM)],
+                )
+                .unwrap();
+            assert!(query_pending_fees(&app, addr.clone()).is_empty());
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.5").unwrap()
+            );
+
+            // Both fees are paid to komple
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(600));
+
+            let msg = QueryMsg::FixedFee {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: ResponseWrapper<FixedFeeResponse> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(res.data.value, Uint128::new(100));
+        }
+
+        #[test]
+        fn test_notice_period_reduction() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+            update_notice_period(&mut app, addr.clone(), 3600);
+
+            // Reduction waits for the current notice period
+            update_notice_period(&mut app, addr.clone(), 0);
+
+            let msg = QueryMsg::NoticePeriod {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let res: ResponseWrapper<u64> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, 3600);
+
+            let pending_msg = QueryMsg::PendingNoticePeriod {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let res: ResponseWrapper<Option<PendingNoticePeriod>> = app
+                .wrap()
+                .query_wasm_smart(addr.clone(), &pending_msg)
+                .unwrap();
+            assert_eq!(
+                res.data,
+                Some(PendingNoticePeriod {
+                    notice_period: 0,
+                    activation_time: app.block_info().time.plus_seconds(3600),
+                })
+            );
+
+            // Fee changes are still staged
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.05"),
+            );
+            assert_eq!(query_pending_fees(&app, addr.clone()).len(), 1);
+
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+            let res: ResponseWrapper<u64> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data, 0);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.1"),
+            );
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.1").unwrap()
+            );
+            assert!(query_pending_fees(&app, addr.clone()).is_empty());
+
+            let res: ResponseWrapper<Option<PendingNoticePeriod>> =
+                app.wrap().query_wasm_smart(addr, &pending_msg).unwrap();
+            assert_eq!(res.data, None);
+        }
+
+        #[test]
+        fn test_staged_removal_and_denom_prices() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                percentage_data("0.05"),
+            );
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Fixed,
+                Modules::Marketplace.as_str(),
+                "listing",
+                to_binary(&FixedPayment {
+                    value: Uint128::new(1_000),
+                    address: Some(COMMUNITY.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+            update_notice_period(&mut app, addr.clone(), 3600);
+
+            let msg = ExecuteMsg::RemoveFee {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = ExecuteMsg::SetFixedFeeDenom {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "listing".to_string(),
+                denom: OTHER_DENOM.to_string(),
+                value: Uint128::new(500),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let activation_time = app.block_info().time.plus_seconds(3600);
+            assert_eq!(
+                query_pending_fees(&app, addr.clone()),
+                vec![PendingFeeResponse {
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                    fee_type: Fees::Percentage,
+                    data: None,
+                    activation_time,
+                }]
+            );
+            let msg = QueryMsg::PendingFixedFeeDenoms {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "listing".to_string(),
+            };
+            let res: ResponseWrapper<Vec<PendingFixedFeeDenomResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![PendingFixedFeeDenomResponse {
+                    denom: OTHER_DENOM.to_string(),
+                    value: Some(Uint128::new(500)),
+                    activation_time,
+                }]
+            );
+
+            let denoms_msg = QueryMsg::FixedFeeDenoms {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "listing".to_string(),
+            };
+            let res: ResponseWrapper<Vec<Coin>> = app
+                .wrap()
+                .query_wasm_smart(addr.clone(), &denoms_msg)
+                .unwrap();
+            assert!(res.data.is_empty());
+            assert_eq!(
+                query_komple_fee(&app, addr.clone()),
+                Decimal::from_str("0.05").unwrap()
+            );
+
+            app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+            // Queries resolve the due changes before they are saved
+            let res: ResponseWrapper<Vec<Coin>> = app
+                .wrap()
+                .query_wasm_smart(addr.clone(), &denoms_msg)
+                .unwrap();
+            assert_eq!(res.data, vec![coin(500, OTHER_DENOM)]);
+
+            let total_msg = QueryMsg::TotalPercentageFees {
+                module_name: Modules::Marketplace.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Decimal> = app
+                .wrap()
+                .query_wasm_smart(addr.clone(), &total_msg)
+                .unwrap();
+            assert_eq!(res.data, Decimal::zero());
+
+            let msg = ExecuteMsg::ApplyPendingFees {
+                module_name: Modules::Marketplace.to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                .unwrap();
+            assert!(query_pending_fees(&app, addr.clone()).is_empty());
+
+            let msg = QueryMsg::PercentageFee {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: Result<PercentageFeeResponse, StdError> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg);
+            assert!(res.is_err());
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Fixed,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr,
+                    &msg,
+                    &[coin(500, OTHER_DENOM)],
+                )
+                .unwrap();
+            let balance = app.wrap().query_balance(COMMUNITY, OTHER_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(500));
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msgs = vec![
+                ExecuteMsg::UpdateNoticePeriod {
+                    module_name: Modules::Marketplace.to_string(),
+                    notice_period: 3600,
+                },
+                ExecuteMsg::ScheduleFee {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                    data: percentage_data("0.05"),
+                    activation_time: app.block_info().time.plus_seconds(100),
+                },
+                ExecuteMsg::CancelPendingFee {
+                    fee_type: Fees::Percentage,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: "komple".to_string(),
+                },
+            ];
+            for msg in msgs {
+                let err = app
+                    .execute_contract(Addr::unchecked(KOMPLE), addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+            }
+        }
+    }
+
+    mod revenue {
+        use super::*;
+
+        fn distribute(app: &mut App, addr: Addr, funds: &[Coin]) {
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, funds)
+                .unwrap();
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            for (fee_name, value, address) in
+                [("komple", "0.04", KOMPLE), ("community", "0.02", COMMUNITY)]
+            {
+                setup_fee(
+                    &mut app,
+                    addr.clone(),
+                    Fees::Percentage,
+                    Modules::Marketplace.as_str(),
+                    fee_name,
+                    to_binary(&PercentagePayment {
+                        value: Decimal::from_str(value).unwrap(),
+                        address: Some(address.to_string()),
+                        group: None,
+                    })
+                    .unwrap(),
+                );
+            }
+
+            let day = app.block_info().time.seconds() / 86_400;
+
+            // 400 native and 200 other for komple
+            // 200 native and 100 other for community
+            distribute(
+                &mut app,
+                addr.clone(),
+                &[coin(600, NATIVE_DENOM), coin(300, OTHER_DENOM)],
+            );
+
+            app.update_block(|block| block.time = block.time.plus_seconds(86_400));
+
+            distribute(&mut app, addr.clone(), &[coin(600, NATIVE_DENOM)]);
+
+            let msg = QueryMsg::FeeRevenue {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+            };
+            let res: ResponseWrapper<Vec<Coin>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![coin(800, NATIVE_DENOM), coin(200, OTHER_DENOM)]
+            );
+
+            let msg = QueryMsg::DailyFeeRevenue {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<DailyRevenueResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![
+                    DailyRevenueResponse {
+                        day,
+                        amounts: vec![coin(400, NATIVE_DENOM), coin(200, OTHER_DENOM)],
+                    },
+                    DailyRevenueResponse {
+                        day: day + 1,
+                        amounts: vec![coin(400, NATIVE_DENOM)],
+                    },
+                ]
+            );
+
+            let msg = QueryMsg::DailyFeeRevenue {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "komple".to_string(),
+                start_after: Some(day),
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<DailyRevenueResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data.len(), 1);
+            assert_eq!(res.data[0].day, day + 1);
+
+            let msg = QueryMsg::RecipientRevenue {
+                address: COMMUNITY.to_string(),
+            };
+            let res: ResponseWrapper<Vec<Coin>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![coin(400, NATIVE_DENOM), coin(100, OTHER_DENOM)]
+            );
+
+            let msg = QueryMsg::DailyRecipientRevenue {
+                address: COMMUNITY.to_string(),
+                start_after: None,
+                limit: Some(1),
+            };
+            let res: ResponseWrapper<Vec<DailyRevenueResponse>> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![DailyRevenueResponse {
+                    day,
+                    amounts: vec![coin(200, NATIVE_DENOM), coin(100, OTHER_DENOM)],
+                }]
+            );
+        }
+    }
+
+    mod revenue_split {
+        use super::*;
+
+        fn revenue_share(name: &str, address: &str, share: &str) -> RevenueShare {
+            RevenueShare {
+                name: name.to_string(),
+                address: Some(address.to_string()),
+                share: Decimal::from_str(share).unwrap(),
+                group: None,
+            }
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            // Existing and staged fees of the module are replaced
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                &MintFees::new_revenue(1),
+                "old",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.5").unwrap(),
+                    address: Some(PAYMENT.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+            let msg = ExecuteMsg::UpdateNoticePeriod {
+                module_name: MintFees::new_revenue(1),
+                notice_period: 100,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                &MintFees::new_revenue(1),
+                "staged",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.1").unwrap(),
+                    address: Some(PAYMENT.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+
+            let msg = ExecuteMsg::SetRevenueSplit {
+                collection_id: 1,
+                shares: vec![
+                    revenue_share("artist", KOMPLE, "0.75"),
+                    revenue_share("charity", COMMUNITY, "0.25"),
+                ],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(PAYMENT), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PercentageFees {
+                module_name: MintFees::new_revenue(1),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<PercentageFeeResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data.len(), 2);
+            assert_eq!(res.data[0].fee_name, "artist");
+            assert_eq!(res.data[1].fee_name, "charity");
+
+            let msg = QueryMsg::PendingFees {
+                fee_type: Fees::Percentage,
+                module_name: MintFees::new_revenue(1),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<PendingFeeResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert!(res.data.is_empty());
+
+            // Split is distributed with the pull mode of the module
+            let msg = ExecuteMsg::UpdateDistributionMode {
+                module_name: MintFees::new_revenue(1),
+                mode: DistributionModes::Pull,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: MintFees::new_revenue(1),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(100, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            let msg = QueryMsg::ClaimableBalances {
+                address: KOMPLE.to_string(),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data[0].amount, Uint128::new(75));
+
+            // Empty split removes the fees
+            let msg = ExecuteMsg::SetRevenueSplit {
+                collection_id: 1,
+                shares: vec![],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::TotalPercentageFees {
+                module_name: MintFees::new_revenue(1),
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Decimal> = app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(res.data, Decimal::zero());
+        }
+
+        #[test]
+        fn test_invalid_split() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            for shares in [
+                vec![revenue_share("artist", KOMPLE, "0.5")],
+                vec![
+                    revenue_share("artist", KOMPLE, "1"),
+                    revenue_share("charity", COMMUNITY, "0"),
+                ],
+                vec![
+                    revenue_share("artist", KOMPLE, "0.5"),
+                    revenue_share("artist", COMMUNITY, "0.5"),
+                ],
+                vec![RevenueShare {
+                    name: "artist".to_string(),
+                    address: None,
+                    share: Decimal::one(),
+                    group: None,
+                }],
+            ] {
+                let msg = ExecuteMsg::SetRevenueSplit {
+                    collection_id: 1,
+                    shares,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidRevenueSplit {}.to_string()
+                );
+            }
+        }
+    }
+
+    mod fee_groups {
+        use super::*;
+
+        const DAO: &str = "juno..dao";
+        const GRANTS: &str = "juno..grants";
+
+        fn percentage_fee_msg(
+            module_name: &str,
+            fee_name: &str,
+            address: Option<&str>,
+            group: Option<&str>,
+        ) -> ExecuteMsg {
+            ExecuteMsg::SetFee {
+                fee_type: Fees::Percentage,
+                module_name: module_name.to_string(),
+                fee_name: fee_name.to_string(),
+                data: to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.02").unwrap(),
+                    address: address.map(|a| a.to_string()),
+                    group: group.map(|g| g.to_string()),
+                })
+                .unwrap(),
+            }
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.04").unwrap(),
+                    address: Some(KOMPLE.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+
+            let msg = percentage_fee_msg(
+                Modules::Marketplace.as_str(),
+                "community",
+                None,
+                Some("community_split"),
+            );
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = percentage_fee_msg("community_split", "dao", Some(DAO), None);
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = percentage_fee_msg("community_split", "grants", Some(GRANTS), None);
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PercentageFee {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "community".to_string(),
+            };
+            let res: ResponseWrapper<PercentageFeeResponse> =
+                app.wrap().query_wasm_smart(addr.clone(), &msg).unwrap();
+            assert_eq!(res.data.address, None);
+            assert_eq!(res.data.group, Some("community_split".to_string()));
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(603, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            // 402 for komple and 201 for community
+            // Community is split between dao and grants
+            // Rounding remainder goes to dao
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(402));
+            let balance = app.wrap().query_balance(DAO, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(101));
+            let balance = app.wrap().query_balance(GRANTS, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(100));
+
+            let msg = QueryMsg::FeeRevenue {
+                module_name: Modules::Marketplace.to_string(),
+                fee_name: "community".to_string(),
+            };
+            let res: ResponseWrapper<Vec<Coin>> = app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(res.data, vec![coin(201, NATIVE_DENOM)]);
+        }
+
+        #[test]
+        fn test_group_without_fees() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            setup_fee(
+                &mut app,
+                addr.clone(),
+                Fees::Percentage,
+                Modules::Marketplace.as_str(),
+                "komple",
+                to_binary(&PercentagePayment {
+                    value: Decimal::from_str("0.04").unwrap(),
+                    address: Some(KOMPLE.to_string()),
+                    group: None,
+                })
+                .unwrap(),
+            );
+
+            let msg = percentage_fee_msg("community_split", "dao", Some(DAO), None);
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = percentage_fee_msg(
+                Modules::Marketplace.as_str(),
+                "community",
+                None,
+                Some("community_split"),
+            );
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::RemoveFee {
+                fee_type: Fees::Percentage,
+                module_name: "community_split".to_string(),
+                fee_name: "dao".to_string(),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    addr.clone(),
+                    &msg,
+                    &[coin(603, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            // 402 for komple and 201 for community
+            // Community group has no fees so its share is kept as dust
+            let balance = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::new(402));
+            let balance = app.wrap().query_balance(DAO, NATIVE_DENOM).unwrap();
+            assert_eq!(balance.amount, Uint128::zero());
+
+            let msg = QueryMsg::Dust {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> =
+                app.wrap().query_wasm_smart(addr, &msg).unwrap();
+            assert_eq!(
+                res.data,
+                vec![ClaimableBalanceResponse {
+                    denom: NATIVE_DENOM.to_string(),
+                    is_native: true,
+                    amount: Uint128::new(201),
+                }]
+            );
+        }
+
+        #[test]
+        fn test_invalid_group() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = percentage_fee_msg(
+                Modules::Marketplace.as_str(),
+                "community",
+                Some(COMMUNITY),
+                Some("community_split"),
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidFeeGroup {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_cycle() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            let msg = percentage_fee_msg(
+                Modules::Marketplace.as_str(),
+                "community",
+                None,
+                Some(Modules::Marketplace.as_str()),
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::FeeGroupCycle {}.to_string()
+            );
+
+            let msg = percentage_fee_msg(
+                Modules::Marketplace.as_str(),
+                "community",
+                None,
+                Some("community_split"),
+            );
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+            let msg = percentage_fee_msg("community_split", "grants", None, Some("grants_split"));
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = percentage_fee_msg(
+                "grants_split",
+                "marketplace",
+                None,
+                Some(Modules::Marketplace.as_str()),
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::FeeGroupCycle {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_max_depth() {
+            let mut app = mock_app();
+            let addr = setup_fee_contract(&mut app);
+
+            for (module_name, group) in [("a", "b"), ("b", "c"), ("c", "d")] {
+                let msg = percentage_fee_msg(module_name, "split", None, Some(group));
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap();
+            }
+
+            for (module_name, group) in [("d", "e"), ("x", "a")] {
+                let msg = percentage_fee_msg(module_name, "split", None, Some(group));
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::MaxFeeGroupDepth {}.to_string()
+                );
+            }
         }
     }
 
@@ -1056,6 +3675,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.04").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1068,6 +3688,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.02").unwrap(),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1080,6 +3701,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.03").unwrap(),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1107,6 +3729,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1119,6 +3742,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1131,6 +3755,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1162,6 +3787,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.04").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1174,6 +3800,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.02").unwrap(),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1186,6 +3813,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.03").unwrap(),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1198,6 +3826,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.1").unwrap(),
                     address: Some("test".to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1259,6 +3888,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.04").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1271,6 +3901,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.02").unwrap(),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1283,6 +3914,7 @@ mod queries {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.03").unwrap(),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1333,6 +3965,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1345,6 +3978,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1357,6 +3991,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1369,6 +4004,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(100_000),
                     address: Some("test".to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1430,6 +4066,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1442,6 +4079,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1454,6 +4092,7 @@ mod queries {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -1516,6 +4155,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1528,6 +4168,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1540,6 +4181,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.02").unwrap(),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1571,6 +4213,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1583,6 +4226,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.04").unwrap(),
                         address: Some(KOMPLE.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1595,6 +4239,7 @@ mod queries {
                     to_binary(&PercentagePayment {
                         value: Decimal::from_str("0.02").unwrap(),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1638,6 +4283,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1650,6 +4296,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1662,6 +4309,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1693,6 +4341,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1705,6 +4354,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1717,6 +4367,7 @@ mod queries {
                     to_binary(&FixedPayment {
                         value: Uint128::new(1_000_000),
                         address: Some(COMMUNITY.to_string()),
+                        group: None,
                     })
                     .unwrap(),
                 );
@@ -1,14 +1,19 @@
 use cosmwasm_std::Decimal;
+use cosmwasm_std::{coin, Addr, Coin, Empty, Uint128};
 use cosmwasm_std::{to_binary, Binary};
-use cosmwasm_std::{Addr, Empty, Uint128};
 use cw20::Cw20Coin;
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 use cw20_base::msg::InstantiateMsg as Cw20InstantiateMsg;
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 use komple_framework_fee_module::msg::CustomPaymentAddress;
-use komple_framework_fee_module::msg::ExecuteMsg;
-use komple_framework_types::modules::fee::{Fees, FixedPayment, PercentagePayment};
+use komple_framework_fee_module::msg::{
+    ClaimableBalanceResponse, DailyRevenueResponse, ExecuteMsg, QueryMsg,
+};
+use komple_framework_types::modules::fee::{
+    DistributionModes, Fees, FixedPayment, PercentagePayment,
+};
 use komple_framework_types::modules::Modules;
+use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
 use komple_framework_utils::funds::FundsError;
 use std::str::FromStr;
@@ -114,6 +119,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.04").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -126,6 +132,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.02").unwrap(),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -138,6 +145,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.03").unwrap(),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -151,6 +159,7 @@ mod distribute {
                     fee_type: Fees::Percentage,
                     module_name: Modules::Marketplace.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -202,6 +211,7 @@ mod distribute {
                     fee_type: Fees::Percentage,
                     module_name: Modules::Marketplace.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -261,6 +271,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.04").unwrap(),
                     address: Some(KOMPLE.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -273,6 +284,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.02").unwrap(),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -285,6 +297,7 @@ mod distribute {
                 to_binary(&PercentagePayment {
                     value: Decimal::from_str("0.03").unwrap(),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -310,6 +323,7 @@ mod distribute {
                             address: NEW_PAYMENT.to_string(),
                         },
                     ]),
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -377,6 +391,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -389,6 +404,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -401,6 +417,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -414,6 +431,7 @@ mod distribute {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -457,6 +475,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -469,6 +488,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -481,6 +501,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -510,6 +531,7 @@ mod distribute {
                             address: NEW_PAYMENT.to_string(),
                         },
                     ]),
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -573,6 +595,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(1_000_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -585,6 +608,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(500_000),
                     address: Some(COMMUNITY.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -597,6 +621,7 @@ mod distribute {
                 to_binary(&FixedPayment {
                     value: Uint128::new(250_000),
                     address: Some(PAYMENT.to_string()),
+                    group: None,
                 })
                 .unwrap(),
             );
@@ -610,6 +635,7 @@ mod distribute {
                     fee_type: Fees::Fixed,
                     module_name: Modules::Hub.to_string(),
                     custom_payment_addresses: None,
+                    discount_address: None,
                 })
                 .unwrap(),
             };
@@ -627,3 +653,166 @@ mod distribute {
         }
     }
 }
+
+mod pull_distribution {
+    use super::*;
+
+    #[test]
+    fn test_happy_path() {
+        let mut app = mock_app();
+        let fee_module_addr = setup_fee_contract(&mut app);
+
+        setup_fee(
+            &mut app,
+            fee_module_addr.clone(),
+            Fees::Fixed,
+            Modules::Hub.as_str(),
+            "creation",
+            to_binary(&FixedPayment {
+                value: Uint128::new(1_000_000),
+                address: Some(COMMUNITY.to_string()),
+                group: None,
+            })
+            .unwrap(),
+        );
+        setup_fee(
+            &mut app,
+            fee_module_addr.clone(),
+            Fees::Fixed,
+            Modules::Hub.as_str(),
+            "module_register",
+            to_binary(&FixedPayment {
+                value: Uint128::new(500_000),
+                address: Some(COMMUNITY.to_string()),
+                group: None,
+            })
+            .unwrap(),
+        );
+
+        let msg = ExecuteMsg::UpdateDistributionMode {
+            module_name: Modules::Hub.to_string(),
+            mode: DistributionModes::Pull,
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), fee_module_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let cw20_addr = setup_cw20_token(&mut app);
+
+        let msg = Cw20ExecuteMsg::Send {
+            contract: fee_module_addr.to_string(),
+            amount: Uint128::new(1_500_000),
+            msg: to_binary(&ExecuteMsg::Distribute {
+                fee_type: Fees::Fixed,
+                module_name: Modules::Hub.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            })
+            .unwrap(),
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), cw20_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let msg = QueryMsg::ClaimableBalances {
+            address: COMMUNITY.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: ResponseWrapper<Vec<ClaimableBalanceResponse>> = app
+            .wrap()
+            .query_wasm_smart(fee_module_addr.clone(), &msg)
+            .unwrap();
+        assert_eq!(
+            res.data,
+            vec![ClaimableBalanceResponse {
+                denom: cw20_addr.to_string(),
+                is_native: false,
+                amount: Uint128::new(1_500_000),
+            }]
+        );
+
+        let msg = ExecuteMsg::Claim {};
+        let _ = app
+            .execute_contract(Addr::unchecked(COMMUNITY), fee_module_addr, &msg, &[])
+            .unwrap();
+
+        let balance: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw20_addr,
+                &Cw20QueryMsg::Balance {
+                    address: COMMUNITY.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(balance.balance, Uint128::new(1_500_000));
+    }
+}
+
+mod revenue {
+    use super::*;
+
+    #[test]
+    fn test_happy_path() {
+        let mut app = mock_app();
+        let fee_module_addr = setup_fee_contract(&mut app);
+
+        setup_fee(
+            &mut app,
+            fee_module_addr.clone(),
+            Fees::Percentage,
+            Modules::Marketplace.as_str(),
+            "komple",
+            to_binary(&PercentagePayment {
+                value: Decimal::from_str("0.04").unwrap(),
+                address: Some(KOMPLE.to_string()),
+                group: None,
+            })
+            .unwrap(),
+        );
+
+        let cw20_addr = setup_cw20_token(&mut app);
+
+        let msg = Cw20ExecuteMsg::Send {
+            contract: fee_module_addr.to_string(),
+            amount: Uint128::new(160_000),
+            msg: to_binary(&ExecuteMsg::Distribute {
+                fee_type: Fees::Percentage,
+                module_name: Modules::Marketplace.to_string(),
+                custom_payment_addresses: None,
+                discount_address: None,
+            })
+            .unwrap(),
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), cw20_addr.clone(), &msg, &[])
+            .unwrap();
+
+        // Token address is used as the denom
+        let msg = QueryMsg::FeeRevenue {
+            module_name: Modules::Marketplace.to_string(),
+            fee_name: "komple".to_string(),
+        };
+        let res: ResponseWrapper<Vec<Coin>> = app
+            .wrap()
+            .query_wasm_smart(fee_module_addr.clone(), &msg)
+            .unwrap();
+        assert_eq!(res.data, vec![coin(160_000, cw20_addr.to_string())]);
+
+        let msg = QueryMsg::DailyRecipientRevenue {
+            address: KOMPLE.to_string(),
+            start_after: None,
+            limit: None,
+        };
+        let res: ResponseWrapper<Vec<DailyRevenueResponse>> =
+            app.wrap().query_wasm_smart(fee_module_addr, &msg).unwrap();
+        assert_eq!(
+            res.data,
+            vec![DailyRevenueResponse {
+                day: app.block_info().time.seconds() / 86_400,
+                amounts: vec![coin(160_000, cw20_addr.to_string())],
+            }]
+        );
+    }
+}
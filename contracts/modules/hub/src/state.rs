@@ -39,4 +39,7 @@ pub const MODULE_ID: Item<u64> = Item::new(MODULE_ID_NAMESPACE);
 pub const MODULE_TO_REGISTER: Item<String> = Item::new(MODULE_TO_REGISTER_NAMESPACE);
 
 /// Fee module address if hub is created through Marbu.
+///
+/// Platform fees on this fee module are taken from mint, marketplace and merge payments.
+/// It is only set on instantiate so the hub admin cannot remove it.
 pub const MARBU_FEE_MODULE: Item<Addr> = Item::new(MARBU_FEE_MODULE_NAMESPACE);
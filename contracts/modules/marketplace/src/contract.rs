@@ -32,7 +32,7 @@ use komple_framework_utils::{
 use semver::Version;
 use std::ops::Mul;
 
-use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg};
+use crate::msg::{ExecuteMsg, FeeQuoteResponse, InstantiateMsg, MigrateMsg, QueryMsg, ReceiveMsg};
 use crate::state::{
     Config, FixedListing, CONFIG, EXECUTE_LOCK, FIXED_LISTING, FUND_INFO, HUB_ADDR,
 };
@@ -372,7 +372,7 @@ fn execute_update_price(
 
 fn execute_buy(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     listing_type: Listing,
     collection_id: u32,
@@ -386,6 +386,7 @@ fn execute_buy(
     match listing_type {
         Listing::Fixed => _execute_buy_fixed_listing(
             deps,
+            &env,
             &info,
             collection_id,
             token_id,
@@ -418,7 +419,7 @@ fn execute_permission_buy(
 
     match listing_type {
         Listing::Fixed => {
-            _execute_buy_fixed_listing(deps, &info, collection_id, token_id, buyer, None)
+            _execute_buy_fixed_listing(deps, &env, &info, collection_id, token_id, buyer, None)
         }
         Listing::Auction => unimplemented!(),
     }
@@ -426,6 +427,7 @@ fn execute_permission_buy(
 
 fn _execute_buy_fixed_listing(
     deps: DepsMut,
+    env: &Env,
     info: &MessageInfo,
     collection_id: u32,
     token_id: u32,
@@ -465,21 +467,24 @@ fn _execute_buy_fixed_listing(
     let mut royalty_fee = Uint128::zero();
 
     // Process Marbu fee if exists on Hub
+    // Platform fees are enforced on every hub created with a Marbu fee module
+    let mut platform_fee = Uint128::zero();
     let res =
         StorageHelper::query_storage::<Addr>(&deps.querier, &hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
     if let Some(marbu_fee_module) = res {
-        process_marketplace_fees(
+        platform_fee = process_marketplace_fees(
             &deps,
             &fund_info,
             &mut sub_msgs,
             &marbu_fee_module,
             fixed_listing.price,
-            &mut marketplace_fee,
+            &buyer,
             Some(vec![FeeModuleCustomPaymentAddress {
                 fee_name: MarketplaceFees::HubAdmin.as_str().to_string(),
                 address: config.admin.to_string(),
             }]),
         )?;
+        marketplace_fee += platform_fee;
     };
 
     // Process fee module fees if exists on Hub
@@ -487,22 +492,34 @@ fn _execute_buy_fixed_listing(
         StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string());
     if let Ok(fee_module_addr) = fee_module_addr {
         // Marketplace fees
-        // process_marketplace_fees(
+        // marketplace_fee += process_marketplace_fees(
         //     &deps,
-        //     &config,
+        //     &fund_info,
         //     &mut sub_msgs,
         //     &fee_module_addr,
         //     fixed_listing.price,
-        //     &mut marketplace_fee,
+        //     &buyer,
         //     None,
         // )?;
 
+        // Tiered marketplace fees
+        process_marketplace_tiered_fees(
+            &deps,
+            &fund_info,
+            &mut sub_msgs,
+            &fee_module_addr,
+            fixed_listing.price,
+            &buyer,
+            &mut marketplace_fee,
+        )?;
+
         // Collection royalty fees
         let res = StorageHelper::query_percentage_fee(
             &deps.querier,
             &fee_module_addr,
             Modules::Mint.to_string(),
             MintFees::new_royalty(collection_id),
+            env.block.time,
         );
         if let Ok(percentage_fee) = res {
             royalty_fee = percentage_fee.value.mul(fixed_listing.price);
@@ -588,6 +605,7 @@ fn _execute_buy_fixed_listing(
                 .add_attribute("price", fixed_listing.price.to_string())
                 .add_attribute("owner", fixed_listing.owner)
                 .add_attribute("buyer", buyer)
+                .add_attribute("platform_fee", platform_fee.to_string())
                 .add_attribute("marketplace_fee", marketplace_fee.to_string())
                 .add_attribute("royalty_fee", royalty_fee.to_string())
                 .add_attribute("payout", payout.to_string())
@@ -597,7 +615,7 @@ fn _execute_buy_fixed_listing(
 
 fn execute_receive(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     cw20_receive_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
@@ -618,6 +636,7 @@ fn execute_receive(
             match listing_type {
                 Listing::Fixed => _execute_buy_fixed_listing(
                     deps,
+                    &env,
                     &info,
                     collection_id,
                     token_id,
@@ -630,55 +649,114 @@ fn execute_receive(
     }
 }
 
-// Gets the current total fee percentage from fee module
-// If exists updates the marketplace fee
+// Gets the total fee percentage with buyer discounts from fee module
 // Creates a distribute msg and adds to sub message
+// Returns the marketplace fee amount
 fn process_marketplace_fees(
     deps: &DepsMut,
     fund_info: &FundInfo,
     sub_msgs: &mut Vec<SubMsg>,
     fee_module_addr: &Addr,
     listing_price: Uint128,
-    marketplace_fee: &mut Uint128,
+    buyer: &str,
     custom_payment_addresses: Option<Vec<FeeModuleCustomPaymentAddress>>,
-) -> Result<(), ContractError> {
+) -> Result<Uint128, ContractError> {
+    // Holder discounts and fee exemptions of the buyer are applied to the percentages
     let fee_percentage = KompleFeeModule(fee_module_addr.to_owned())
-        .query_total_percentage_fees(&deps.querier, Modules::Marketplace.as_str())?;
+        .query_total_discounted_percentage_fees(
+            &deps.querier,
+            Modules::Marketplace.as_str(),
+            buyer,
+        )?;
+
+    let fee_to_send = fee_percentage.mul(listing_price);
+
+    if !fee_to_send.is_zero() {
+        // Create distribution message and add it to sub_msgs
+        if fund_info.is_native {
+            sub_msgs.push(SubMsg::new(
+                KompleFeeModule(fee_module_addr.to_owned()).distribute_msg(
+                    Fees::Percentage,
+                    Modules::Marketplace.to_string(),
+                    custom_payment_addresses,
+                    Some(buyer.to_string()),
+                    vec![Coin {
+                        denom: fund_info.denom.to_string(),
+                        amount: fee_to_send,
+                    }],
+                )?,
+            ));
+        } else {
+            sub_msgs.push(SubMsg::new(WasmMsg::Execute {
+                contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: fee_module_addr.to_string(),
+                    amount: fee_to_send,
+                    msg: to_binary(&FeeModuleExecuteMsg::Distribute {
+                        fee_type: Fees::Percentage,
+                        module_name: Modules::Marketplace.to_string(),
+                        custom_payment_addresses,
+                        discount_address: Some(buyer.to_string()),
+                    })?,
+                })?,
+                funds: vec![],
+            }));
+        }
+    };
 
-    if !fee_percentage.is_zero() {
-        let fee_to_send = fee_percentage.mul(listing_price);
+    Ok(fee_to_send)
+}
 
-        if !fee_to_send.is_zero() {
-            *marketplace_fee += fee_to_send;
+fn process_marketplace_tiered_fees(
+    deps: &DepsMut,
+    fund_info: &FundInfo,
+    sub_msgs: &mut Vec<SubMsg>,
+    fee_module_addr: &Addr,
+    listing_price: Uint128,
+    buyer: &str,
+    marketplace_fee: &mut Uint128,
+) -> Result<(), ContractError> {
+    // Holder discounts and fee exemptions of the buyer are applied to the tiers
+    let fee_to_send = KompleFeeModule(fee_module_addr.to_owned())
+        .query_total_discounted_tiered_fees(
+            &deps.querier,
+            Modules::Marketplace.as_str(),
+            listing_price,
+            buyer,
+        )?;
 
-            // Create distribution message and add it to sub_msgs
-            if fund_info.is_native {
-                sub_msgs.push(SubMsg::new(
-                    KompleFeeModule(fee_module_addr.to_owned()).distribute_msg(
-                        Fees::Percentage,
-                        Modules::Marketplace.to_string(),
-                        custom_payment_addresses,
-                        vec![Coin {
-                            denom: fund_info.denom.to_string(),
-                            amount: fee_to_send,
-                        }],
-                    )?,
-                ));
-            } else {
-                sub_msgs.push(SubMsg::new(WasmMsg::Execute {
-                    contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
-                    msg: to_binary(&Cw20ExecuteMsg::Send {
-                        contract: fee_module_addr.to_string(),
+    if !fee_to_send.is_zero() {
+        *marketplace_fee += fee_to_send;
+
+        // Create distribution message and add it to sub_msgs
+        if fund_info.is_native {
+            sub_msgs.push(SubMsg::new(
+                KompleFeeModule(fee_module_addr.to_owned()).distribute_tiered_msg(
+                    Modules::Marketplace.to_string(),
+                    listing_price,
+                    None,
+                    Some(buyer.to_string()),
+                    vec![Coin {
+                        denom: fund_info.denom.to_string(),
                         amount: fee_to_send,
-                        msg: to_binary(&FeeModuleExecuteMsg::Distribute {
-                            fee_type: Fees::Percentage,
-                            module_name: Modules::Marketplace.to_string(),
-                            custom_payment_addresses,
-                        })?,
+                    }],
+                )?,
+            ));
+        } else {
+            sub_msgs.push(SubMsg::new(WasmMsg::Execute {
+                contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::Send {
+                    contract: fee_module_addr.to_string(),
+                    amount: fee_to_send,
+                    msg: to_binary(&FeeModuleExecuteMsg::DistributeTiered {
+                        module_name: Modules::Marketplace.to_string(),
+                        sale_amount: listing_price,
+                        custom_payment_addresses: None,
+                        discount_address: Some(buyer.to_string()),
                     })?,
-                    funds: vec![],
-                }));
-            }
+                })?,
+                funds: vec![],
+            }));
         }
     };
 
@@ -708,7 +786,7 @@ fn check_locks(locks: Locks) -> Result<(), TokenContractError> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::FixedListing {
@@ -726,6 +804,11 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             limit,
         )?),
         QueryMsg::Operators {} => to_binary(&query_operators(deps)?),
+        QueryMsg::FeeQuote {
+            collection_id,
+            token_id,
+            buyer,
+        } => to_binary(&query_fee_quote(deps, env, collection_id, token_id, buyer)?),
     }
 }
 
@@ -767,6 +850,83 @@ fn query_fixed_listings(
     Ok(ResponseWrapper::new("listings", listings))
 }
 
+/// Gets the fees that are taken from a fixed listing price on purchase
+fn query_fee_quote(
+    deps: Deps,
+    env: Env,
+    collection_id: u32,
+    token_id: u32,
+    buyer: Option<String>,
+) -> StdResult<ResponseWrapper<FeeQuoteResponse>> {
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    let listing = FIXED_LISTING.load(deps.storage, (collection_id, token_id))?;
+
+    let mut platform_fee = Uint128::zero();
+    let mut hub_fee = Uint128::zero();
+    let mut royalty_fee = Uint128::zero();
+
+    let res =
+        StorageHelper::query_storage::<Addr>(&deps.querier, &hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
+    if let Some(marbu_fee_module) = res {
+        let marbu_fee_module = KompleFeeModule(marbu_fee_module);
+        let fee_percentage = match &buyer {
+            Some(buyer) => marbu_fee_module.query_total_discounted_percentage_fees(
+                &deps.querier,
+                Modules::Marketplace.as_str(),
+                buyer,
+            )?,
+            None => marbu_fee_module
+                .query_total_percentage_fees(&deps.querier, Modules::Marketplace.as_str())?,
+        };
+        platform_fee = fee_percentage.mul(listing.price);
+    };
+
+    let fee_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string());
+    if let Ok(fee_module_addr) = fee_module_addr {
+        let fee_module = KompleFeeModule(fee_module_addr.clone());
+        hub_fee = match &buyer {
+            Some(buyer) => fee_module.query_total_discounted_tiered_fees(
+                &deps.querier,
+                Modules::Marketplace.as_str(),
+                listing.price,
+                buyer,
+            )?,
+            None => fee_module.query_total_tiered_fees(
+                &deps.querier,
+                Modules::Marketplace.as_str(),
+                listing.price,
+            )?,
+        };
+
+        let res = StorageHelper::query_percentage_fee(
+            &deps.querier,
+            &fee_module_addr,
+            Modules::Mint.to_string(),
+            MintFees::new_royalty(collection_id),
+            env.block.time,
+        );
+        if let Ok(percentage_fee) = res {
+            royalty_fee = percentage_fee.value.mul(listing.price);
+        };
+    };
+
+    let total_fee = platform_fee + hub_fee + royalty_fee;
+    let payout = listing.price.checked_sub(total_fee)?;
+
+    Ok(ResponseWrapper::new(
+        "fee_quote",
+        FeeQuoteResponse {
+            price: listing.price,
+            platform_fee,
+            hub_fee,
+            royalty_fee,
+            total_fee,
+            payout,
+        },
+    ))
+}
+
 fn query_operators(deps: Deps) -> StdResult<ResponseWrapper<Vec<String>>> {
     let addrs = OPERATORS.may_load(deps.storage)?;
     let addrs = match addrs {
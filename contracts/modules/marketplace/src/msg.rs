@@ -122,6 +122,28 @@ pub enum QueryMsg {
     /// Get the operators of this contract.
     #[returns(ResponseWrapper<Vec<String>>)]
     Operators {},
+    /// Get the platform, hub and royalty fees for buying a fixed listing.
+    ///
+    /// Buyer discounts are applied to the platform fees if the buyer is given.
+    #[returns(ResponseWrapper<FeeQuoteResponse>)]
+    FeeQuote {
+        collection_id: u32,
+        token_id: u32,
+        buyer: Option<String>,
+    },
+}
+
+/// The combined fees of a fixed listing purchase.
+///
+/// Platform fees are defined on the Marbu fee module and hub fees on the hub's fee module.
+#[cw_serde]
+pub struct FeeQuoteResponse {
+    pub price: Uint128,
+    pub platform_fee: Uint128,
+    pub hub_fee: Uint128,
+    pub royalty_fee: Uint128,
+    pub total_fee: Uint128,
+    pub payout: Uint128,
 }
 
 #[cw_serde]
@@ -226,6 +226,15 @@ mod actions {
                     token_module_code_id,
                 );
 
+                // Public collections are whitelisted by the admin
+                let msg = MintExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
                 let collection_addr =
                     StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
                         .unwrap();
@@ -138,6 +138,7 @@ pub fn setup_fee_module(app: &mut App, fee_module_addr: &Addr) {
         data: to_binary(&FeeModulePercentagePayment {
             address: Some("contract0".to_string()),
             value: Decimal::from_str("0.04").unwrap(),
+            group: None,
         })
         .unwrap(),
     };
@@ -152,6 +153,7 @@ pub fn setup_fee_module(app: &mut App, fee_module_addr: &Addr) {
         data: to_binary(&FeeModulePercentagePayment {
             address: Some("juno..community".to_string()),
             value: Decimal::from_str("0.02").unwrap(),
+            group: None,
         })
         .unwrap(),
     };
@@ -166,6 +168,7 @@ pub fn setup_fee_module(app: &mut App, fee_module_addr: &Addr) {
         data: to_binary(&FeeModulePercentagePayment {
             address: None,
             value: Decimal::from_str("0.02").unwrap(),
+            group: None,
         })
         .unwrap(),
     };
@@ -182,6 +185,7 @@ pub fn set_royalties(app: &mut App, fee_module_addr: &Addr, collection_id: u32,
         data: to_binary(&FeeModulePercentagePayment {
             address: None,
             value: Decimal::from_str(royalty).unwrap(),
+            group: None,
         })
         .unwrap(),
     };
@@ -309,12 +313,14 @@ pub fn create_collection(
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -331,6 +337,7 @@ pub fn create_collection(
         token_info,
         fund_info,
         linked_collections: None,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(creator_addr), mint_module_addr, &msg, &[])
@@ -340,7 +347,8 @@ pub fn create_collection(
 pub fn mint_token(app: &mut App, mint_module_addr: Addr, collection_id: u32, sender: &str) {
     let msg = MintExecuteMsg::Mint {
         collection_id,
-        metadata_id: None,
+        quantity: 1,
+        metadata_ids: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(sender), mint_module_addr, &msg, &[])
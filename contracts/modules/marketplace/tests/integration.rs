@@ -2,7 +2,9 @@ use cosmwasm_std::{to_binary, Addr, Empty, Uint128};
 use cw721_base::msg::ExecuteMsg as Cw721ExecuteMsg;
 use cw_multi_test::Executor;
 use komple_framework_hub_module::msg::ExecuteMsg as HubExecuteMsg;
-use komple_framework_marketplace_module::msg::{InstantiateMsg, MarketplaceFundInfo};
+use komple_framework_marketplace_module::msg::{
+    FeeQuoteResponse, InstantiateMsg, MarketplaceFundInfo,
+};
 use komple_framework_marketplace_module::ContractError;
 use komple_framework_mint_module::msg::ExecuteMsg as MintExecuteMsg;
 use komple_framework_types::modules::Modules;
@@ -742,7 +744,11 @@ mod actions {
         use komple_framework_types::modules::marketplace::Listing;
 
         mod fixed_tokens {
-            use cosmwasm_std::StdError;
+            use cosmwasm_std::{Decimal, StdError};
+            use komple_framework_fee_module::msg::ExecuteMsg as FeeModuleExecuteMsg;
+            use komple_framework_types::modules::fee::{
+                DiscountConditions, DiscountRule, FeeTier, Fees, MarketplaceFees, TieredPayment,
+            };
 
             use super::*;
 
@@ -771,6 +777,15 @@ mod actions {
                     token_module_code_id,
                 );
 
+                // Public collections are whitelisted by the admin
+                let msg = MintExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
                 let collection_addr =
                     StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
                         .unwrap();
@@ -990,6 +1005,379 @@ mod actions {
                 assert_eq!(balance.amount, Uint128::new(50_000));
             }
 
+            #[test]
+            fn test_fee_quote() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, true);
+
+                let (mint_module_addr, marketplace_module_addr) =
+                    setup_modules(&mut app, hub_addr.clone());
+
+                let msg = MintExecuteMsg::UpdatePublicCollectionCreation {
+                    public_collection_creation: true,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let token_module_code_id = app.store_code(token_module());
+                create_collection(
+                    &mut app,
+                    mint_module_addr.clone(),
+                    CREATOR,
+                    token_module_code_id,
+                );
+
+                // Public collections are whitelisted by the admin
+                let msg = MintExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+
+                mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+
+                give_approval_to_module(&mut app, collection_addr, USER, &marketplace_module_addr);
+
+                let fee_module_code_id = app.store_code(fee_module());
+                let msg = HubExecuteMsg::RegisterModule {
+                    module: Modules::Fee.to_string(),
+                    msg: Some(
+                        to_binary(&RegisterMsg {
+                            admin: ADMIN.to_string(),
+                            data: None,
+                        })
+                        .unwrap(),
+                    ),
+                    code_id: fee_module_code_id,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), hub_addr.clone(), &msg, &[])
+                    .unwrap();
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                set_royalties(&mut app, &fee_module_addr, 1, "0.1");
+
+                setup_marketplace_listing(
+                    &mut app,
+                    &mint_module_addr,
+                    &marketplace_module_addr,
+                    1,
+                    1,
+                    Uint128::new(1_000),
+                );
+
+                // Platform fees are 8% and royalty is 10%
+                let msg = MarketplaceQueryMsg::FeeQuote {
+                    collection_id: 1,
+                    token_id: 1,
+                    buyer: Some(RANDOM.to_string()),
+                };
+                let res: ResponseWrapper<FeeQuoteResponse> = app
+                    .wrap()
+                    .query_wasm_smart(marketplace_module_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(
+                    res.data,
+                    FeeQuoteResponse {
+                        price: Uint128::new(1_000),
+                        platform_fee: Uint128::new(80),
+                        hub_fee: Uint128::zero(),
+                        royalty_fee: Uint128::new(100),
+                        total_fee: Uint128::new(180),
+                        payout: Uint128::new(820),
+                    }
+                );
+
+                let msg = MarketplaceExecuteMsg::Buy {
+                    listing_type: Listing::Fixed,
+                    collection_id: 1,
+                    token_id: 1,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(RANDOM),
+                        marketplace_module_addr,
+                        &msg,
+                        &[coin(1_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                // Owner receives the quoted payout
+                let balance = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(1_000_820));
+            }
+
+            #[test]
+            fn test_happy_path_with_holder_discount() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, true);
+
+                let (mint_module_addr, marketplace_module_addr) =
+                    setup_modules(&mut app, hub_addr.clone());
+
+                let msg = MintExecuteMsg::UpdatePublicCollectionCreation {
+                    public_collection_creation: true,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let token_module_code_id = app.store_code(token_module());
+                create_collection(
+                    &mut app,
+                    mint_module_addr.clone(),
+                    CREATOR,
+                    token_module_code_id,
+                );
+
+                // Public collections are whitelisted by the admin
+                let msg = MintExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+
+                mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+                mint_token(&mut app, mint_module_addr.clone(), 1, RANDOM);
+
+                give_approval_to_module(
+                    &mut app,
+                    collection_addr.clone(),
+                    USER,
+                    &marketplace_module_addr,
+                );
+
+                // Holders of a single token get half of the komple fee off
+                let msg = FeeModuleExecuteMsg::SetDiscount {
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: MarketplaceFees::Komple.as_str().to_string(),
+                    rules: vec![DiscountRule {
+                        collection_addr: collection_addr.to_string(),
+                        condition: DiscountConditions::MinTokens { amount: 1 },
+                        value: Decimal::percent(50),
+                    }],
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        Addr::unchecked("contract0"),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap();
+
+                setup_marketplace_listing(
+                    &mut app,
+                    &mint_module_addr,
+                    &marketplace_module_addr,
+                    1,
+                    1,
+                    Uint128::new(1_000),
+                );
+
+                let msg = MarketplaceExecuteMsg::Buy {
+                    listing_type: Listing::Fixed,
+                    collection_id: 1,
+                    token_id: 1,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(RANDOM),
+                        marketplace_module_addr.clone(),
+                        &msg,
+                        &[coin(1_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                // Owner balance
+                let balance = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(1_000_940));
+
+                // Komple fee
+                let balance = app.wrap().query_balance("contract0", NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(20));
+
+                // Community fee
+                let balance = app
+                    .wrap()
+                    .query_balance("juno..community", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(balance.amount, Uint128::new(20));
+
+                // Marketplace owner fee
+                let balance = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(20));
+            }
+
+            #[test]
+            fn test_happy_path_with_tiered_fees() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, false);
+
+                let (mint_module_addr, marketplace_module_addr) =
+                    setup_modules(&mut app, hub_addr.clone());
+
+                let msg = MintExecuteMsg::UpdatePublicCollectionCreation {
+                    public_collection_creation: true,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let token_module_code_id = app.store_code(token_module());
+                create_collection(
+                    &mut app,
+                    mint_module_addr.clone(),
+                    CREATOR,
+                    token_module_code_id,
+                );
+
+                // Public collections are whitelisted by the admin
+                let msg = MintExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+
+                mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+                mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+
+                give_approval_to_module(&mut app, collection_addr, USER, &marketplace_module_addr);
+
+                let fee_module_code_id = app.store_code(fee_module());
+                let msg = HubExecuteMsg::RegisterModule {
+                    module: Modules::Fee.to_string(),
+                    msg: Some(
+                        to_binary(&RegisterMsg {
+                            admin: ADMIN.to_string(),
+                            data: None,
+                        })
+                        .unwrap(),
+                    ),
+                    code_id: fee_module_code_id,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), hub_addr.clone(), &msg, &[])
+                    .unwrap();
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                // 5 percent below 1_000, 2.5 percent above with at most 40 taken
+                let msg = FeeModuleExecuteMsg::SetFee {
+                    fee_type: Fees::Tiered,
+                    module_name: Modules::Marketplace.to_string(),
+                    fee_name: MarketplaceFees::Community.as_str().to_string(),
+                    data: to_binary(&TieredPayment {
+                        address: Some("juno..community".to_string()),
+                        tiers: vec![
+                            FeeTier {
+                                min_amount: Uint128::zero(),
+                                value: Decimal::percent(5),
+                            },
+                            FeeTier {
+                                min_amount: Uint128::new(1_000),
+                                value: Decimal::permille(25),
+                            },
+                        ],
+                        min: None,
+                        max: Some(Uint128::new(40)),
+                    })
+                    .unwrap(),
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), fee_module_addr, &msg, &[])
+                    .unwrap();
+
+                setup_marketplace_listing(
+                    &mut app,
+                    &mint_module_addr,
+                    &marketplace_module_addr,
+                    1,
+                    1,
+                    Uint128::new(500),
+                );
+                let msg = MarketplaceExecuteMsg::Buy {
+                    listing_type: Listing::Fixed,
+                    collection_id: 1,
+                    token_id: 1,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(RANDOM),
+                        marketplace_module_addr.clone(),
+                        &msg,
+                        &[coin(500, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                let balance = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(1_000_475));
+                let balance = app
+                    .wrap()
+                    .query_balance("juno..community", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(balance.amount, Uint128::new(25));
+
+                setup_marketplace_listing(
+                    &mut app,
+                    &mint_module_addr,
+                    &marketplace_module_addr,
+                    1,
+                    2,
+                    Uint128::new(2_000),
+                );
+                let msg = MarketplaceExecuteMsg::Buy {
+                    listing_type: Listing::Fixed,
+                    collection_id: 1,
+                    token_id: 2,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(RANDOM),
+                        marketplace_module_addr,
+                        &msg,
+                        &[coin(2_000, NATIVE_DENOM)],
+                    )
+                    .unwrap();
+
+                // 2.5 percent of 2_000 is 50 but capped to 40
+                let balance = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(balance.amount, Uint128::new(1_002_435));
+                let balance = app
+                    .wrap()
+                    .query_balance("juno..community", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(balance.amount, Uint128::new(65));
+            }
+
             // #[test]
             // fn test_happy_path_without_marbu() {
             //     let mut app = mock_app();
@@ -1,25 +1,42 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult, WasmMsg,
+    from_binary, to_binary, Addr, Attribute, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw20::Cw20ReceiveMsg;
+use cw_storage_plus::Bound;
+use komple_framework_fee_module::helper::KompleFeeModule;
+use komple_framework_metadata_module::helper::KompleMetadataModule;
+use komple_framework_metadata_module::state::Trait;
 use komple_framework_mint_module::helper::KompleMintModule;
 use komple_framework_permission_module::msg::ExecuteMsg as PermissionExecuteMsg;
 use komple_framework_token_module::helper::KompleTokenModule;
+use komple_framework_types::modules::fee::{FundInfo, MergeFees};
+use komple_framework_types::modules::hub::MARBU_FEE_MODULE_NAMESPACE;
+use komple_framework_types::modules::metadata::Metadata as MetadataType;
+use komple_framework_types::modules::permission::AttributeConditions;
 use komple_framework_types::modules::Modules;
-use komple_framework_types::shared::events::MergeEventAttributes;
+use komple_framework_types::shared::events::{MergeEventAttributes, MetadataEventAttributes};
 use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
+use komple_framework_utils::funds::{check_cw20_fund_info, check_single_coin, FundsError};
 use komple_framework_utils::response::{EventHelper, ResponseHelper};
 use komple_framework_utils::shared::{execute_lock_execute, execute_update_operators};
 use komple_framework_utils::{check_admin_privileges, storage::StorageHelper};
 use semver::Version;
+use std::ops::Mul;
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, MergeMsg, MigrateMsg, QueryMsg};
-use crate::state::{Config, CONFIG, EXECUTE_LOCK, HUB_ADDR, OPERATORS};
+use crate::msg::{
+    ExecuteMsg, FeeQuoteResponse, MergeBurnMsg, MergeMsg, MigrateMsg, QueryMsg, ReceiveMsg,
+    RecipeResponse,
+};
+use crate::state::{
+    AttributeOperation, AttributeRule, Config, Recipe, RecipeCondition, RecipeOutput, CONFIG,
+    EXECUTE_LOCK, HUB_ADDR, OPERATORS, RECIPES, RECIPE_ID, RECIPE_USES,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:komple-framework-merge-module";
@@ -70,11 +87,22 @@ pub fn execute(
 
     match msg {
         ExecuteMsg::UpdateMergeLock { lock } => execute_update_merge_lock(deps, env, info, lock),
-        ExecuteMsg::Merge { msg } => execute_merge(deps, env, info, msg),
+        ExecuteMsg::Merge {
+            recipe_id,
+            burn_ids,
+        } => {
+            let sender = info.sender.clone();
+            execute_merge(deps, env, info, sender, recipe_id, burn_ids, None)
+        }
         ExecuteMsg::PermissionMerge {
             permission_msg,
             merge_msg,
         } => execute_permission_merge(deps, env, info, permission_msg, merge_msg),
+        ExecuteMsg::CreateRecipe { recipe } => execute_create_recipe(deps, env, info, recipe),
+        ExecuteMsg::UpdateRecipe { recipe_id, recipe } => {
+            execute_update_recipe(deps, env, info, recipe_id, recipe)
+        }
+        ExecuteMsg::RemoveRecipe { recipe_id } => execute_remove_recipe(deps, env, info, recipe_id),
         ExecuteMsg::UpdateOperators { addrs } => {
             let config = CONFIG.load(deps.storage)?;
             let res = execute_update_operators(
@@ -99,6 +127,7 @@ pub fn execute(
                 Err(err) => Err(err.into()),
             }
         }
+        ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
     }
 }
 
@@ -133,22 +162,270 @@ fn execute_update_merge_lock(
     )
 }
 
+fn execute_create_recipe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipe: Recipe,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    check_recipe(&deps, &env, &recipe)?;
+
+    // Recipe id is not set on instantiate for older versions
+    let recipe_id = RECIPE_ID.may_load(deps.storage)?.unwrap_or(0) + 1;
+
+    RECIPES.save(deps.storage, recipe_id, &recipe)?;
+    RECIPE_ID.save(deps.storage, &recipe_id)?;
+
+    Ok(
+        ResponseHelper::new_module("merge", "create_recipe").add_event(
+            EventHelper::new("merge_create_recipe")
+                .add_attribute("recipe_id", recipe_id.to_string())
+                .add_attributes(
+                    recipe
+                        .outputs
+                        .iter()
+                        .map(|output| {
+                            MergeEventAttributes::new_mint_id_attribute(
+                                output.collection_id,
+                                output.metadata_id,
+                            )
+                        })
+                        .collect(),
+                )
+                .check_add_attribute(
+                    &recipe.max_uses,
+                    "max_uses",
+                    recipe.max_uses.unwrap_or(0).to_string(),
+                )
+                .get(),
+        ),
+    )
+}
+
+fn execute_update_recipe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipe_id: u32,
+    recipe: Recipe,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    if !RECIPES.has(deps.storage, recipe_id) {
+        return Err(ContractError::RecipeNotFound {});
+    }
+
+    check_recipe(&deps, &env, &recipe)?;
+
+    RECIPES.save(deps.storage, recipe_id, &recipe)?;
+
+    Ok(
+        ResponseHelper::new_module("merge", "update_recipe").add_event(
+            EventHelper::new("merge_update_recipe")
+                .add_attribute("recipe_id", recipe_id.to_string())
+                .add_attributes(
+                    recipe
+                        .outputs
+                        .iter()
+                        .map(|output| {
+                            MergeEventAttributes::new_mint_id_attribute(
+                                output.collection_id,
+                                output.metadata_id,
+                            )
+                        })
+                        .collect(),
+                )
+                .check_add_attribute(
+                    &recipe.max_uses,
+                    "max_uses",
+                    recipe.max_uses.unwrap_or(0).to_string(),
+                )
+                .get(),
+        ),
+    )
+}
+
+fn execute_remove_recipe(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipe_id: u32,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    if !RECIPES.has(deps.storage, recipe_id) {
+        return Err(ContractError::RecipeNotFound {});
+    }
+
+    RECIPES.remove(deps.storage, recipe_id);
+    RECIPE_USES.remove(deps.storage, recipe_id);
+
+    Ok(
+        ResponseHelper::new_module("merge", "remove_recipe").add_event(
+            EventHelper::new("merge_remove_recipe")
+                .add_attribute("recipe_id", recipe_id.to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_receive_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let msg: ReceiveMsg = from_binary(&cw20_receive_msg.msg)?;
+    let sender = deps.api.addr_validate(&cw20_receive_msg.sender)?;
+    let amount = cw20_receive_msg.amount;
+    match msg {
+        ReceiveMsg::Merge {
+            recipe_id,
+            burn_ids,
+        } => execute_merge(deps, env, info, sender, recipe_id, burn_ids, Some(amount)),
+    }
+}
+
 fn execute_merge(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    msg: MergeMsg,
+    sender: Addr,
+    recipe_id: u32,
+    burn_ids: Vec<MergeBurnMsg>,
+    cw20_token_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     if config.merge_lock {
         return Err(ContractError::MergeLocked {});
     };
 
+    let recipe = RECIPES.may_load(deps.storage, recipe_id)?;
+    if recipe.is_none() {
+        return Err(ContractError::RecipeNotFound {});
+    }
+    let recipe = recipe.unwrap();
+
+    if let Some(start_time) = recipe.start_time {
+        if env.block.time < start_time {
+            return Err(ContractError::RecipeNotStarted {});
+        }
+    }
+    if let Some(end_time) = recipe.end_time {
+        if env.block.time >= end_time {
+            return Err(ContractError::RecipeEnded {});
+        }
+    }
+
+    let uses = RECIPE_USES.may_load(deps.storage, recipe_id)?.unwrap_or(0);
+    if let Some(max_uses) = recipe.max_uses {
+        if uses >= max_uses {
+            return Err(ContractError::RecipeMaxUsesReached {});
+        }
+    }
+
+    // Throw an error if there are no burn messages
+    if burn_ids.is_empty() {
+        return Err(ContractError::BurnNotFound {});
+    }
+
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    let mint_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Mint.to_string())?;
+
+    let input_attributes =
+        check_recipe_inputs(&deps, &sender, &mint_module_addr, &recipe, &burn_ids)?;
+
+    let fee_msgs = process_merge_fee(
+        &deps,
+        &env,
+        &info,
+        &sender,
+        recipe_id,
+        &recipe.fund_info,
+        cw20_token_amount,
+    )?;
+
     let mut msgs: Vec<WasmMsg> = vec![];
 
     let mut event_attributes: Vec<Attribute> = vec![];
+    event_attributes.push(Attribute::new("recipe_id", recipe_id.to_string()));
+
+    make_burn_messages(
+        &deps,
+        &mut event_attributes,
+        &mint_module_addr,
+        &burn_ids,
+        &mut msgs,
+    )?;
+
+    for (index, output) in recipe.outputs.iter().enumerate() {
+        let msg = KompleMintModule(mint_module_addr.clone()).admin_mint_msg(
+            sender.to_string(),
+            output.collection_id,
+            output.metadata_id,
+            vec![],
+        )?;
+        msgs.push(msg);
+
+        event_attributes.push(MergeEventAttributes::new_mint_id_attribute(
+            output.collection_id,
+            output.metadata_id,
+        ));
+
+        // Derived attributes are written after the output token is minted
+        if !output.attribute_rules.is_empty() {
+            let previous_mints = recipe.outputs[..index]
+                .iter()
+                .filter(|item| item.collection_id == output.collection_id)
+                .count() as u32;
+            make_metadata_messages(
+                &deps,
+                &mut event_attributes,
+                &mint_module_addr,
+                output,
+                previous_mints,
+                &input_attributes,
+                &mut msgs,
+            )?;
+        }
+    }
+
+    msgs.extend(fee_msgs);
 
-    make_merge_msg(&deps, &info, &mut event_attributes, msg, &mut msgs)?;
+    RECIPE_USES.save(deps.storage, recipe_id, &(uses + 1))?;
 
     Ok(ResponseHelper::new_module("merge", "merge")
         .add_messages(msgs)
@@ -198,7 +475,7 @@ fn execute_permission_merge(
 
     let mut event_attributes: Vec<Attribute> = vec![];
 
-    make_merge_msg(&deps, &info, &mut event_attributes, merge_msg, &mut msgs)?;
+    make_merge_msg(&deps, &mut event_attributes, merge_msg, &mut msgs)?;
 
     Ok(ResponseHelper::new_module("merge", "permission_merge")
         .add_messages(msgs)
@@ -212,7 +489,6 @@ fn execute_permission_merge(
 /// Constructs the mint and burn messages
 fn make_merge_msg(
     deps: &DepsMut,
-    info: &MessageInfo,
     event_attributes: &mut Vec<Attribute>,
     merge_msg: MergeMsg,
     msgs: &mut Vec<WasmMsg>,
@@ -226,22 +502,32 @@ fn make_merge_msg(
         return Err(ContractError::BurnNotFound {});
     }
 
-    // Pushes the burn_ids inside msgs list
-    make_burn_messages(deps, event_attributes, &mint_module_addr, &merge_msg, msgs)?;
+    // Throw an error if there are no mint messages
+    if merge_msg.mint_ids.is_empty() {
+        return Err(ContractError::MintNotFound {});
+    }
 
-    let msg = KompleMintModule(mint_module_addr).admin_mint_msg(
-        merge_msg.recipient,
-        merge_msg.mint_id,
-        merge_msg.metadata_id,
-        info.funds.clone(),
+    // Pushes the burn_ids inside msgs list
+    make_burn_messages(
+        deps,
+        event_attributes,
+        &mint_module_addr,
+        &merge_msg.burn_ids,
+        msgs,
     )?;
-    msgs.push(msg);
 
-    event_attributes.push(Attribute::new("mint_id", merge_msg.mint_id.to_string()));
-    if merge_msg.metadata_id.is_some() {
-        event_attributes.push(Attribute::new(
-            "metadata_id",
-            merge_msg.metadata_id.as_ref().unwrap().to_string(),
+    for mint_msg in merge_msg.mint_ids {
+        let msg = KompleMintModule(mint_module_addr.clone()).admin_mint_msg(
+            merge_msg.recipient.clone(),
+            mint_msg.collection_id,
+            mint_msg.metadata_id,
+            vec![],
+        )?;
+        msgs.push(msg);
+
+        event_attributes.push(MergeEventAttributes::new_mint_id_attribute(
+            mint_msg.collection_id,
+            mint_msg.metadata_id,
         ));
     }
 
@@ -254,10 +540,10 @@ fn make_burn_messages(
     deps: &DepsMut,
     event_attributes: &mut Vec<Attribute>,
     mint_module_addr: &Addr,
-    merge_msg: &MergeMsg,
+    burn_ids: &[MergeBurnMsg],
     msgs: &mut Vec<WasmMsg>,
 ) -> Result<(), ContractError> {
-    for burn_msg in &merge_msg.burn_ids {
+    for burn_msg in burn_ids {
         let collection_addr = StorageHelper::query_collection_address(
             &deps.querier,
             mint_module_addr,
@@ -275,11 +561,455 @@ fn make_burn_messages(
     Ok(())
 }
 
+// Creates the messages for writing the derived attributes
+// to the dynamic metadata of the token that will be minted
+fn make_metadata_messages(
+    deps: &DepsMut,
+    event_attributes: &mut Vec<Attribute>,
+    mint_module_addr: &Addr,
+    output: &RecipeOutput,
+    previous_mints: u32,
+    input_attributes: &[Trait],
+    msgs: &mut Vec<WasmMsg>,
+) -> Result<(), ContractError> {
+    let collection_addr = StorageHelper::query_collection_address(
+        &deps.querier,
+        mint_module_addr,
+        &output.collection_id,
+    )?;
+    let sub_modules = StorageHelper::query_token_sub_modules(&deps.querier, &collection_addr)?;
+    if sub_modules.metadata.is_none() {
+        return Err(ContractError::MetadataNotFound {});
+    };
+    let metadata_module = KompleMetadataModule(sub_modules.metadata.unwrap());
+
+    // Output tokens are minted in the same transaction
+    let token_id =
+        StorageHelper::query_token_ids(&deps.querier, &collection_addr)? + previous_mints + 1;
+
+    // Linked dynamic metadata starts as a copy of the raw metadata
+    let metadata =
+        metadata_module.query_raw_metadata(&deps.querier, output.metadata_id.unwrap())?;
+
+    for rule in &output.attribute_rules {
+        let value = derive_attribute_value(input_attributes, rule)?;
+        if value.is_none() {
+            continue;
+        }
+
+        let attribute = Trait {
+            trait_type: rule.trait_type.clone(),
+            value: value.unwrap(),
+        };
+        event_attributes.push(MetadataEventAttributes::new_attribute_attribute(
+            attribute.trait_type.clone(),
+            attribute.value.clone(),
+        ));
+
+        let exists = metadata
+            .attributes
+            .iter()
+            .any(|attr| attr.trait_type == rule.trait_type);
+        let msg = match exists {
+            true => metadata_module.update_attribute_msg(false, token_id, attribute)?,
+            false => metadata_module.add_attribute_msg(false, token_id, attribute)?,
+        };
+        msgs.push(msg);
+    }
+
+    Ok(())
+}
+
+// Checks the merge price on the fee module and creates the messages
+// for distributing the payment with the platform and merge percentage fees
+fn process_merge_fee(
+    deps: &DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    sender: &Addr,
+    recipe_id: u32,
+    fund_info: &FundInfo,
+    cw20_token_amount: Option<Uint128>,
+) -> Result<Vec<WasmMsg>, ContractError> {
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+
+    let mut msgs: Vec<WasmMsg> = vec![];
+
+    // Merge is free if fee module is not registered or price is not set
+    let fee_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string())
+            .ok();
+    let price = match &fee_module_addr {
+        Some(fee_module_addr) => match StorageHelper::query_fixed_fee(
+            &deps.querier,
+            fee_module_addr,
+            Modules::Merge.to_string(),
+            MergeFees::new_price(recipe_id),
+            env.block.time,
+        ) {
+            Ok(fixed_payment) => fixed_payment.value,
+            Err(StdError::NotFound { .. }) => Uint128::zero(),
+            Err(e) => return Err(e.into()),
+        },
+        None => Uint128::zero(),
+    };
+
+    if price.is_zero() {
+        let amount = match cw20_token_amount {
+            Some(amount) => Some(amount),
+            None => info.funds.first().map(|coin| coin.amount),
+        };
+        if let Some(amount) = amount {
+            return Err(FundsError::InvalidFunds {
+                got: amount.to_string(),
+                expected: price.to_string(),
+            }
+            .into());
+        }
+        return Ok(msgs);
+    }
+
+    match fund_info.is_native {
+        true => {
+            if cw20_token_amount.is_some() {
+                return Err(FundsError::InvalidCw20Token {}.into());
+            }
+            check_single_coin(
+                info,
+                Coin {
+                    denom: fund_info.denom.to_string(),
+                    amount: price,
+                },
+            )?;
+        }
+        false => {
+            let cw20_address = fund_info.cw20_address.as_ref().unwrap();
+            if cw20_token_amount.is_none() || info.sender != *cw20_address {
+                return Err(FundsError::InvalidCw20Token {}.into());
+            }
+            if cw20_token_amount.unwrap() != price {
+                return Err(FundsError::InvalidFunds {
+                    got: cw20_token_amount.unwrap().to_string(),
+                    expected: price.to_string(),
+                }
+                .into());
+            }
+        }
+    }
+
+    // Platform fees are taken out of the price before the merge fees
+    let mut platform_fee = Uint128::zero();
+    let res =
+        StorageHelper::query_storage::<Addr>(&deps.querier, &hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
+    if let Some(marbu_fee_module) = res {
+        let marbu_fee_module = KompleFeeModule(marbu_fee_module);
+        let fee_percentage = marbu_fee_module.query_total_discounted_percentage_fees(
+            &deps.querier,
+            Modules::Merge.as_str(),
+            sender.as_str(),
+        )?;
+        platform_fee = fee_percentage.mul(price);
+
+        if !platform_fee.is_zero() {
+            msgs.push(marbu_fee_module.distribute_funds_msg(
+                fund_info,
+                Modules::Merge.to_string(),
+                platform_fee,
+                Some(sender.to_string()),
+            )?);
+        }
+    }
+
+    let merge_fee = price - platform_fee;
+    if !merge_fee.is_zero() {
+        msgs.push(
+            KompleFeeModule(fee_module_addr.unwrap()).distribute_funds_msg(
+                fund_info,
+                Modules::Merge.to_string(),
+                merge_fee,
+                None,
+            )?,
+        );
+    }
+
+    Ok(msgs)
+}
+
+// Validates the recipe values and checks if the collections exist
+fn check_recipe(deps: &DepsMut, env: &Env, recipe: &Recipe) -> Result<(), ContractError> {
+    if recipe.inputs.is_empty() || recipe.inputs.iter().any(|input| input.count == 0) {
+        return Err(ContractError::InvalidRecipe {});
+    }
+    if recipe.outputs.is_empty() {
+        return Err(ContractError::InvalidRecipe {});
+    }
+    if recipe.max_uses == Some(0) {
+        return Err(ContractError::InvalidRecipe {});
+    }
+    if !recipe.fund_info.is_native {
+        if recipe.fund_info.cw20_address.is_none() {
+            return Err(FundsError::InvalidCw20Token {}.into());
+        }
+        deps.api
+            .addr_validate(recipe.fund_info.cw20_address.as_ref().unwrap().as_str())?;
+        check_cw20_fund_info(deps, &recipe.fund_info)?;
+    }
+    if let Some(end_time) = recipe.end_time {
+        if end_time <= env.block.time {
+            return Err(ContractError::InvalidEndTime {});
+        }
+        if recipe.start_time.is_some() && recipe.start_time.unwrap() >= end_time {
+            return Err(ContractError::InvalidStartTime {});
+        }
+    }
+
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    let mint_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Mint.to_string())?;
+
+    for collection_id in recipe
+        .inputs
+        .iter()
+        .map(|input| input.collection_id)
+        .chain(recipe.outputs.iter().map(|output| output.collection_id))
+    {
+        StorageHelper::query_collection_address(&deps.querier, &mint_module_addr, &collection_id)?;
+    }
+
+    for output in &recipe.outputs {
+        if !output.attribute_rules.is_empty() {
+            check_attribute_rules(deps, &mint_module_addr, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Attribute rules can only be written to a linked dynamic metadata
+fn check_attribute_rules(
+    deps: &DepsMut,
+    mint_module_addr: &Addr,
+    output: &RecipeOutput,
+) -> Result<(), ContractError> {
+    if output.metadata_id.is_none() {
+        return Err(ContractError::InvalidRecipe {});
+    }
+    for (index, rule) in output.attribute_rules.iter().enumerate() {
+        if rule.trait_type.is_empty()
+            || output.attribute_rules[..index]
+                .iter()
+                .any(|r| r.trait_type == rule.trait_type)
+        {
+            return Err(ContractError::InvalidRecipe {});
+        }
+        if let AttributeOperation::Priority { values } = &rule.operation {
+            if values.is_empty() {
+                return Err(ContractError::InvalidRecipe {});
+            }
+        }
+    }
+
+    let collection_addr = StorageHelper::query_collection_address(
+        &deps.querier,
+        mint_module_addr,
+        &output.collection_id,
+    )?;
+    let sub_modules = StorageHelper::query_token_sub_modules(&deps.querier, &collection_addr)?;
+    if sub_modules.metadata.is_none() {
+        return Err(ContractError::MetadataNotFound {});
+    };
+    let metadata_config =
+        KompleMetadataModule(sub_modules.metadata.unwrap()).query_config(&deps.querier)?;
+    if metadata_config.metadata_type != MetadataType::Dynamic {
+        return Err(ContractError::DynamicMetadataRequired {});
+    }
+
+    Ok(())
+}
+
+// Matches the burn ids with the recipe inputs in order
+// and checks the ownership and attribute conditions of every token
+// Returns the attributes of the tokens if they are needed by the recipe
+fn check_recipe_inputs(
+    deps: &DepsMut,
+    owner: &Addr,
+    mint_module_addr: &Addr,
+    recipe: &Recipe,
+    burn_ids: &[MergeBurnMsg],
+) -> Result<Vec<Trait>, ContractError> {
+    let total_count: u32 = recipe.inputs.iter().map(|input| input.count).sum();
+    if burn_ids.len() != total_count as usize {
+        return Err(ContractError::InvalidBurnIds {});
+    }
+
+    for (index, burn_msg) in burn_ids.iter().enumerate() {
+        if burn_ids[..index].contains(burn_msg) {
+            return Err(ContractError::DuplicateBurnId {});
+        }
+    }
+
+    let mut input_attributes: Vec<Trait> = vec![];
+    let has_attribute_rules = recipe
+        .outputs
+        .iter()
+        .any(|output| !output.attribute_rules.is_empty());
+
+    let mut burn_ids = burn_ids.iter();
+    for input in &recipe.inputs {
+        let collection_addr = StorageHelper::query_collection_address(
+            &deps.querier,
+            mint_module_addr,
+            &input.collection_id,
+        )?;
+
+        let metadata_module_addr = match input.conditions.is_empty() && !has_attribute_rules {
+            true => None,
+            false => {
+                let sub_modules =
+                    StorageHelper::query_token_sub_modules(&deps.querier, &collection_addr)?;
+                if sub_modules.metadata.is_none() {
+                    return Err(ContractError::MetadataNotFound {});
+                };
+                sub_modules.metadata
+            }
+        };
+
+        // Tokens are served the unrevealed metadata until the reveal
+        if let Some(metadata_module_addr) = &metadata_module_addr {
+            let reveal =
+                KompleMetadataModule(metadata_module_addr.clone()).query_reveal(&deps.querier)?;
+            if matches!(reveal, Some(reveal) if reveal.metadata_root.is_none()) {
+                return Err(ContractError::MetadataNotRevealed {});
+            }
+        }
+
+        for _ in 0..input.count {
+            let burn_msg = burn_ids.next().unwrap();
+            if burn_msg.collection_id != input.collection_id {
+                return Err(ContractError::InvalidBurnIds {});
+            }
+
+            let token_owner = StorageHelper::query_token_owner(
+                &deps.querier,
+                &collection_addr,
+                &burn_msg.token_id,
+            )?;
+            if token_owner != *owner {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            if let Some(metadata_module_addr) = &metadata_module_addr {
+                let response = KompleMetadataModule(metadata_module_addr.clone())
+                    .query_metadata(&deps.querier, burn_msg.token_id)?;
+                check_recipe_conditions(&response.metadata.attributes, &input.conditions)?;
+                input_attributes.extend(response.metadata.attributes);
+            }
+        }
+    }
+
+    Ok(input_attributes)
+}
+
+// Checks the token attributes against the input conditions
+// Comparisons only work with integer values
+fn check_recipe_conditions(
+    attributes: &[Trait],
+    conditions: &[RecipeCondition],
+) -> Result<(), ContractError> {
+    for condition in conditions {
+        let attribute = attributes
+            .iter()
+            .find(|attr| attr.trait_type == condition.trait_type);
+
+        let is_met = match (&condition.condition, attribute) {
+            (AttributeConditions::Exist, attribute) => attribute.is_some(),
+            (AttributeConditions::Absent, attribute) => attribute.is_none(),
+            (_, None) => false,
+            (AttributeConditions::Equal, Some(attr)) => attr.value == condition.value,
+            (AttributeConditions::NotEqual, Some(attr)) => attr.value != condition.value,
+            (comparison, Some(attr)) => {
+                match (attr.value.parse::<u32>(), condition.value.parse::<u32>()) {
+                    (Ok(attribute_value), Ok(condition_value)) => match comparison {
+                        AttributeConditions::GreaterThan => attribute_value > condition_value,
+                        AttributeConditions::GreaterThanOrEqual => {
+                            attribute_value >= condition_value
+                        }
+                        AttributeConditions::LessThan => attribute_value < condition_value,
+                        AttributeConditions::LessThanOrEqual => attribute_value <= condition_value,
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+        };
+
+        if !is_met {
+            return Err(ContractError::AttributeConditionNotMet {});
+        }
+    }
+    Ok(())
+}
+
+// Calculates the output attribute value from the input attributes
+// Returns None if none of the inputs have the attribute
+fn derive_attribute_value(
+    attributes: &[Trait],
+    rule: &AttributeRule,
+) -> Result<Option<String>, ContractError> {
+    let values: Vec<&str> = attributes
+        .iter()
+        .filter(|attr| attr.trait_type == rule.trait_type)
+        .map(|attr| attr.value.as_str())
+        .collect();
+    if values.is_empty() {
+        return Ok(None);
+    }
+
+    let value = match &rule.operation {
+        AttributeOperation::Sum | AttributeOperation::Max | AttributeOperation::Avg => {
+            let numbers = values
+                .iter()
+                .map(|value| {
+                    value
+                        .parse::<u32>()
+                        .map(u64::from)
+                        .map_err(|_| ContractError::InvalidAttributeValue {})
+                })
+                .collect::<Result<Vec<u64>, ContractError>>()?;
+            let number = match &rule.operation {
+                AttributeOperation::Sum => numbers.iter().sum(),
+                AttributeOperation::Max => *numbers.iter().max().unwrap(),
+                _ => numbers.iter().sum::<u64>() / numbers.len() as u64,
+            };
+            number.to_string()
+        }
+        AttributeOperation::Concat { separator } => values.join(separator),
+        AttributeOperation::Priority { values: priorities } => values
+            .iter()
+            .min_by_key(|value| {
+                priorities
+                    .iter()
+                    .position(|priority| priority == *value)
+                    .unwrap_or(priorities.len())
+            })
+            .unwrap()
+            .to_string(),
+    };
+    Ok(Some(value))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::Operators {} => to_binary(&query_operators(deps)?),
+        QueryMsg::Recipe { recipe_id } => to_binary(&query_recipe(deps, recipe_id)?),
+        QueryMsg::Recipes { start_after, limit } => {
+            to_binary(&query_recipes(deps, start_after, limit)?)
+        }
+        QueryMsg::FeeQuote { recipe_id, merger } => {
+            to_binary(&query_fee_quote(deps, env, recipe_id, merger)?)
+        }
     }
 }
 
@@ -296,6 +1026,99 @@ fn query_operators(deps: Deps) -> StdResult<ResponseWrapper<Vec<String>>> {
     ))
 }
 
+fn query_recipe(deps: Deps, recipe_id: u32) -> StdResult<ResponseWrapper<RecipeResponse>> {
+    let recipe = RECIPES.load(deps.storage, recipe_id)?;
+    let uses = RECIPE_USES.may_load(deps.storage, recipe_id)?.unwrap_or(0);
+    Ok(ResponseWrapper::new(
+        "recipe",
+        RecipeResponse {
+            recipe_id,
+            recipe,
+            uses,
+        },
+    ))
+}
+
+fn query_recipes(
+    deps: Deps,
+    start_after: Option<u32>,
+    limit: Option<u8>,
+) -> StdResult<ResponseWrapper<Vec<RecipeResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let recipes = RECIPES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (recipe_id, recipe) = item?;
+            let uses = RECIPE_USES.may_load(deps.storage, recipe_id)?.unwrap_or(0);
+            Ok(RecipeResponse {
+                recipe_id,
+                recipe,
+                uses,
+            })
+        })
+        .collect::<StdResult<Vec<RecipeResponse>>>()?;
+
+    Ok(ResponseWrapper::new("recipes", recipes))
+}
+
+fn query_fee_quote(
+    deps: Deps,
+    env: Env,
+    recipe_id: u32,
+    merger: Option<String>,
+) -> StdResult<ResponseWrapper<FeeQuoteResponse>> {
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    RECIPES.load(deps.storage, recipe_id)?;
+
+    let fee_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string());
+    let price = match fee_module_addr {
+        Ok(fee_module_addr) => match StorageHelper::query_fixed_fee(
+            &deps.querier,
+            &fee_module_addr,
+            Modules::Merge.to_string(),
+            MergeFees::new_price(recipe_id),
+            env.block.time,
+        ) {
+            Ok(fixed_payment) => fixed_payment.value,
+            Err(StdError::NotFound { .. }) => Uint128::zero(),
+            Err(e) => return Err(e),
+        },
+        Err(_) => Uint128::zero(),
+    };
+
+    let mut platform_fee = Uint128::zero();
+    let res =
+        StorageHelper::query_storage::<Addr>(&deps.querier, &hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
+    if let (Some(marbu_fee_module), false) = (res, price.is_zero()) {
+        let marbu_fee_module = KompleFeeModule(marbu_fee_module);
+        let fee_percentage = match &merger {
+            Some(merger) => marbu_fee_module.query_total_discounted_percentage_fees(
+                &deps.querier,
+                Modules::Merge.as_str(),
+                merger,
+            )?,
+            None => marbu_fee_module
+                .query_total_percentage_fees(&deps.querier, Modules::Merge.as_str())?,
+        };
+        platform_fee = fee_percentage.mul(price);
+    };
+
+    let payout = price.checked_sub(platform_fee)?;
+
+    Ok(ResponseWrapper::new(
+        "fee_quote",
+        FeeQuoteResponse {
+            price,
+            platform_fee,
+            payout,
+        },
+    ))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
@@ -1,5 +1,5 @@
 use cosmwasm_std::StdError;
-use komple_framework_utils::{shared::SharedError, UtilError};
+use komple_framework_utils::{funds::FundsError, shared::SharedError, UtilError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -19,12 +19,60 @@ pub enum ContractError {
     #[error("No burn messages found")]
     BurnNotFound {},
 
+    #[error("No mint messages found")]
+    MintNotFound {},
+
+    #[error("Recipe not found")]
+    RecipeNotFound {},
+
+    #[error("Invalid recipe")]
+    InvalidRecipe {},
+
+    #[error("Invalid start time")]
+    InvalidStartTime {},
+
+    #[error("Invalid end time")]
+    InvalidEndTime {},
+
+    #[error("Recipe is not active yet")]
+    RecipeNotStarted {},
+
+    #[error("Recipe has ended")]
+    RecipeEnded {},
+
+    #[error("Recipe max uses reached")]
+    RecipeMaxUsesReached {},
+
+    #[error("Burn ids do not match recipe inputs")]
+    InvalidBurnIds {},
+
+    #[error("Duplicate burn id")]
+    DuplicateBurnId {},
+
+    #[error("Metadata not found")]
+    MetadataNotFound {},
+
+    #[error("Metadata is not revealed")]
+    MetadataNotRevealed {},
+
+    #[error("Attribute condition not met")]
+    AttributeConditionNotMet {},
+
+    #[error("Attribute value is not a number")]
+    InvalidAttributeValue {},
+
+    #[error("Output collection must use dynamic metadata")]
+    DynamicMetadataRequired {},
+
     #[error("{0}")]
     Util(#[from] UtilError),
 
     #[error("{0}")]
     SharedError(#[from] SharedError),
 
+    #[error("{0}")]
+    Funds(#[from] FundsError),
+
     #[error("Semver parsing error: {0}")]
     SemVer(String),
 }
@@ -1,6 +1,7 @@
-use crate::state::Config;
+use crate::state::{Config, Recipe};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Uint128};
+use cw20::Cw20ReceiveMsg;
 use komple_framework_types::shared::execute::SharedExecuteMsg;
 use komple_framework_types::shared::query::ResponseWrapper;
 
@@ -13,9 +14,12 @@ pub enum ExecuteMsg {
     UpdateMergeLock { lock: bool },
     /// Public message.
     ///
-    /// Burn multiple tokens and mint a new one.
-    /// Takes merge msg to determine which tokens to burn and which to mint.
-    Merge { msg: MergeMsg },
+    /// Burn multiple tokens and mint a new one using a recipe.
+    /// Burn ids are checked against the recipe inputs in order.
+    Merge {
+        recipe_id: u32,
+        burn_ids: Vec<MergeBurnMsg>,
+    },
     /// Admin message.
     ///
     /// Same as `Merge` message but can be used with permissions.
@@ -25,6 +29,18 @@ pub enum ExecuteMsg {
     },
     /// Admin message.
     ///
+    /// Register a new merge recipe.
+    CreateRecipe { recipe: Recipe },
+    /// Admin message.
+    ///
+    /// Replace an existing merge recipe.
+    UpdateRecipe { recipe_id: u32, recipe: Recipe },
+    /// Admin message.
+    ///
+    /// Remove a merge recipe.
+    RemoveRecipe { recipe_id: u32 },
+    /// Admin message.
+    ///
     /// Update the operators of this contract.
     UpdateOperators { addrs: Vec<String> },
     /// Hub message.
@@ -32,6 +48,18 @@ pub enum ExecuteMsg {
     /// Lock the execute entry point.
     /// Can only be called by the hub module.
     LockExecute {},
+    /// Public message.
+    ///
+    /// Receive cw20 tokens for paying the merge fee.
+    Receive(Cw20ReceiveMsg),
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    Merge {
+        recipe_id: u32,
+        burn_ids: Vec<MergeBurnMsg>,
+    },
 }
 
 impl From<ExecuteMsg> for SharedExecuteMsg {
@@ -52,6 +80,23 @@ pub enum QueryMsg {
     /// Get the operators of this contract.
     #[returns(ResponseWrapper<Vec<String>>)]
     Operators {},
+    /// Get a merge recipe with given recipe id.
+    #[returns(ResponseWrapper<RecipeResponse>)]
+    Recipe { recipe_id: u32 },
+    /// List the merge recipes with pagination.
+    #[returns(ResponseWrapper<Vec<RecipeResponse>>)]
+    Recipes {
+        start_after: Option<u32>,
+        limit: Option<u8>,
+    },
+    /// Get the platform fee of a merge at the recipe price.
+    ///
+    /// Platform fee discounts of the merger are applied if the merger is given.
+    #[returns(ResponseWrapper<FeeQuoteResponse>)]
+    FeeQuote {
+        recipe_id: u32,
+        merger: Option<String>,
+    },
 }
 
 /// Message that is used for the tokens that will be burned.
@@ -61,14 +106,38 @@ pub struct MergeBurnMsg {
     pub token_id: u32,
 }
 
+/// Message that is used for the tokens that will be minted.
+#[cw_serde]
+pub struct MergeMintMsg {
+    pub collection_id: u32,
+    pub metadata_id: Option<u32>,
+}
+
 /// Message that is used for the merge operation.
 #[cw_serde]
 pub struct MergeMsg {
     pub recipient: String,
-    pub mint_id: u32,
-    pub metadata_id: Option<u32>,
+    pub mint_ids: Vec<MergeMintMsg>,
     pub burn_ids: Vec<MergeBurnMsg>,
 }
 
+#[cw_serde]
+pub struct RecipeResponse {
+    pub recipe_id: u32,
+    pub recipe: Recipe,
+    pub uses: u32,
+}
+
+/// The fees of a single merge.
+///
+/// Platform fees are defined on the Marbu fee module.
+/// Payout is the amount distributed for the merge fees.
+#[cw_serde]
+pub struct FeeQuoteResponse {
+    pub price: Uint128,
+    pub platform_fee: Uint128,
+    pub payout: Uint128,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
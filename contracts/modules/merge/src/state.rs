@@ -1,10 +1,15 @@
 use cosmwasm_schema::cw_serde;
+use komple_framework_types::modules::fee::FundInfo;
+use komple_framework_types::modules::merge::{
+    RECIPES_NAMESPACE, RECIPE_ID_NAMESPACE, RECIPE_USES_NAMESPACE,
+};
+use komple_framework_types::modules::permission::AttributeConditions;
 use komple_framework_types::shared::{
     CONFIG_NAMESPACE, EXECUTE_LOCK_NAMESPACE, OPERATORS_NAMESPACE, PARENT_ADDR_NAMESPACE,
 };
 
-use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Timestamp};
+use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
 pub struct Config {
@@ -21,3 +26,83 @@ pub const OPERATORS: Item<Vec<Addr>> = Item::new(OPERATORS_NAMESPACE);
 
 /// Lock for the execute entry point.
 pub const EXECUTE_LOCK: Item<bool> = Item::new(EXECUTE_LOCK_NAMESPACE);
+
+/// Attribute condition that a burned token must satisfy.
+#[cw_serde]
+pub struct RecipeCondition {
+    pub condition: AttributeConditions,
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// A single input of a recipe.
+///
+/// `count` tokens from the collection must be burned,
+/// each of them satisfying every condition in `conditions`.
+#[cw_serde]
+pub struct RecipeInput {
+    pub collection_id: u32,
+    pub count: u32,
+    pub conditions: Vec<RecipeCondition>,
+}
+
+/// Operation used to derive an output attribute from the input attributes.
+///
+/// Numeric operations only work with integer values.
+#[cw_serde]
+pub enum AttributeOperation {
+    Sum,
+    Max,
+    /// Rounded down to the nearest integer.
+    Avg,
+    /// Values are joined in burn order.
+    Concat {
+        separator: String,
+    },
+    /// Picks the input value that comes first in `values`.
+    /// Values that are not listed have the lowest priority.
+    Priority {
+        values: Vec<String>,
+    },
+}
+
+/// Rule for calculating a single output attribute.
+#[cw_serde]
+pub struct AttributeRule {
+    pub trait_type: String,
+    pub operation: AttributeOperation,
+}
+
+/// A token that is minted as the result of a recipe.
+///
+/// If there are attribute rules, the output attributes are derived from the
+/// burned tokens and written to the new token's dynamic metadata.
+#[cw_serde]
+pub struct RecipeOutput {
+    pub collection_id: u32,
+    pub metadata_id: Option<u32>,
+    pub attribute_rules: Vec<AttributeRule>,
+}
+
+/// Merge recipe registered by the admin.
+///
+/// Inputs are checked against the burn ids in order.
+/// Price is set on the fee module with `MergeFees::new_price`
+/// and paid with the denom in `fund_info`.
+#[cw_serde]
+pub struct Recipe {
+    pub inputs: Vec<RecipeInput>,
+    pub outputs: Vec<RecipeOutput>,
+    pub fund_info: FundInfo,
+    pub max_uses: Option<u32>,
+    pub start_time: Option<Timestamp>,
+    pub end_time: Option<Timestamp>,
+}
+/// Map of recipe ids to recipes.
+pub const RECIPES: Map<u32, Recipe> = Map::new(RECIPES_NAMESPACE);
+
+/// ID used for the recipe numbers.
+pub const RECIPE_ID: Item<u32> = Item::new(RECIPE_ID_NAMESPACE);
+
+/// Map of recipe ids to the number of merges done with the recipe.
+pub const RECIPE_USES: Map<u32, u32> = Map::new(RECIPE_USES_NAMESPACE);
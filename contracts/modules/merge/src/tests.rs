@@ -1,4 +1,4 @@
-use crate::msg::{MergeBurnMsg, MergeMsg};
+use crate::msg::MergeBurnMsg;
 use crate::{
     msg::{ExecuteMsg, QueryMsg},
     state::Config,
@@ -79,15 +79,11 @@ mod merge_lock {
         assert_eq!(res.data.merge_lock, true);
 
         let msg = ExecuteMsg::Merge {
-            msg: MergeMsg {
-                recipient: USER.to_string(),
-                mint_id: 1,
-                metadata_id: None,
-                burn_ids: vec![MergeBurnMsg {
-                    collection_id: 1,
-                    token_id: 1,
-                }],
-            },
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
         };
         let err = app
             .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
@@ -1,16 +1,27 @@
-use cosmwasm_std::{to_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{to_binary, Addr, Coin, Decimal, Empty, StdError, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg};
+use cw20_base::msg::InstantiateMsg as Cw20InstantiateMsg;
 use cw721::OwnerOfResponse;
 use cw721_base::msg::{ExecuteMsg as Cw721ExecuteMsg, QueryMsg as Cw721QueryMsg};
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
+use komple_framework_fee_module::msg::ExecuteMsg as FeeModuleExecuteMsg;
 use komple_framework_hub_module::{
     msg::{ExecuteMsg as HubExecuteMsg, InstantiateMsg as HubInstantiateMsg},
     state::HubInfo,
 };
 use komple_framework_merge_module::msg::{
-    ExecuteMsg as MergeModuleExecuteMsg, MergeBurnMsg, MergeMsg,
+    ExecuteMsg as MergeModuleExecuteMsg, FeeQuoteResponse, MergeBurnMsg, MergeMintMsg, MergeMsg,
+    QueryMsg as MergeModuleQueryMsg, ReceiveMsg as MergeModuleReceiveMsg, RecipeResponse,
+};
+use komple_framework_merge_module::state::{
+    AttributeOperation, AttributeRule, Recipe, RecipeCondition, RecipeInput, RecipeOutput,
 };
 use komple_framework_merge_module::ContractError as MergeContractError;
-use komple_framework_metadata_module::msg::InstantiateMsg as MetadataModuleInstantiateMsg;
+use komple_framework_metadata_module::msg::{
+    ExecuteMsg as MetadataModuleExecuteMsg, InstantiateMsg as MetadataModuleInstantiateMsg,
+    MetadataResponse, QueryMsg as MetadataModuleQueryMsg, RevealCommitment,
+};
+use komple_framework_metadata_module::state::{MetaInfo, Metadata, Trait};
 use komple_framework_mint_module::{
     msg::{CollectionFundInfo, ExecuteMsg as MintModuleExecuteMsg},
     state::CollectionInfo,
@@ -20,11 +31,16 @@ use komple_framework_token_module::msg::{
     ExecuteMsg as TokenModuleExecuteMsg, MetadataInfo, QueryMsg as TokenModuleQueryMsg, TokenInfo,
 };
 use komple_framework_token_module::state::CollectionConfig;
+use komple_framework_types::modules::fee::{
+    Fees, FixedPayment, FundInfo, MergeFees, PercentagePayment,
+};
 use komple_framework_types::modules::metadata::Metadata as MetadataType;
 use komple_framework_types::modules::mint::Collections;
-use komple_framework_types::modules::permission::Permissions;
+use komple_framework_types::modules::permission::{AttributeConditions, Permissions};
 use komple_framework_types::modules::Modules;
+use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
+use komple_framework_utils::funds::FundsError;
 use komple_framework_utils::storage::StorageHelper;
 
 pub const USER: &str = "juno..user";
@@ -83,6 +99,24 @@ pub fn merge_module() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn fee_module() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        komple_framework_fee_module::contract::execute,
+        komple_framework_fee_module::contract::instantiate,
+        komple_framework_fee_module::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
 pub fn metadata_module() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         komple_framework_metadata_module::contract::execute,
@@ -160,6 +194,10 @@ pub fn mock_app() -> App {
 }
 
 pub fn proper_instantiate(app: &mut App) -> Addr {
+    instantiate_hub(app, None)
+}
+
+pub fn instantiate_hub(app: &mut App, marbu_fee_module: Option<String>) -> Addr {
     let hub_code_id = app.store_code(hub_module());
 
     let msg = HubInstantiateMsg {
@@ -169,7 +207,7 @@ pub fn proper_instantiate(app: &mut App) -> Addr {
             image: "https://image.com".to_string(),
             external_link: None,
         },
-        marbu_fee_module: None,
+        marbu_fee_module,
     };
     let register_msg = RegisterMsg {
         admin: ADMIN.to_string(),
@@ -250,11 +288,97 @@ pub fn setup_all_modules(app: &mut App, hub_addr: Addr) {
     setup_permission_module(app, hub_addr.clone());
 }
 
+pub fn setup_fee_module(app: &mut App, hub_addr: Addr) -> Addr {
+    let fee_code_id = app.store_code(fee_module());
+
+    let msg = HubExecuteMsg::RegisterModule {
+        module: Modules::Fee.to_string(),
+        msg: Some(
+            to_binary(&RegisterMsg {
+                admin: ADMIN.to_string(),
+                data: None,
+            })
+            .unwrap(),
+        ),
+        code_id: fee_code_id,
+    };
+    let _ = app
+        .execute_contract(Addr::unchecked(ADMIN), hub_addr.clone(), &msg, &[])
+        .unwrap();
+
+    StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Fee.to_string()).unwrap()
+}
+
+pub fn setup_merge_fees(app: &mut App, fee_module_addr: Addr, recipe_id: u32, price: u128) {
+    let msg = FeeModuleExecuteMsg::SetFee {
+        fee_type: Fees::Fixed,
+        module_name: Modules::Merge.to_string(),
+        fee_name: MergeFees::new_price(recipe_id),
+        data: to_binary(&FixedPayment {
+            address: None,
+            value: Uint128::new(price),
+            group: None,
+        })
+        .unwrap(),
+    };
+    let _ = app
+        .execute_contract(Addr::unchecked(ADMIN), fee_module_addr.clone(), &msg, &[])
+        .unwrap();
+
+    let msg = FeeModuleExecuteMsg::SetFee {
+        fee_type: Fees::Percentage,
+        module_name: Modules::Merge.to_string(),
+        fee_name: "hub_admin".to_string(),
+        data: to_binary(&PercentagePayment {
+            address: Some(ADMIN.to_string()),
+            value: Decimal::percent(10),
+            group: None,
+        })
+        .unwrap(),
+    };
+    let _ = app
+        .execute_contract(Addr::unchecked(ADMIN), fee_module_addr, &msg, &[])
+        .unwrap();
+}
+
+pub fn setup_cw20_token(app: &mut App) -> Addr {
+    let code_id = app.store_code(cw20_contract());
+    let msg = Cw20InstantiateMsg {
+        name: "Test token".to_string(),
+        symbol: "cwdenom".to_string(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            address: USER.to_string(),
+            amount: Uint128::new(1_000_000),
+        }],
+        mint: None,
+        marketing: None,
+    };
+    app.instantiate_contract(code_id, Addr::unchecked(ADMIN), &msg, &[], "test", None)
+        .unwrap()
+}
+
 pub fn create_collection(
     app: &mut App,
     mint_module_addr: Addr,
     token_module_code_id: u64,
     linked_collections: Option<Vec<u32>>,
+) {
+    create_collection_with_reveal(
+        app,
+        mint_module_addr,
+        token_module_code_id,
+        linked_collections,
+        None,
+    )
+}
+
+pub fn create_collection_with_reveal(
+    app: &mut App,
+    mint_module_addr: Addr,
+    token_module_code_id: u64,
+    linked_collections: Option<Vec<u32>>,
+    reveal: Option<RevealCommitment>,
 ) {
     let metadata_code_id = app.store_code(metadata_module());
 
@@ -272,12 +396,14 @@ pub fn create_collection(
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataModuleInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal,
         },
         code_id: metadata_code_id,
     };
@@ -294,6 +420,55 @@ pub fn create_collection(
         token_info,
         fund_info,
         linked_collections,
+        metadata_shuffle: None,
+    };
+    let _ = app
+        .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
+        .unwrap();
+}
+
+pub fn create_dynamic_collection(app: &mut App, mint_module_addr: Addr, token_module_code_id: u64) {
+    let metadata_code_id = app.store_code(metadata_module());
+
+    let collection_info = CollectionInfo {
+        collection_type: Collections::Komple,
+        name: "Test Collection".to_string(),
+        description: "Test Collection".to_string(),
+        image: "https://image.com".to_string(),
+        external_link: None,
+    };
+    let token_info = TokenInfo {
+        symbol: "TEST".to_string(),
+        minter: mint_module_addr.to_string(),
+    };
+    let collection_config = CollectionConfig {
+        per_address_limit: None,
+        start_time: None,
+        end_time: None,
+        max_token_limit: None,
+        ipfs_link: None,
+    };
+    let metadata_info = MetadataInfo {
+        instantiate_msg: MetadataModuleInstantiateMsg {
+            metadata_type: MetadataType::Dynamic,
+            reveal: None,
+        },
+        code_id: metadata_code_id,
+    };
+    let fund_info = CollectionFundInfo {
+        is_native: true,
+        denom: NATIVE_DENOM.to_string(),
+        cw20_address: None,
+    };
+    let msg = MintModuleExecuteMsg::CreateCollection {
+        code_id: token_module_code_id,
+        collection_config,
+        collection_info,
+        metadata_info,
+        token_info,
+        fund_info,
+        linked_collections: None,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
@@ -318,7 +493,8 @@ pub fn link_collections(
 pub fn mint_token(app: &mut App, mint_module_addr: Addr, collection_id: u32, sender: &str) {
     let msg = MintModuleExecuteMsg::Mint {
         collection_id,
-        metadata_id: None,
+        quantity: 1,
+        metadata_ids: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(sender), mint_module_addr, &msg, &[])
@@ -347,6 +523,13 @@ pub fn setup_mint_module_operators(app: &mut App, mint_module_addr: Addr, addrs:
         .unwrap();
 }
 
+pub fn create_recipe(app: &mut App, merge_module_addr: Addr, recipe: Recipe) {
+    let msg = MergeModuleExecuteMsg::CreateRecipe { recipe };
+    let _ = app
+        .execute_contract(Addr::unchecked(ADMIN), merge_module_addr, &msg, &[])
+        .unwrap();
+}
+
 pub fn setup_module_permissions(
     app: &mut App,
     permission_module_addr: &Addr,
@@ -488,9 +671,40 @@ mod normal_merge {
         );
         give_approval_to_module(&mut app, collection_3_addr, USER, &merge_module_addr);
 
-        let merge_msg = MergeMsg {
-            recipient: USER.to_string(),
-            mint_id: 2,
+        create_recipe(
+            &mut app,
+            merge_module_addr.clone(),
+            Recipe {
+                inputs: vec![
+                    RecipeInput {
+                        collection_id: 1,
+                        count: 2,
+                        conditions: vec![],
+                    },
+                    RecipeInput {
+                        collection_id: 3,
+                        count: 1,
+                        conditions: vec![],
+                    },
+                ],
+                outputs: vec![RecipeOutput {
+                    collection_id: 2,
+                    metadata_id: None,
+                    attribute_rules: vec![],
+                }],
+                fund_info: FundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                },
+                max_uses: Some(1),
+                start_time: None,
+                end_time: None,
+            },
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
             burn_ids: vec![
                 MergeBurnMsg {
                     collection_id: 1,
@@ -505,11 +719,9 @@ mod normal_merge {
                     token_id: 1,
                 },
             ],
-            metadata_id: None,
         };
-        let msg = MergeModuleExecuteMsg::Merge { msg: merge_msg };
         let _ = app
-            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr, &msg, &[])
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
             .unwrap();
 
         let msg: Cw721QueryMsg<TokenModuleQueryMsg> = Cw721QueryMsg::OwnerOf {
@@ -540,6 +752,13 @@ mod normal_merge {
             .query_wasm_smart(collection_2_addr, &msg)
             .unwrap();
         assert_eq!(res.owner, USER);
+
+        let msg = MergeModuleQueryMsg::Recipe { recipe_id: 1 };
+        let res: ResponseWrapper<RecipeResponse> = app
+            .wrap()
+            .query_wasm_smart(merge_module_addr, &msg)
+            .unwrap();
+        assert_eq!(res.data.uses, 1);
     }
 
     #[test]
@@ -577,34 +796,154 @@ mod normal_merge {
         );
 
         mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr.clone(), 1, RANDOM);
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeNotFound {}.to_string()
+        );
+
+        create_recipe(
+            &mut app,
+            merge_module_addr.clone(),
+            Recipe {
+                inputs: vec![RecipeInput {
+                    collection_id: 1,
+                    count: 2,
+                    conditions: vec![],
+                }],
+                outputs: vec![RecipeOutput {
+                    collection_id: 2,
+                    metadata_id: None,
+                    attribute_rules: vec![],
+                }],
+                fund_info: FundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                },
+                max_uses: None,
+                start_time: None,
+                end_time: None,
+            },
+        );
 
-        let merge_msg = MergeMsg {
-            recipient: USER.to_string(),
-            mint_id: 2,
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
             burn_ids: vec![],
-            metadata_id: None,
         };
-        let msg = MergeModuleExecuteMsg::Merge { msg: merge_msg };
         let err = app
-            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
             .unwrap_err();
         assert_eq!(
             err.source().unwrap().to_string(),
             MergeContractError::BurnNotFound {}.to_string()
         );
 
-        let merge_msg = MergeMsg {
-            recipient: USER.to_string(),
-            mint_id: 2,
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
             burn_ids: vec![MergeBurnMsg {
                 collection_id: 1,
                 token_id: 1,
             }],
-            metadata_id: None,
         };
-        let msg = MergeModuleExecuteMsg::Merge { msg: merge_msg };
         let err = app
-            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::InvalidBurnIds {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 2,
+                    token_id: 1,
+                },
+            ],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::InvalidBurnIds {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+            ],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::DuplicateBurnId {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 3,
+                },
+            ],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::Unauthorized {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 2,
+                },
+            ],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
             .unwrap_err();
         assert_eq!(
             err.source().unwrap().source().unwrap().to_string(),
@@ -640,52 +979,930 @@ mod normal_merge {
     }
 }
 
-mod permission_merge {
+mod recipes {
     use super::*;
 
-    mod ownership_permission {
-        use komple_framework_ownership_permission::msg::OwnershipMsg;
-        use komple_framework_permission_module::msg::PermissionCheckMsg;
+    fn setup_collections(app: &mut App) -> (Addr, Addr, Addr) {
+        let hub_addr = proper_instantiate(app);
+        setup_hub_collections(app, hub_addr)
+    }
 
-        use super::*;
+    fn setup_hub_collections(app: &mut App, hub_addr: Addr) -> (Addr, Addr, Addr) {
+        setup_all_modules(app, hub_addr.clone());
 
-        #[test]
-        fn test_happy_path() {
-            let mut app = mock_app();
-            let hub_addr = proper_instantiate(&mut app);
+        let mint_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Mint.to_string())
+                .unwrap();
+        let merge_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Merge.to_string())
+                .unwrap();
 
-            setup_all_modules(&mut app, hub_addr.clone());
+        let token_module_code_id = app.store_code(token_module());
+        create_collection(app, mint_module_addr.clone(), token_module_code_id, None);
+        create_collection(app, mint_module_addr.clone(), token_module_code_id, None);
 
-            let mint_module_addr = StorageHelper::query_module_address(
-                &app.wrap(),
-                &hub_addr,
-                Modules::Mint.to_string(),
-            )
-            .unwrap();
-            let merge_module_addr = StorageHelper::query_module_address(
-                &app.wrap(),
-                &hub_addr,
-                Modules::Merge.to_string(),
-            )
-            .unwrap();
-            let permission_module_addr = StorageHelper::query_module_address(
-                &app.wrap(),
-                &hub_addr,
-                Modules::Permission.to_string(),
-            )
-            .unwrap();
+        setup_mint_module_operators(
+            app,
+            mint_module_addr.clone(),
+            vec![merge_module_addr.to_string()],
+        );
 
-            let token_module_code_id = app.store_code(token_module());
-            create_collection(
-                &mut app,
-                mint_module_addr.clone(),
-                token_module_code_id,
-                None,
-            );
-            create_collection(
-                &mut app,
-                mint_module_addr.clone(),
-                token_module_code_id,
+        let collection_1_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1).unwrap();
+        give_approval_to_module(app, collection_1_addr, USER, &merge_module_addr);
+
+        (hub_addr, mint_module_addr, merge_module_addr)
+    }
+
+    fn get_recipe(conditions: Vec<RecipeCondition>) -> Recipe {
+        Recipe {
+            inputs: vec![RecipeInput {
+                collection_id: 1,
+                count: 1,
+                conditions,
+            }],
+            outputs: vec![RecipeOutput {
+                collection_id: 2,
+                metadata_id: None,
+                attribute_rules: vec![],
+            }],
+            fund_info: FundInfo {
+                is_native: true,
+                denom: NATIVE_DENOM.to_string(),
+                cw20_address: None,
+            },
+            max_uses: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    #[test]
+    fn test_manage_happy_path() {
+        let mut app = mock_app();
+        let (_, _, merge_module_addr) = setup_collections(&mut app);
+
+        create_recipe(&mut app, merge_module_addr.clone(), get_recipe(vec![]));
+        create_recipe(&mut app, merge_module_addr.clone(), get_recipe(vec![]));
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.max_uses = Some(5);
+        let msg = MergeModuleExecuteMsg::UpdateRecipe {
+            recipe_id: 2,
+            recipe: recipe.clone(),
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let msg = MergeModuleQueryMsg::Recipe { recipe_id: 2 };
+        let res: ResponseWrapper<RecipeResponse> = app
+            .wrap()
+            .query_wasm_smart(merge_module_addr.clone(), &msg)
+            .unwrap();
+        assert_eq!(res.data.recipe, recipe);
+        assert_eq!(res.data.uses, 0);
+
+        let msg = MergeModuleExecuteMsg::RemoveRecipe { recipe_id: 1 };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let msg = MergeModuleQueryMsg::Recipes {
+            start_after: None,
+            limit: None,
+        };
+        let res: ResponseWrapper<Vec<RecipeResponse>> = app
+            .wrap()
+            .query_wasm_smart(merge_module_addr, &msg)
+            .unwrap();
+        assert_eq!(res.data.len(), 1);
+        assert_eq!(res.data[0].recipe_id, 2);
+    }
+
+    #[test]
+    fn test_manage_unhappy_path() {
+        let mut app = mock_app();
+        let (_, _, merge_module_addr) = setup_collections(&mut app);
+
+        let msg = MergeModuleExecuteMsg::CreateRecipe {
+            recipe: get_recipe(vec![]),
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::Unauthorized {}.to_string()
+        );
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.inputs[0].count = 0;
+        let msg = MergeModuleExecuteMsg::CreateRecipe { recipe };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::InvalidRecipe {}.to_string()
+        );
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.start_time = Some(app.block_info().time.plus_seconds(10));
+        recipe.end_time = Some(app.block_info().time.plus_seconds(5));
+        let msg = MergeModuleExecuteMsg::CreateRecipe { recipe };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::InvalidStartTime {}.to_string()
+        );
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.outputs[0].collection_id = 5;
+        let msg = MergeModuleExecuteMsg::CreateRecipe { recipe };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            StdError::NotFound {
+                kind: "Collection".to_string()
+            }
+            .to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::UpdateRecipe {
+            recipe_id: 1,
+            recipe: get_recipe(vec![]),
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeNotFound {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::RemoveRecipe { recipe_id: 1 };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr, &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeNotFound {}.to_string()
+        );
+    }
+
+    #[test]
+    fn test_fee_and_window() {
+        let mut app = mock_app();
+        let (hub_addr, mint_module_addr, merge_module_addr) = setup_collections(&mut app);
+        let fee_module_addr = setup_fee_module(&mut app, hub_addr);
+        setup_merge_fees(&mut app, fee_module_addr, 1, 1_000);
+
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr, 1, USER);
+
+        let funds = [Coin {
+            denom: NATIVE_DENOM.to_string(),
+            amount: Uint128::new(1_000),
+        }];
+        let mut recipe = get_recipe(vec![]);
+        recipe.max_uses = Some(1);
+        recipe.start_time = Some(app.block_info().time.plus_seconds(10));
+        recipe.end_time = Some(app.block_info().time.plus_seconds(20));
+        create_recipe(&mut app, merge_module_addr.clone(), recipe);
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr.clone(),
+                &msg,
+                &funds,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeNotStarted {}.to_string()
+        );
+
+        app.update_block(|block| block.time = block.time.plus_seconds(10));
+
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::Funds(FundsError::MissingFunds {}).to_string()
+        );
+
+        let _ = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr.clone(),
+                &msg,
+                &funds,
+            )
+            .unwrap();
+
+        let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+        assert_eq!(res.amount, Uint128::new(1_000));
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 2,
+            }],
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr.clone(),
+                &msg,
+                &funds,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeMaxUsesReached {}.to_string()
+        );
+
+        app.update_block(|block| block.time = block.time.plus_seconds(10));
+
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr, &msg, &funds)
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::RecipeEnded {}.to_string()
+        );
+    }
+
+    #[test]
+    fn test_multiple_outputs() {
+        let mut app = mock_app();
+        let (_, mint_module_addr, merge_module_addr) = setup_collections(&mut app);
+
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.inputs[0].count = 2;
+        recipe.outputs = vec![
+            RecipeOutput {
+                collection_id: 2,
+                metadata_id: None,
+                attribute_rules: vec![],
+            },
+            RecipeOutput {
+                collection_id: 2,
+                metadata_id: None,
+                attribute_rules: vec![],
+            },
+            RecipeOutput {
+                collection_id: 1,
+                metadata_id: None,
+                attribute_rules: vec![],
+            },
+        ];
+        create_recipe(&mut app, merge_module_addr.clone(), recipe);
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 2,
+                },
+            ],
+        };
+        // Free merges do not accept funds
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr.clone(),
+                &msg,
+                &[Coin {
+                    denom: NATIVE_DENOM.to_string(),
+                    amount: Uint128::new(100),
+                }],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::Funds(FundsError::InvalidFunds {
+                got: "100".to_string(),
+                expected: "0".to_string(),
+            })
+            .to_string()
+        );
+
+        let _ = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr, &msg, &[])
+            .unwrap();
+
+        let collection_1_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1).unwrap();
+        let collection_2_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &2).unwrap();
+
+        for token_id in ["1", "2"] {
+            let msg: Cw721QueryMsg<TokenModuleQueryMsg> = Cw721QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
+            };
+            let res: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(collection_2_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.owner, USER);
+        }
+
+        let msg: Cw721QueryMsg<TokenModuleQueryMsg> = Cw721QueryMsg::OwnerOf {
+            token_id: "3".to_string(),
+            include_expired: None,
+        };
+        let res: OwnerOfResponse = app
+            .wrap()
+            .query_wasm_smart(collection_1_addr, &msg)
+            .unwrap();
+        assert_eq!(res.owner, USER);
+    }
+
+    #[test]
+    fn test_cw20_fee() {
+        let mut app = mock_app();
+        let (hub_addr, mint_module_addr, merge_module_addr) = setup_collections(&mut app);
+        let fee_module_addr = setup_fee_module(&mut app, hub_addr);
+        setup_merge_fees(&mut app, fee_module_addr, 1, 1_000);
+        let cw20_addr = setup_cw20_token(&mut app);
+
+        mint_token(&mut app, mint_module_addr, 1, USER);
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.fund_info = FundInfo {
+            is_native: false,
+            denom: "cwdenom".to_string(),
+            cw20_address: Some(cw20_addr.clone()),
+        };
+        create_recipe(&mut app, merge_module_addr.clone(), recipe);
+
+        let burn_ids = vec![MergeBurnMsg {
+            collection_id: 1,
+            token_id: 1,
+        }];
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: burn_ids.clone(),
+        };
+        let err = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr.clone(),
+                &msg,
+                &[Coin {
+                    denom: NATIVE_DENOM.to_string(),
+                    amount: Uint128::new(1_000),
+                }],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::Funds(FundsError::InvalidCw20Token {}).to_string()
+        );
+
+        let msg = Cw20ExecuteMsg::Send {
+            contract: merge_module_addr.to_string(),
+            amount: Uint128::new(500),
+            msg: to_binary(&MergeModuleReceiveMsg::Merge {
+                recipe_id: 1,
+                burn_ids: burn_ids.clone(),
+            })
+            .unwrap(),
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), cw20_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().source().unwrap().to_string(),
+            MergeContractError::Funds(FundsError::InvalidFunds {
+                got: "500".to_string(),
+                expected: "1000".to_string(),
+            })
+            .to_string()
+        );
+
+        let msg = Cw20ExecuteMsg::Send {
+            contract: merge_module_addr.to_string(),
+            amount: Uint128::new(1_000),
+            msg: to_binary(&MergeModuleReceiveMsg::Merge {
+                recipe_id: 1,
+                burn_ids,
+            })
+            .unwrap(),
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(USER), cw20_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let res: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                cw20_addr,
+                &Cw20QueryMsg::Balance {
+                    address: ADMIN.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(res.balance, Uint128::new(1_000));
+    }
+
+    #[test]
+    fn test_platform_fee() {
+        let mut app = mock_app();
+
+        // Platform takes 20% of the merge price
+        let fee_code_id = app.store_code(fee_module());
+        let msg = RegisterMsg {
+            admin: ADMIN.to_string(),
+            data: None,
+        };
+        let marbu_fee_module_addr = app
+            .instantiate_contract(fee_code_id, Addr::unchecked(ADMIN), &msg, &[], "test", None)
+            .unwrap();
+        let msg = FeeModuleExecuteMsg::SetFee {
+            fee_type: Fees::Percentage,
+            module_name: Modules::Merge.to_string(),
+            fee_name: "komple".to_string(),
+            data: to_binary(&PercentagePayment {
+                address: Some(RANDOM_2.to_string()),
+                value: Decimal::percent(20),
+                group: None,
+            })
+            .unwrap(),
+        };
+        let _ = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                marbu_fee_module_addr.clone(),
+                &msg,
+                &[],
+            )
+            .unwrap();
+
+        let hub_addr = instantiate_hub(&mut app, Some(marbu_fee_module_addr.to_string()));
+        let (hub_addr, mint_module_addr, merge_module_addr) =
+            setup_hub_collections(&mut app, hub_addr);
+        let fee_module_addr = setup_fee_module(&mut app, hub_addr);
+        setup_merge_fees(&mut app, fee_module_addr, 1, 1_000);
+
+        mint_token(&mut app, mint_module_addr, 1, USER);
+        create_recipe(&mut app, merge_module_addr.clone(), get_recipe(vec![]));
+
+        let msg = MergeModuleQueryMsg::FeeQuote {
+            recipe_id: 1,
+            merger: Some(USER.to_string()),
+        };
+        let res: ResponseWrapper<FeeQuoteResponse> = app
+            .wrap()
+            .query_wasm_smart(merge_module_addr.clone(), &msg)
+            .unwrap();
+        assert_eq!(
+            res.data,
+            FeeQuoteResponse {
+                price: Uint128::new(1_000),
+                platform_fee: Uint128::new(200),
+                payout: Uint128::new(800),
+            }
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
+        };
+        let _ = app
+            .execute_contract(
+                Addr::unchecked(USER),
+                merge_module_addr,
+                &msg,
+                &[Coin {
+                    denom: NATIVE_DENOM.to_string(),
+                    amount: Uint128::new(1_000),
+                }],
+            )
+            .unwrap();
+
+        // Platform fee is taken out before the hub merge fees
+        let res = app.wrap().query_balance(RANDOM_2, NATIVE_DENOM).unwrap();
+        assert_eq!(res.amount, Uint128::new(200));
+        let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+        assert_eq!(res.amount, Uint128::new(800));
+    }
+
+    #[test]
+    fn test_attribute_conditions() {
+        let mut app = mock_app();
+        let (_, mint_module_addr, merge_module_addr) = setup_collections(&mut app);
+
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+
+        let collection_1_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1).unwrap();
+        let sub_modules =
+            StorageHelper::query_token_sub_modules(&app.wrap(), &collection_1_addr).unwrap();
+        let msg = MetadataModuleExecuteMsg::AddAttribute {
+            raw_metadata: false,
+            id: 2,
+            attribute: Trait {
+                trait_type: "level".to_string(),
+                value: "10".to_string(),
+            },
+        };
+        let _ = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                sub_modules.metadata.unwrap(),
+                &msg,
+                &[],
+            )
+            .unwrap();
+
+        create_recipe(
+            &mut app,
+            merge_module_addr.clone(),
+            get_recipe(vec![RecipeCondition {
+                condition: AttributeConditions::GreaterThanOrEqual,
+                trait_type: "level".to_string(),
+                value: "5".to_string(),
+            }]),
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::AttributeConditionNotMet {}.to_string()
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 2,
+            }],
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr, &msg, &[])
+            .unwrap();
+
+        let msg: Cw721QueryMsg<TokenModuleQueryMsg> = Cw721QueryMsg::OwnerOf {
+            token_id: "2".to_string(),
+            include_expired: None,
+        };
+        let res: Result<OwnerOfResponse, cosmwasm_std::StdError> =
+            app.wrap().query_wasm_smart(collection_1_addr, &msg);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_unrevealed_inputs() {
+        let mut app = mock_app();
+        let hub_addr = proper_instantiate(&mut app);
+        setup_all_modules(&mut app, hub_addr.clone());
+
+        let mint_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Mint.to_string())
+                .unwrap();
+        let merge_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Merge.to_string())
+                .unwrap();
+
+        let token_module_code_id = app.store_code(token_module());
+        create_collection_with_reveal(
+            &mut app,
+            mint_module_addr.clone(),
+            token_module_code_id,
+            None,
+            Some(RevealCommitment {
+                unrevealed_metadata: Metadata {
+                    meta_info: MetaInfo {
+                        image: Some("https://example.com/unrevealed.png".to_string()),
+                        external_url: None,
+                        description: None,
+                        youtube_url: None,
+                        animation_url: None,
+                    },
+                    attributes: vec![],
+                },
+                commitment: "0".repeat(64),
+            }),
+        );
+        create_collection(
+            &mut app,
+            mint_module_addr.clone(),
+            token_module_code_id,
+            None,
+        );
+        setup_mint_module_operators(
+            &mut app,
+            mint_module_addr.clone(),
+            vec![merge_module_addr.to_string()],
+        );
+        let collection_1_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1).unwrap();
+        give_approval_to_module(&mut app, collection_1_addr, USER, &merge_module_addr);
+
+        mint_token(&mut app, mint_module_addr, 1, USER);
+
+        // Attributes of unrevealed tokens are not checked against the placeholder
+        create_recipe(
+            &mut app,
+            merge_module_addr.clone(),
+            get_recipe(vec![RecipeCondition {
+                condition: AttributeConditions::Absent,
+                trait_type: "level".to_string(),
+                value: "".to_string(),
+            }]),
+        );
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![MergeBurnMsg {
+                collection_id: 1,
+                token_id: 1,
+            }],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr, &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::MetadataNotRevealed {}.to_string()
+        );
+    }
+
+    #[test]
+    fn test_derived_attributes() {
+        let mut app = mock_app();
+        let hub_addr = proper_instantiate(&mut app);
+
+        setup_all_modules(&mut app, hub_addr.clone());
+
+        let mint_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Mint.to_string())
+                .unwrap();
+        let merge_module_addr =
+            StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Merge.to_string())
+                .unwrap();
+
+        let token_module_code_id = app.store_code(token_module());
+        create_collection(
+            &mut app,
+            mint_module_addr.clone(),
+            token_module_code_id,
+            None,
+        );
+        create_dynamic_collection(&mut app, mint_module_addr.clone(), token_module_code_id);
+
+        setup_mint_module_operators(
+            &mut app,
+            mint_module_addr.clone(),
+            vec![merge_module_addr.to_string()],
+        );
+
+        let collection_1_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1).unwrap();
+        let collection_2_addr =
+            StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &2).unwrap();
+        give_approval_to_module(
+            &mut app,
+            collection_1_addr.clone(),
+            USER,
+            &merge_module_addr,
+        );
+
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+        mint_token(&mut app, mint_module_addr.clone(), 1, USER);
+
+        let metadata_1_addr =
+            StorageHelper::query_token_sub_modules(&app.wrap(), &collection_1_addr)
+                .unwrap()
+                .metadata
+                .unwrap();
+        let metadata_2_addr =
+            StorageHelper::query_token_sub_modules(&app.wrap(), &collection_2_addr)
+                .unwrap()
+                .metadata
+                .unwrap();
+
+        for (token_id, level, element) in [(1, "3", "fire"), (2, "4", "water")] {
+            for (trait_type, value) in [("level", level), ("element", element)] {
+                let msg = MetadataModuleExecuteMsg::AddAttribute {
+                    raw_metadata: false,
+                    id: token_id,
+                    attribute: Trait {
+                        trait_type: trait_type.to_string(),
+                        value: value.to_string(),
+                    },
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), metadata_1_addr.clone(), &msg, &[])
+                    .unwrap();
+            }
+        }
+
+        let msg = MetadataModuleExecuteMsg::AddMetadata {
+            meta_info: MetaInfo {
+                image: None,
+                external_url: None,
+                description: None,
+                animation_url: None,
+                youtube_url: None,
+            },
+            attributes: vec![Trait {
+                trait_type: "level".to_string(),
+                value: "1".to_string(),
+            }],
+        };
+        let _ = app
+            .execute_contract(Addr::unchecked(ADMIN), metadata_2_addr.clone(), &msg, &[])
+            .unwrap();
+
+        let mut recipe = get_recipe(vec![]);
+        recipe.inputs[0].count = 2;
+        recipe.outputs[0].attribute_rules = vec![
+            AttributeRule {
+                trait_type: "level".to_string(),
+                operation: AttributeOperation::Sum,
+            },
+            AttributeRule {
+                trait_type: "element".to_string(),
+                operation: AttributeOperation::Priority {
+                    values: vec!["water".to_string(), "fire".to_string()],
+                },
+            },
+            AttributeRule {
+                trait_type: "power".to_string(),
+                operation: AttributeOperation::Max,
+            },
+        ];
+
+        let msg = MergeModuleExecuteMsg::CreateRecipe {
+            recipe: recipe.clone(),
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::InvalidRecipe {}.to_string()
+        );
+
+        recipe.outputs[0].metadata_id = Some(1);
+        recipe.outputs[0].collection_id = 1;
+        let msg = MergeModuleExecuteMsg::CreateRecipe {
+            recipe: recipe.clone(),
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(ADMIN), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            MergeContractError::DynamicMetadataRequired {}.to_string()
+        );
+
+        recipe.outputs[0].collection_id = 2;
+        create_recipe(&mut app, merge_module_addr.clone(), recipe);
+
+        let msg = MergeModuleExecuteMsg::Merge {
+            recipe_id: 1,
+            burn_ids: vec![
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 1,
+                },
+                MergeBurnMsg {
+                    collection_id: 1,
+                    token_id: 2,
+                },
+            ],
+        };
+        let err = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr.clone(), &msg, &[])
+            .unwrap_err();
+        assert_eq!(
+            err.source().unwrap().source().unwrap().to_string(),
+            MergeContractError::Unauthorized {}.to_string()
+        );
+
+        let msg_operators = MetadataModuleExecuteMsg::UpdateOperators {
+            addrs: vec![merge_module_addr.to_string()],
+        };
+        let _ = app
+            .execute_contract(
+                Addr::unchecked(ADMIN),
+                metadata_2_addr.clone(),
+                &msg_operators,
+                &[],
+            )
+            .unwrap();
+
+        let _ = app
+            .execute_contract(Addr::unchecked(USER), merge_module_addr, &msg, &[])
+            .unwrap();
+
+        let msg = MetadataModuleQueryMsg::Metadata { token_id: 1 };
+        let res: ResponseWrapper<MetadataResponse> =
+            app.wrap().query_wasm_smart(metadata_2_addr, &msg).unwrap();
+        assert_eq!(
+            res.data.metadata.attributes,
+            vec![
+                Trait {
+                    trait_type: "level".to_string(),
+                    value: "7".to_string(),
+                },
+                Trait {
+                    trait_type: "element".to_string(),
+                    value: "water".to_string(),
+                },
+            ]
+        );
+    }
+}
+
+mod permission_merge {
+    use super::*;
+
+    mod ownership_permission {
+        use komple_framework_ownership_permission::msg::OwnershipMsg;
+        use komple_framework_permission_module::msg::PermissionCheckMsg;
+
+        use super::*;
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let hub_addr = proper_instantiate(&mut app);
+
+            setup_all_modules(&mut app, hub_addr.clone());
+
+            let mint_module_addr = StorageHelper::query_module_address(
+                &app.wrap(),
+                &hub_addr,
+                Modules::Mint.to_string(),
+            )
+            .unwrap();
+            let merge_module_addr = StorageHelper::query_module_address(
+                &app.wrap(),
+                &hub_addr,
+                Modules::Merge.to_string(),
+            )
+            .unwrap();
+            let permission_module_addr = StorageHelper::query_module_address(
+                &app.wrap(),
+                &hub_addr,
+                Modules::Permission.to_string(),
+            )
+            .unwrap();
+
+            let token_module_code_id = app.store_code(token_module());
+            create_collection(
+                &mut app,
+                mint_module_addr.clone(),
+                token_module_code_id,
+                None,
+            );
+            create_collection(
+                &mut app,
+                mint_module_addr.clone(),
+                token_module_code_id,
                 None,
             );
             create_collection(
@@ -769,7 +1986,10 @@ mod permission_merge {
             .unwrap();
             let merge_msg = MergeMsg {
                 recipient: USER.to_string(),
-                mint_id: 2,
+                mint_ids: vec![MergeMintMsg {
+                    collection_id: 2,
+                    metadata_id: None,
+                }],
                 burn_ids: vec![
                     MergeBurnMsg {
                         collection_id: 1,
@@ -784,7 +2004,6 @@ mod permission_merge {
                         token_id: 1,
                     },
                 ],
-                metadata_id: None,
             };
             let msg = MergeModuleExecuteMsg::PermissionMerge {
                 permission_msg,
@@ -947,7 +2166,10 @@ mod permission_merge {
             .unwrap();
             let merge_msg = MergeMsg {
                 recipient: USER.to_string(),
-                mint_id: 3,
+                mint_ids: vec![MergeMintMsg {
+                    collection_id: 3,
+                    metadata_id: None,
+                }],
                 burn_ids: vec![
                     MergeBurnMsg {
                         collection_id: 1,
@@ -958,7 +2180,6 @@ mod permission_merge {
                         token_id: 1,
                     },
                 ],
-                metadata_id: None,
             };
             let msg = MergeModuleExecuteMsg::PermissionMerge {
                 permission_msg,
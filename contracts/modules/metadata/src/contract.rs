@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    from_binary, to_binary, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
-    StdError, StdResult,
+    from_binary, to_binary, to_vec, Attribute, Binary, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, Storage,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_storage_plus::Bound;
@@ -14,18 +14,23 @@ use komple_framework_utils::check_admin_privileges;
 use komple_framework_utils::response::{EventHelper, ResponseHelper};
 use komple_framework_utils::shared::execute_update_operators;
 use semver::Version;
+use sha2::{Digest, Sha256};
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg, MetadataResponse, MigrateMsg, QueryMsg};
 use crate::state::{
-    Config, MetaInfo, Metadata, Trait, COLLECTION_ADDR, CONFIG, DYNAMIC_LINKED_METADATA,
-    LINKED_METADATA, METADATA, METADATA_ID, OPERATORS,
+    Config, MetaInfo, Metadata, Reveal, RevealProgress, Trait, COLLECTION_ADDR, CONFIG,
+    DYNAMIC_LINKED_METADATA, LINKED_METADATA, METADATA, METADATA_ID, OPERATORS, REVEAL,
+    REVEAL_PROGRESS,
 };
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:komple-framework-metadata-module";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+// Number of raw metadata hashed in a single reveal transaction
+const REVEAL_PAGE_LIMIT: usize = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -53,12 +58,38 @@ pub fn instantiate(
 
     METADATA_ID.save(deps.storage, &0)?;
 
+    let reveal_commitment = data
+        .reveal
+        .as_ref()
+        .map(|reveal| reveal.commitment.to_lowercase());
+    if let Some(reveal) = data.reveal {
+        match hex::decode(&reveal.commitment) {
+            Ok(commitment) if commitment.len() == 32 => {}
+            _ => return Err(ContractError::InvalidReveal {}),
+        };
+        REVEAL.save(
+            deps.storage,
+            &Reveal {
+                unrevealed_metadata: reveal.unrevealed_metadata,
+                commitment: reveal.commitment.to_lowercase(),
+                metadata_root: None,
+                seed: None,
+                is_modified: false,
+            },
+        )?;
+    }
+
     Ok(
         ResponseHelper::new_module("metadata", "instantiate").add_event(
             EventHelper::new("metadata_instantiate")
                 .add_attribute("admin", config.admin)
                 .add_attribute("metadata_type", config.metadata_type.to_string())
                 .add_attribute("collection_addr", info.sender)
+                .check_add_attribute(
+                    &reveal_commitment,
+                    "reveal_commitment",
+                    reveal_commitment.clone().unwrap_or_default(),
+                )
                 .get(),
         ),
     )
@@ -119,6 +150,10 @@ pub fn execute(
                 Err(err) => Err(err.into()),
             }
         }
+        ExecuteMsg::Reveal {
+            metadata_root,
+            seed,
+        } => execute_reveal(deps, env, info, metadata_root, seed),
     }
 }
 
@@ -149,6 +184,8 @@ fn execute_add_metadata(
     // Metadata id is the raw metadata id
     let metadata_id = (METADATA_ID.load(deps.storage)?) + 1;
 
+    check_reveal(deps.storage, metadata_id)?;
+
     METADATA.save(deps.storage, metadata_id, &metadata)?;
     METADATA_ID.save(deps.storage, &metadata_id)?;
 
@@ -307,6 +344,7 @@ fn execute_update_meta_info(
         || config.metadata_type == MetadataType::Standard
         || config.metadata_type == MetadataType::Shared
     {
+        check_reveal(deps.storage, metadata_id)?;
         METADATA.save(deps.storage, metadata_id, &metadata)?;
     } else {
         DYNAMIC_LINKED_METADATA.save(deps.storage, id, &metadata)?;
@@ -371,6 +409,11 @@ fn execute_add_attribute(
     attribute: Trait,
 ) -> Result<Response, ContractError> {
     let collection_addr = COLLECTION_ADDR.may_load(deps.storage)?;
+    // Operators can only write to the dynamic metadata of the tokens
+    let operators = match raw_metadata {
+        true => None,
+        false => OPERATORS.may_load(deps.storage)?,
+    };
     let config = CONFIG.load(deps.storage)?;
 
     check_admin_privileges(
@@ -378,7 +421,7 @@ fn execute_add_attribute(
         &env.contract.address,
         &config.admin,
         collection_addr,
-        None,
+        operators,
     )?;
 
     let (metadata_id, mut metadata) = match raw_metadata {
@@ -401,6 +444,7 @@ fn execute_add_attribute(
         || config.metadata_type == MetadataType::Standard
         || config.metadata_type == MetadataType::Shared
     {
+        check_reveal(deps.storage, metadata_id)?;
         METADATA.save(deps.storage, metadata_id, &metadata)?;
     } else {
         DYNAMIC_LINKED_METADATA.save(deps.storage, id, &metadata)?;
@@ -429,6 +473,11 @@ fn execute_update_attribute(
     attribute: Trait,
 ) -> Result<Response, ContractError> {
     let collection_addr = COLLECTION_ADDR.may_load(deps.storage)?;
+    // Operators can only write to the dynamic metadata of the tokens
+    let operators = match raw_metadata {
+        true => None,
+        false => OPERATORS.may_load(deps.storage)?,
+    };
     let config = CONFIG.load(deps.storage)?;
 
     check_admin_privileges(
@@ -436,7 +485,7 @@ fn execute_update_attribute(
         &env.contract.address,
         &config.admin,
         collection_addr,
-        None,
+        operators,
     )?;
 
     let (metadata_id, mut metadata) = match raw_metadata {
@@ -464,6 +513,7 @@ fn execute_update_attribute(
         || config.metadata_type == MetadataType::Standard
         || config.metadata_type == MetadataType::Shared
     {
+        check_reveal(deps.storage, metadata_id)?;
         METADATA.save(deps.storage, metadata_id, &metadata)?;
     } else {
         DYNAMIC_LINKED_METADATA.save(deps.storage, id, &metadata)?;
@@ -526,6 +576,7 @@ fn execute_remove_attribute(
         || config.metadata_type == MetadataType::Standard
         || config.metadata_type == MetadataType::Shared
     {
+        check_reveal(deps.storage, metadata_id)?;
         METADATA.save(deps.storage, metadata_id, &metadata)?;
     } else {
         DYNAMIC_LINKED_METADATA.save(deps.storage, id, &metadata)?;
@@ -583,6 +634,128 @@ fn execute_unlink_metadata(
     )
 }
 
+fn execute_reveal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    metadata_root: String,
+    seed: String,
+) -> Result<Response, ContractError> {
+    let collection_addr = COLLECTION_ADDR.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        collection_addr,
+        None,
+    )?;
+
+    let mut reveal = REVEAL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::InvalidReveal {})?;
+    if reveal.metadata_root.is_some() {
+        return Err(ContractError::AlreadyRevealed {});
+    }
+
+    // The commitment must be made with the same root and seed
+    let metadata_root = metadata_root.to_lowercase();
+    if reveal.commitment != get_reveal_commitment(&metadata_root, &seed) {
+        return Err(ContractError::InvalidReveal {});
+    }
+
+    // Raw metadata is added to the root in pages
+    let mut progress = REVEAL_PROGRESS
+        .may_load(deps.storage)?
+        .unwrap_or(RevealProgress {
+            metadata_id: 0,
+            metadata_root: String::from(""),
+        });
+    let start = Some(Bound::exclusive(progress.metadata_id));
+    for item in METADATA
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(REVEAL_PAGE_LIMIT)
+    {
+        let (metadata_id, metadata) = item?;
+        progress.metadata_root = get_next_metadata_root(&progress.metadata_root, &metadata)?;
+        progress.metadata_id = metadata_id;
+    }
+
+    let start = Some(Bound::exclusive(progress.metadata_id));
+    let is_completed = METADATA
+        .keys(deps.storage, start, None, Order::Ascending)
+        .next()
+        .is_none();
+    if !is_completed {
+        REVEAL_PROGRESS.save(deps.storage, &progress)?;
+        return Ok(ResponseHelper::new_module("metadata", "reveal").add_event(
+            EventHelper::new("metadata_reveal")
+                .add_attribute("metadata_id", progress.metadata_id.to_string())
+                .add_attribute("is_completed", "false")
+                .get(),
+        ));
+    }
+
+    // Metadata root must be the root of the raw metadata
+    if metadata_root != progress.metadata_root {
+        return Err(ContractError::InvalidReveal {});
+    }
+    REVEAL_PROGRESS.remove(deps.storage);
+
+    reveal.metadata_root = Some(metadata_root.clone());
+    reveal.seed = Some(seed.clone());
+    REVEAL.save(deps.storage, &reveal)?;
+
+    Ok(ResponseHelper::new_module("metadata", "reveal").add_event(
+        EventHelper::new("metadata_reveal")
+            .add_attribute("metadata_id", progress.metadata_id.to_string())
+            .add_attribute("is_completed", "true")
+            .add_attribute("metadata_root", metadata_root)
+            .add_attribute("seed", seed)
+            .get(),
+    ))
+}
+
+fn get_next_metadata_root(metadata_root: &str, metadata: &Metadata) -> StdResult<String> {
+    let metadata_root =
+        hex::decode(metadata_root).map_err(|err| StdError::generic_err(err.to_string()))?;
+    let mut hasher = Sha256::new();
+    hasher.update(metadata_root);
+    hasher.update(Sha256::digest(to_vec(metadata)?));
+    Ok(hex::encode(hasher.finalize()))
+}
+
+// Raw metadata changes restart the reveal if the entry is already hashed
+// and invalidate a completed reveal
+fn check_reveal(storage: &mut dyn Storage, metadata_id: u32) -> StdResult<()> {
+    if let Some(progress) = REVEAL_PROGRESS.may_load(storage)? {
+        if metadata_id <= progress.metadata_id {
+            REVEAL_PROGRESS.remove(storage);
+        }
+    }
+    if let Some(mut reveal) = REVEAL.may_load(storage)? {
+        if reveal.metadata_root.is_some() && !reveal.is_modified {
+            reveal.is_modified = true;
+            REVEAL.save(storage, &reveal)?;
+        }
+    }
+    Ok(())
+}
+
+fn get_reveal_commitment(metadata_root: &str, seed: &str) -> String {
+    hex::encode(Sha256::digest(format!("{}{}", metadata_root, seed)))
+}
+
+// Unrevealed metadata is served for every token until the reveal
+fn get_unrevealed_metadata(storage: &dyn Storage) -> StdResult<Option<Metadata>> {
+    let reveal = REVEAL.may_load(storage)?;
+    match reveal {
+        Some(reveal) if reveal.metadata_root.is_none() => Ok(Some(reveal.unrevealed_metadata)),
+        _ => Ok(None),
+    }
+}
+
 fn get_metadata_with_token_id(
     deps: &DepsMut,
     metadata_type: &MetadataType,
@@ -632,6 +805,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_binary(&query_metadatas(deps, start_after, limit)?)
         }
         QueryMsg::Operators {} => to_binary(&query_operators(deps)?),
+        QueryMsg::Reveal {} => to_binary(&query_reveal(deps)?),
+        QueryMsg::RevealProgress {} => to_binary(&query_reveal_progress(deps)?),
+        QueryMsg::VerifyReveal {} => to_binary(&query_verify_reveal(deps)?),
     }
 }
 
@@ -642,6 +818,7 @@ fn query_config(deps: Deps) -> StdResult<ResponseWrapper<Config>> {
 
 fn query_raw_metadata(deps: Deps, metadata_id: u32) -> StdResult<ResponseWrapper<Metadata>> {
     let metadata = METADATA.load(deps.storage, metadata_id)?;
+    let metadata = get_unrevealed_metadata(deps.storage)?.unwrap_or(metadata);
     Ok(ResponseWrapper::new("raw_metadata", metadata))
 }
 
@@ -659,6 +836,7 @@ fn query_metadata(deps: Deps, token_id: u32) -> StdResult<ResponseWrapper<Metada
             (token_id, metadata)
         }
     };
+    let metadata = get_unrevealed_metadata(deps.storage)?.unwrap_or(metadata);
 
     Ok(ResponseWrapper::new(
         "metadata",
@@ -676,6 +854,7 @@ fn query_raw_metadatas(
 ) -> StdResult<ResponseWrapper<Vec<MetadataResponse>>> {
     let limit = limit.unwrap_or(30) as usize;
     let start = start_after.map(Bound::exclusive);
+    let unrevealed_metadata = get_unrevealed_metadata(deps.storage)?;
 
     let metadatas = METADATA
         .range(deps.storage, start, None, Order::Ascending)
@@ -684,7 +863,7 @@ fn query_raw_metadatas(
             let (metadata_id, metadata) = item.unwrap();
             MetadataResponse {
                 metadata_id,
-                metadata,
+                metadata: unrevealed_metadata.clone().unwrap_or(metadata),
             }
         })
         .collect::<Vec<MetadataResponse>>();
@@ -732,6 +911,16 @@ fn query_metadatas(
             data
         }
     };
+    let metadatas = match get_unrevealed_metadata(deps.storage)? {
+        Some(unrevealed_metadata) => metadatas
+            .into_iter()
+            .map(|response| MetadataResponse {
+                metadata: unrevealed_metadata.clone(),
+                ..response
+            })
+            .collect(),
+        None => metadatas,
+    };
 
     Ok(ResponseWrapper::new("metadatas", metadatas))
 }
@@ -745,6 +934,33 @@ fn query_operators(deps: Deps) -> StdResult<ResponseWrapper<Vec<String>>> {
     Ok(ResponseWrapper::new("operators", addrs))
 }
 
+fn query_reveal(deps: Deps) -> StdResult<ResponseWrapper<Option<Reveal>>> {
+    let reveal = REVEAL.may_load(deps.storage)?;
+    Ok(ResponseWrapper::new("reveal", reveal))
+}
+
+fn query_reveal_progress(deps: Deps) -> StdResult<ResponseWrapper<Option<RevealProgress>>> {
+    let progress = REVEAL_PROGRESS.may_load(deps.storage)?;
+    Ok(ResponseWrapper::new("reveal_progress", progress))
+}
+
+// Metadata root is checked against the raw metadata on reveal
+// so only the later changes are tracked
+fn query_verify_reveal(deps: Deps) -> StdResult<ResponseWrapper<bool>> {
+    let reveal = REVEAL.may_load(deps.storage)?;
+    let verified = match reveal {
+        Some(Reveal {
+            commitment,
+            metadata_root: Some(metadata_root),
+            seed: Some(seed),
+            is_modified,
+            ..
+        }) => !is_modified && commitment == get_reveal_commitment(&metadata_root, &seed),
+        _ => false,
+    };
+    Ok(ResponseWrapper::new("verify_reveal", verified))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     let version: Version = CONTRACT_VERSION.parse()?;
@@ -22,6 +22,12 @@ pub enum ContractError {
     #[error("Metadata not found")]
     MissingMetadata {},
 
+    #[error("Invalid reveal")]
+    InvalidReveal {},
+
+    #[error("Metadata is already revealed")]
+    AlreadyRevealed {},
+
     #[error("{0}")]
     Util(#[from] UtilError),
 
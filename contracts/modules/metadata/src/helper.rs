@@ -1,6 +1,6 @@
 use crate::{
     msg::{ExecuteMsg, MetadataResponse, QueryMsg},
-    state::{MetaInfo, Trait},
+    state::{Config, MetaInfo, Metadata, Reveal, Trait},
 };
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{to_binary, Addr, QuerierWrapper, StdResult, WasmMsg};
@@ -54,7 +54,59 @@ impl KompleMetadataModule {
         })
     }
 
+    pub fn add_attribute_msg(
+        &self,
+        raw_metadata: bool,
+        id: u32,
+        attribute: Trait,
+    ) -> StdResult<WasmMsg> {
+        let msg = ExecuteMsg::AddAttribute {
+            raw_metadata,
+            id,
+            attribute,
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+
+    pub fn update_attribute_msg(
+        &self,
+        raw_metadata: bool,
+        id: u32,
+        attribute: Trait,
+    ) -> StdResult<WasmMsg> {
+        let msg = ExecuteMsg::UpdateAttribute {
+            raw_metadata,
+            id,
+            attribute,
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+
     // Queries
+    pub fn query_config(&self, querier: &QuerierWrapper) -> StdResult<Config> {
+        let msg = QueryMsg::Config {};
+        let res: ResponseWrapper<Config> = querier.query_wasm_smart(self.0.to_string(), &msg)?;
+        Ok(res.data)
+    }
+
+    pub fn query_raw_metadata(
+        &self,
+        querier: &QuerierWrapper,
+        metadata_id: u32,
+    ) -> StdResult<Metadata> {
+        let msg = QueryMsg::RawMetadata { metadata_id };
+        let res: ResponseWrapper<Metadata> = querier.query_wasm_smart(self.0.to_string(), &msg)?;
+        Ok(res.data)
+    }
+
     pub fn query_metadata(
         &self,
         querier: &QuerierWrapper,
@@ -65,4 +117,11 @@ impl KompleMetadataModule {
             querier.query_wasm_smart(self.0.to_string(), &msg)?;
         Ok(res.data)
     }
+
+    pub fn query_reveal(&self, querier: &QuerierWrapper) -> StdResult<Option<Reveal>> {
+        let msg = QueryMsg::Reveal {};
+        let res: ResponseWrapper<Option<Reveal>> =
+            querier.query_wasm_smart(self.0.to_string(), &msg)?;
+        Ok(res.data)
+    }
 }
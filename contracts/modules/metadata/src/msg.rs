@@ -1,4 +1,4 @@
-use crate::state::{Config, MetaInfo, Metadata, Trait};
+use crate::state::{Config, MetaInfo, Metadata, Reveal, RevealProgress, Trait};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use komple_framework_types::modules::metadata::Metadata as MetadataType;
 use komple_framework_types::shared::query::ResponseWrapper;
@@ -7,6 +7,18 @@ use komple_framework_types::shared::query::ResponseWrapper;
 #[cw_serde]
 pub struct InstantiateMsg {
     pub metadata_type: MetadataType,
+    /// Return the unrevealed metadata from the queries until `Reveal` is called.
+    /// Raw metadata is still readable from the contract storage.
+    pub reveal: Option<RevealCommitment>,
+}
+
+/// Commitment for the delayed reveal.
+///
+/// Commitment is the sha256 hex of the metadata root followed by the seed.
+#[cw_serde]
+pub struct RevealCommitment {
+    pub unrevealed_metadata: Metadata,
+    pub commitment: String,
 }
 
 #[cw_serde]
@@ -70,6 +82,12 @@ pub enum ExecuteMsg {
     ///
     /// Update the operators of this contract.
     UpdateOperators { addrs: Vec<String> },
+    /// Admin message.
+    ///
+    /// Reveal the raw metadata of the tokens.
+    /// Metadata root must match the raw metadata and the commitment made on instantiation.
+    /// Raw metadata is hashed in pages, so this is called until the reveal is completed.
+    Reveal { metadata_root: String, seed: String },
 }
 
 #[cw_serde]
@@ -79,12 +97,14 @@ pub enum QueryMsg {
     #[returns(ResponseWrapper<Config>)]
     Config {},
     /// Get a metadata with given raw metadata id.
+    /// Unrevealed metadata is returned until the reveal.
     #[returns(ResponseWrapper<Metadata>)]
     RawMetadata { metadata_id: u32 },
     /// Get a metadata with given token id.
     #[returns(ResponseWrapper<MetadataResponse>)]
     Metadata { token_id: u32 },
     /// List all the raw metadata with pagination.
+    /// Unrevealed metadata is returned until the reveal.
     #[returns(ResponseWrapper<Vec<MetadataResponse>>)]
     RawMetadatas {
         start_after: Option<u32>,
@@ -99,6 +119,16 @@ pub enum QueryMsg {
     /// Get the operators of this contract.
     #[returns(ResponseWrapper<Vec<String>>)]
     Operators {},
+    /// Get the delayed reveal information.
+    #[returns(ResponseWrapper<Option<Reveal>>)]
+    Reveal {},
+    /// Get the raw metadata hashed so far by the reveal.
+    #[returns(ResponseWrapper<Option<RevealProgress>>)]
+    RevealProgress {},
+    /// Check the revealed metadata root against the commitment
+    /// and that the raw metadata has not changed since the reveal.
+    #[returns(ResponseWrapper<bool>)]
+    VerifyReveal {},
 }
 
 #[cw_serde]
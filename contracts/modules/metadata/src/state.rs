@@ -3,7 +3,7 @@ use cosmwasm_std::Addr;
 use cw_storage_plus::{Item, Map};
 use komple_framework_types::modules::metadata::{
     Metadata as MetadataType, DYNAMIC_LINKED_METADATA_NAMESPACE, LINKED_METADATA_NAMESPACE,
-    METADATA_ID_NAMESPACE, METADATA_NAMESPACE,
+    METADATA_ID_NAMESPACE, METADATA_NAMESPACE, REVEAL_NAMESPACE, REVEAL_PROGRESS_NAMESPACE,
 };
 use komple_framework_types::shared::{
     CONFIG_NAMESPACE, OPERATORS_NAMESPACE, PARENT_ADDR_NAMESPACE,
@@ -63,3 +63,32 @@ pub const DYNAMIC_LINKED_METADATA: Map<u32, Metadata> = Map::new(DYNAMIC_LINKED_
 
 /// Operators of this contract.
 pub const OPERATORS: Item<Vec<Addr>> = Item::new(OPERATORS_NAMESPACE);
+
+/// Delayed reveal of the collection metadata.
+///
+/// Until the metadata root is set, every token and raw metadata query
+/// returns the unrevealed metadata.
+/// Raw metadata stays readable from the contract storage, so this is a display
+/// placeholder and not a way to keep the metadata secret before the reveal.
+/// Commitment is the sha256 of the metadata root followed by the seed.
+/// Metadata root is a sha256 hash chain over the raw metadata entries in metadata id order.
+/// Each step hashes the previous root followed by the sha256 of the entry.
+#[cw_serde]
+pub struct Reveal {
+    pub unrevealed_metadata: Metadata,
+    pub commitment: String,
+    pub metadata_root: Option<String>,
+    pub seed: Option<String>,
+    /// Set when the raw metadata changes after the reveal.
+    pub is_modified: bool,
+}
+pub const REVEAL: Item<Reveal> = Item::new(REVEAL_NAMESPACE);
+
+/// Raw metadata hashed so far by a reveal that runs in pages.
+#[cw_serde]
+pub struct RevealProgress {
+    /// Last raw metadata id in the root.
+    pub metadata_id: u32,
+    pub metadata_root: String,
+}
+pub const REVEAL_PROGRESS: Item<RevealProgress> = Item::new(REVEAL_PROGRESS_NAMESPACE);
@@ -44,7 +44,13 @@ fn proper_instantiate(app: &mut App, metadata_type: MetadataType) -> Addr {
 
     let msg = RegisterMsg {
         admin: ADMIN.to_string(),
-        data: Some(to_binary(&InstantiateMsg { metadata_type }).unwrap()),
+        data: Some(
+            to_binary(&InstantiateMsg {
+                metadata_type,
+                reveal: None,
+            })
+            .unwrap(),
+        ),
     };
 
     app.instantiate_contract(
@@ -103,6 +109,7 @@ mod initialization {
             data: Some(
                 to_binary(&InstantiateMsg {
                     metadata_type: MetadataType::Shared,
+                    reveal: None,
                 })
                 .unwrap(),
             ),
@@ -780,6 +787,107 @@ mod actions {
                 ContractError::MissingMetadata {}.to_string()
             );
         }
+
+        #[test]
+        fn test_operator() {
+            let mut app = mock_app();
+            let metadata_module_addr = proper_instantiate(&mut app, MetadataType::Dynamic);
+
+            setup_metadata(&mut app, metadata_module_addr.clone());
+
+            let msg = ExecuteMsg::LinkMetadata {
+                token_id: 1,
+                metadata_id: Some(1),
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap();
+
+            let attribute = Trait {
+                trait_type: "new_trait".to_string(),
+                value: "some_value".to_string(),
+            };
+            let msg = ExecuteMsg::AddAttribute {
+                raw_metadata: false,
+                id: 1,
+                attribute: attribute.clone(),
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+
+            let operators_msg = ExecuteMsg::UpdateOperators {
+                addrs: vec![USER.to_string()],
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &operators_msg,
+                    &[],
+                )
+                .unwrap();
+
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap();
+
+            // Operators cannot write to the raw metadata
+            let msg = ExecuteMsg::AddAttribute {
+                raw_metadata: true,
+                id: 1,
+                attribute,
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+
+            let msg = ExecuteMsg::AddMetadata {
+                meta_info: MetaInfo {
+                    image: None,
+                    external_url: None,
+                    description: None,
+                    animation_url: None,
+                    youtube_url: None,
+                },
+                attributes: vec![],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), metadata_module_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
     }
 
     mod update_attribute {
@@ -1199,8 +1307,391 @@ mod actions {
             );
         }
     }
+
+    mod reveal {
+        use super::*;
+        use crate::msg::RevealCommitment;
+        use crate::state::{Reveal, RevealProgress};
+        use cosmwasm_std::to_vec;
+        use sha2::{Digest, Sha256};
+
+        fn unrevealed_metadata() -> Metadata {
+            Metadata {
+                meta_info: MetaInfo {
+                    image: Some("https://example.com/unrevealed.png".to_string()),
+                    external_url: None,
+                    description: None,
+                    animation_url: None,
+                    youtube_url: None,
+                },
+                attributes: vec![],
+            }
+        }
+
+        fn metadata(index: u32) -> Metadata {
+            Metadata {
+                meta_info: MetaInfo {
+                    image: Some(format!("https://example.com/{}.png", index)),
+                    external_url: None,
+                    description: None,
+                    animation_url: None,
+                    youtube_url: None,
+                },
+                attributes: vec![Trait {
+                    trait_type: "type".to_string(),
+                    value: index.to_string(),
+                }],
+            }
+        }
+
+        // Hash chain over the raw metadata in metadata id order
+        fn metadata_root(count: u32) -> String {
+            let mut metadata_root = vec![];
+            for index in 1..=count {
+                let mut hasher = Sha256::new();
+                hasher.update(metadata_root);
+                hasher.update(Sha256::digest(to_vec(&metadata(index)).unwrap()));
+                metadata_root = hasher.finalize().to_vec();
+            }
+            hex::encode(metadata_root)
+        }
+
+        fn setup_reveal(app: &mut App, count: u32, seed: &str) -> Addr {
+            let commitment =
+                hex::encode(Sha256::digest(format!("{}{}", metadata_root(count), seed)));
+
+            let metadata_code_id = app.store_code(metadata_module());
+            let metadata_module_addr = app
+                .instantiate_contract(
+                    metadata_code_id,
+                    Addr::unchecked(ADMIN),
+                    &instantiate_msg(commitment),
+                    &[],
+                    "test",
+                    None,
+                )
+                .unwrap();
+
+            for index in 1..=count {
+                let msg = ExecuteMsg::AddMetadata {
+                    meta_info: metadata(index).meta_info,
+                    attributes: metadata(index).attributes,
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        metadata_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap();
+            }
+
+            metadata_module_addr
+        }
+
+        fn instantiate_msg(commitment: String) -> RegisterMsg {
+            RegisterMsg {
+                admin: ADMIN.to_string(),
+                data: Some(
+                    to_binary(&InstantiateMsg {
+                        metadata_type: MetadataType::Shared,
+                        reveal: Some(RevealCommitment {
+                            unrevealed_metadata: unrevealed_metadata(),
+                            commitment,
+                        }),
+                    })
+                    .unwrap(),
+                ),
+            }
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+
+            let metadata_root = metadata_root(3);
+            let seed = "secret".to_string();
+            let metadata_module_addr = setup_reveal(&mut app, 3, &seed);
+
+            for token_id in 1..=3 {
+                let msg = ExecuteMsg::LinkMetadata {
+                    token_id,
+                    metadata_id: Some(4 - token_id),
+                };
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        metadata_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap();
+            }
+
+            let msg = QueryMsg::Metadata { token_id: 1 };
+            let res: ResponseWrapper<MetadataResponse> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.metadata, unrevealed_metadata());
+
+            let msg = QueryMsg::Metadatas {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<MetadataResponse>> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.len(), 3);
+            assert!(res
+                .data
+                .iter()
+                .all(|response| response.metadata == unrevealed_metadata()));
+
+            // Raw metadata queries return the unrevealed metadata until the reveal
+            let msg = QueryMsg::RawMetadata { metadata_id: 1 };
+            let res: ResponseWrapper<Metadata> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data, unrevealed_metadata());
+            let msg = QueryMsg::RawMetadatas {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<MetadataResponse>> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert!(res
+                .data
+                .iter()
+                .all(|response| response.metadata == unrevealed_metadata()));
+
+            let msg = QueryMsg::VerifyReveal {};
+            let res: ResponseWrapper<bool> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert!(!res.data);
+
+            let msg = ExecuteMsg::Reveal {
+                metadata_root: metadata_root.clone(),
+                seed: seed.clone(),
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+
+            let msg = ExecuteMsg::Reveal {
+                metadata_root: metadata_root.clone(),
+                seed: "wrong".to_string(),
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidReveal {}.to_string()
+            );
+
+            let msg = ExecuteMsg::Reveal {
+                metadata_root: metadata_root.clone(),
+                seed: seed.clone(),
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap();
+
+            let msg = QueryMsg::Metadata { token_id: 1 };
+            let res: ResponseWrapper<MetadataResponse> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.metadata_id, 3);
+            assert_eq!(res.data.metadata, metadata(3));
+            let msg = QueryMsg::RawMetadata { metadata_id: 1 };
+            let res: ResponseWrapper<Metadata> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data, metadata(1));
+
+            let msg = QueryMsg::Reveal {};
+            let res: ResponseWrapper<Option<Reveal>> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            let reveal = res.data.unwrap();
+            assert_eq!(reveal.metadata_root, Some(metadata_root.clone()));
+            assert_eq!(reveal.seed, Some(seed.clone()));
+
+            let msg = QueryMsg::VerifyReveal {};
+            let res: ResponseWrapper<bool> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert!(res.data);
+
+            let msg = ExecuteMsg::Reveal {
+                metadata_root,
+                seed,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), metadata_module_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::AlreadyRevealed {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_paged_reveal() {
+            let mut app = mock_app();
+            let metadata_root = metadata_root(105);
+            let seed = "secret".to_string();
+            let metadata_module_addr = setup_reveal(&mut app, 105, &seed);
+
+            let reveal_msg = ExecuteMsg::Reveal {
+                metadata_root,
+                seed,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &reveal_msg,
+                    &[],
+                )
+                .unwrap();
+
+            let msg = QueryMsg::RevealProgress {};
+            let res: ResponseWrapper<Option<RevealProgress>> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.unwrap().metadata_id, 100);
+
+            // Changing a hashed raw metadata restarts the reveal
+            let msg = ExecuteMsg::UpdateMetaInfo {
+                raw_metadata: true,
+                id: 50,
+                meta_info: metadata(50).meta_info,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap();
+            let msg = QueryMsg::RevealProgress {};
+            let res: ResponseWrapper<Option<RevealProgress>> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data, None);
+
+            for _ in 0..2 {
+                let _ = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        metadata_module_addr.clone(),
+                        &reveal_msg,
+                        &[],
+                    )
+                    .unwrap();
+            }
+
+            let msg = QueryMsg::VerifyReveal {};
+            let res: ResponseWrapper<bool> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr.clone(), &msg)
+                .unwrap();
+            assert!(res.data);
+
+            // Raw metadata changes after the reveal fail the verification
+            let msg = ExecuteMsg::UpdateMetaInfo {
+                raw_metadata: true,
+                id: 105,
+                meta_info: metadata(1).meta_info,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    metadata_module_addr.clone(),
+                    &msg,
+                    &[],
+                )
+                .unwrap();
+            let msg = QueryMsg::VerifyReveal {};
+            let res: ResponseWrapper<bool> = app
+                .wrap()
+                .query_wasm_smart(metadata_module_addr, &msg)
+                .unwrap();
+            assert!(!res.data);
+        }
+
+        #[test]
+        fn test_invalid_reveal() {
+            let mut app = mock_app();
+
+            let metadata_code_id = app.store_code(metadata_module());
+            let err = app
+                .instantiate_contract(
+                    metadata_code_id,
+                    Addr::unchecked(ADMIN),
+                    &instantiate_msg("invalid".to_string()),
+                    &[],
+                    "test",
+                    None,
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidReveal {}.to_string()
+            );
+
+            let metadata_module_addr = proper_instantiate(&mut app, MetadataType::Shared);
+            let msg = ExecuteMsg::Reveal {
+                metadata_root: "root".to_string(),
+                seed: "seed".to_string(),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), metadata_module_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidReveal {}.to_string()
+            );
+        }
+    }
 }
 mod queries {
+
     use super::*;
 
     mod raw_metadatas {
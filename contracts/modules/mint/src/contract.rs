@@ -1,26 +1,29 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    coins, from_binary, to_binary, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps,
-    DepsMut, Env, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg,
-    Timestamp, Uint128, WasmMsg,
+    coins, from_binary, to_binary, to_vec, Addr, Attribute, BankMsg, Binary, Coin, CosmosMsg,
+    Decimal, Deps, DepsMut, Env, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult,
+    Storage, SubMsg, Timestamp, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use cw721::{Cw721QueryMsg, TokensResponse};
 use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
 
+use komple_framework_fee_module::helper::KompleFeeModule;
 use komple_framework_permission_module::msg::ExecuteMsg as PermissionExecuteMsg;
 use komple_framework_token_module::{
-    helper::KompleTokenModule,
-    msg::{InstantiateMsg as TokenInstantiateMsg, MetadataInfo, TokenInfo},
-    state::CollectionConfig,
+    helper::KompleTokenModule, msg::InstantiateMsg as TokenInstantiateMsg,
+    state::Config as TokenConfig,
 };
-use komple_framework_types::modules::fee::{FundInfo, MintFees};
+use komple_framework_types::modules::fee::{FundInfo, MintFees, RevenueShare};
+use komple_framework_types::modules::hub::MARBU_FEE_MODULE_NAMESPACE;
+use komple_framework_types::modules::mint::Collections;
 use komple_framework_types::modules::whitelist::WHITELIST_NAMESPACE;
 use komple_framework_types::modules::Modules;
 use komple_framework_types::shared::query::ResponseWrapper;
-use komple_framework_types::shared::RegisterMsg;
+use komple_framework_types::shared::{RegisterMsg, CONFIG_NAMESPACE};
 use komple_framework_utils::{
     check_admin_privileges,
     funds::{check_cw20_fund_info, FundsError},
@@ -31,6 +34,9 @@ use komple_framework_utils::{
 use komple_framework_utils::{funds::check_single_coin, response::EventHelper};
 use komple_framework_whitelist_module::helper::KompleWhitelistHelper;
 use semver::Version;
+use sha2::{Digest, Sha256};
+use std::convert::TryInto;
+use std::ops::Mul;
 
 use crate::{error::ContractError, msg::ReceiveMsg, state::EXECUTE_LOCK};
 use crate::{
@@ -42,8 +48,20 @@ use crate::{
     },
 };
 use crate::{
-    msg::{CollectionsResponse, ExecuteMsg, MigrateMsg, MintMsg, QueryMsg},
-    state::CREATORS,
+    msg::{
+        CollectionInfoMsg, CollectionsResponse, CreateCollectionMsg, ExecuteMsg, FeeQuoteResponse,
+        MetadataShuffleInfo, MigrateMsg, MintMsg, MintPhaseMsg, MintVoucher, ProvenanceResponse,
+        QueryMsg,
+    },
+    state::{
+        BondingCurve, Curve, DutchAuction, DutchAuctionMint, Eligibility, MetadataShuffle,
+        MintBatch, MintPhase, ShuffledMint, VoucherKey, ARCHIVED_COLLECTIONS, BONDING_CURVES,
+        BONDING_CURVE_RESERVES, BONDING_CURVE_SUPPLIES, CREATION_FEE_FUND_INFO, CREATORS,
+        CREATOR_COLLECTIONS, CREATOR_COLLECTION_LIMIT, DUTCH_AUCTIONS,
+        DUTCH_AUCTION_CLEARING_PRICES, DUTCH_AUCTION_MINTS, DUTCH_AUCTION_UNSETTLED,
+        METADATA_SHUFFLES, METADATA_SHUFFLE_POOL, MINT_BATCHES, MINT_PHASES, PENDING_COLLECTIONS,
+        PHASE_ADDR_MINTS, PHASE_MINTS, SHUFFLED_MINTS, VOUCHER_KEYS, VOUCHER_NONCES,
+    },
 };
 
 // version info for migration info
@@ -52,6 +70,14 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const TOKEN_INSTANTIATE_REPLY_ID: u64 = 1;
 
+// Maximum number of recipients minted in a single admin mint batch transaction
+// This is not a gas estimate, resumes can use a lower limit
+const MINT_BATCH_LIMIT: u32 = 50;
+// Maximum number of shuffled mints drawn in a single seed reveal
+const SHUFFLE_REVEAL_LIMIT: u32 = 50;
+// Number of raw metadata hashed in a single provenance query
+const PROVENANCE_PAGE_LIMIT: u32 = 100;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -109,34 +135,102 @@ pub fn execute(
             token_info,
             fund_info,
             linked_collections,
-        } => execute_create_collection(
-            deps,
-            env,
-            info,
-            code_id,
-            collection_config,
-            collection_info,
-            metadata_info,
-            token_info,
-            fund_info,
-            linked_collections,
-        ),
+            metadata_shuffle,
+        } => {
+            let create_collection_msg = CreateCollectionMsg {
+                code_id,
+                collection_config,
+                collection_info,
+                metadata_info,
+                token_info,
+                fund_info,
+                linked_collections,
+                metadata_shuffle,
+            };
+            let creator = info.sender.clone();
+            execute_create_collection(deps, env, info, creator, create_collection_msg, None)
+        }
         ExecuteMsg::UpdatePublicCollectionCreation {
             public_collection_creation,
         } => execute_update_public_collection_creation(deps, env, info, public_collection_creation),
+        ExecuteMsg::UpdateCollectionInfo {
+            collection_id,
+            collection_info,
+        } => execute_update_collection_info(deps, env, info, collection_id, collection_info),
+        ExecuteMsg::ArchiveCollection { collection_id } => {
+            execute_archive_collection(deps, env, info, collection_id)
+        }
+        ExecuteMsg::UpdateRevenueSplit {
+            collection_id,
+            revenue_split,
+        } => execute_update_revenue_split(deps, env, info, collection_id, revenue_split),
         ExecuteMsg::UpdateCollectionMintLock {
             collection_id,
             lock,
         } => execute_update_collection_mint_lock(deps, env, info, collection_id, lock),
         ExecuteMsg::Mint {
             collection_id,
-            metadata_id,
-        } => execute_mint(deps, env, info, collection_id, metadata_id),
+            quantity,
+            metadata_ids,
+        } => {
+            let mint_request = MintRequest {
+                collection_id,
+                recipient: info.sender.to_string(),
+                quantity,
+                metadata_ids,
+                proof: None,
+                voucher: None,
+            };
+            execute_mint(deps, env, info, mint_request, None)
+        }
+        ExecuteMsg::MintWithProof {
+            collection_id,
+            quantity,
+            metadata_ids,
+            proof,
+        } => {
+            let mint_request = MintRequest {
+                collection_id,
+                recipient: info.sender.to_string(),
+                quantity,
+                metadata_ids,
+                proof: Some(proof),
+                voucher: None,
+            };
+            execute_mint(deps, env, info, mint_request, None)
+        }
+        ExecuteMsg::MintWithVoucher {
+            quantity,
+            metadata_ids,
+            voucher,
+            signature,
+        } => {
+            let mint_request = MintRequest {
+                collection_id: voucher.collection_id,
+                recipient: info.sender.to_string(),
+                quantity,
+                metadata_ids,
+                proof: None,
+                voucher: Some((voucher, signature)),
+            };
+            execute_mint(deps, env, info, mint_request, None)
+        }
         ExecuteMsg::AdminMint {
             collection_id,
             recipient,
             metadata_id,
         } => execute_admin_mint(deps, env, info, collection_id, recipient, metadata_id),
+        ExecuteMsg::AdminMintBatch {
+            collection_id,
+            recipients,
+        } => execute_admin_mint_batch(deps, env, info, collection_id, recipients),
+        ExecuteMsg::ResumeAdminMintBatch {
+            collection_id,
+            limit,
+        } => execute_resume_admin_mint_batch(deps, env, info, collection_id, limit),
+        ExecuteMsg::CancelAdminMintBatch { collection_id } => {
+            execute_cancel_admin_mint_batch(deps, env, info, collection_id)
+        }
         ExecuteMsg::PermissionMint {
             permission_msg,
             mint_msg,
@@ -146,6 +240,12 @@ pub fn execute(
             linked_collections,
         } => execute_update_linked_collections(deps, env, info, collection_id, linked_collections),
         ExecuteMsg::UpdateCreators { addrs } => execute_update_creators(deps, env, info, addrs),
+        ExecuteMsg::UpdateCreatorCollectionLimit { limit } => {
+            execute_update_creator_collection_limit(deps, env, info, limit)
+        }
+        ExecuteMsg::UpdateCreationFeeFundInfo { fund_info } => {
+            execute_update_creation_fee_fund_info(deps, env, info, fund_info)
+        }
         ExecuteMsg::UpdateCollectionStatus {
             collection_id,
             is_blacklist,
@@ -173,60 +273,114 @@ pub fn execute(
                 Err(err) => Err(err.into()),
             }
         }
+        ExecuteMsg::UpdateMintPhases {
+            collection_id,
+            phases,
+        } => execute_update_mint_phases(deps, env, info, collection_id, phases),
+        ExecuteMsg::UpdateDutchAuction {
+            collection_id,
+            dutch_auction,
+        } => execute_update_dutch_auction(deps, env, info, collection_id, dutch_auction),
+        ExecuteMsg::ClaimDutchAuctionRebate {
+            collection_id,
+            address,
+        } => execute_claim_dutch_auction_rebate(deps, env, info, collection_id, address),
+        ExecuteMsg::SettleDutchAuction { collection_id } => {
+            execute_settle_dutch_auction(deps, env, info, collection_id)
+        }
+        ExecuteMsg::UpdateBondingCurve {
+            collection_id,
+            bonding_curve,
+        } => execute_update_bonding_curve(deps, env, info, collection_id, bonding_curve),
+        ExecuteMsg::SellBack {
+            collection_id,
+            token_id,
+        } => execute_sell_back(deps, env, info, collection_id, token_id),
+        ExecuteMsg::RevealShuffleSeed {
+            collection_id,
+            seed,
+            limit,
+        } => execute_reveal_shuffle_seed(deps, env, info, collection_id, seed, limit),
+        ExecuteMsg::UpdateVoucherKey {
+            collection_id,
+            voucher_key,
+        } => execute_update_voucher_key(deps, env, info, collection_id, voucher_key),
         ExecuteMsg::Receive(msg) => execute_receive(deps, env, info, msg),
     }
 }
 
-pub fn execute_create_collection(
+fn execute_create_collection(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    code_id: u64,
-    collection_config: CollectionConfig,
-    collection_info: CollectionInfo,
-    metadata_info: MetadataInfo,
-    mut token_info: TokenInfo,
-    fund_info: CollectionFundInfo,
-    linked_collections: Option<Vec<u32>>,
+    creator: Addr,
+    create_collection_msg: CreateCollectionMsg,
+    cw20_token_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
+    let CreateCollectionMsg {
+        code_id,
+        collection_config,
+        collection_info,
+        metadata_info,
+        mut token_info,
+        fund_info,
+        linked_collections,
+        metadata_shuffle,
+    } = create_collection_msg;
+
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
 
-    // If public collection creation is enabled skip privilege checks
-    if !config.public_collection_creation {
-        let hub_addr = HUB_ADDR.may_load(deps.storage)?;
-        let operators = OPERATORS.may_load(deps.storage)?;
-
-        // Check for creators privilege
-        let creators = CREATORS.may_load(deps.storage)?;
-        match creators {
-            Some(creators) => {
-                // If the sender is not a creator
-                if !creators.contains(&info.sender) {
-                    check_admin_privileges(
-                        &info.sender,
-                        &env.contract.address,
-                        &config.admin,
-                        hub_addr,
-                        operators,
-                    )?;
-                }
-            }
-            // If there are no creators
-            None => {
-                check_admin_privileges(
-                    &info.sender,
-                    &env.contract.address,
-                    &config.admin,
-                    hub_addr,
-                    operators,
-                )?;
+    // Creators and admins are not public creators
+    let creators = CREATORS.may_load(deps.storage)?.unwrap_or_default();
+    let privilege_check = match creators.contains(&creator) {
+        true => Ok(()),
+        false => check_admin_privileges(
+            &creator,
+            &env.contract.address,
+            &config.admin,
+            hub_addr.clone(),
+            operators,
+        ),
+    };
+    let is_public = privilege_check.is_err();
+    if is_public && !config.public_collection_creation {
+        privilege_check?;
+    };
+
+    let collection_id = (COLLECTION_ID.load(deps.storage)?) + 1;
+
+    let mut creation_fee_msg: Option<CosmosMsg> = None;
+    if is_public {
+        let creator_collections = CREATOR_COLLECTIONS
+            .may_load(deps.storage, &creator)?
+            .unwrap_or(0);
+        if let Some(limit) = CREATOR_COLLECTION_LIMIT.may_load(deps.storage)? {
+            if creator_collections >= limit {
+                return Err(ContractError::CreatorCollectionLimitReached {});
             }
         }
+        CREATOR_COLLECTIONS.save(deps.storage, &creator, &(creator_collections + 1))?;
+
+        creation_fee_msg = process_creation_fee(&deps, hub_addr, &info, cw20_token_amount)?;
+
+        // Public collections wait for the admin approval
+        PENDING_COLLECTIONS.save(deps.storage, collection_id, &creator)?;
+    };
+    // Cw20 tokens are only accepted for the creation fee
+    if cw20_token_amount.is_some() && creation_fee_msg.is_none() {
+        return Err(FundsError::InvalidCw20Token {}.into());
+    };
+    // Creation fee funds are not forwarded to the token module
+    let token_funds = match creation_fee_msg {
+        Some(_) => vec![],
+        None => info.funds,
     };
 
     token_info.minter = env.contract.address.to_string();
     let token_instantiate_msg = TokenInstantiateMsg {
-        creator: info.sender.to_string(),
+        creator: creator.to_string(),
         collection_config: collection_config.clone(),
         collection_type: collection_info.clone().collection_type,
         collection_name: collection_info.clone().name,
@@ -245,7 +399,7 @@ pub fn execute_create_collection(
         msg: WasmMsg::Instantiate {
             code_id,
             msg: to_binary(&register_msg)?,
-            funds: info.funds,
+            funds: token_funds,
             admin: contract_info.admin,
             label: String::from("Komple Framework Token Module"),
         }
@@ -255,8 +409,6 @@ pub fn execute_create_collection(
         reply_on: ReplyOn::Success,
     };
 
-    let collection_id = (COLLECTION_ID.load(deps.storage)?) + 1;
-
     if linked_collections.is_some() {
         check_collection_ids_exists(&deps, &linked_collections.clone().unwrap())?;
         LINKED_COLLECTIONS.save(deps.storage, collection_id, &linked_collections.unwrap())?;
@@ -266,8 +418,20 @@ pub fn execute_create_collection(
 
     COLLECTION_INFO.save(deps.storage, collection_id, &collection_info)?;
 
+    if let Some(metadata_shuffle) = &metadata_shuffle {
+        save_metadata_shuffle(
+            deps.storage,
+            collection_id,
+            &collection_info,
+            metadata_shuffle,
+        )?;
+    }
+
     MINT_LOCKS.save(deps.storage, collection_id, &false)?;
 
+    let provenance_hash =
+        metadata_shuffle.map(|metadata_shuffle| metadata_shuffle.provenance_hash.to_lowercase());
+
     let cw20_address = match fund_info.cw20_address {
         Some(addr) => Some(deps.api.addr_validate(&addr)?),
         None => None,
@@ -285,9 +449,12 @@ pub fn execute_create_collection(
 
     Ok(ResponseHelper::new_module("mint", "create_collection")
         .add_submessage(sub_msg)
+        .add_messages(creation_fee_msg)
         .add_event(
             EventHelper::new("mint_create_collection")
+                .add_attribute("collection_id", collection_id.to_string())
                 .add_attribute("creator", token_instantiate_msg.creator)
+                .add_attribute("is_pending", is_public.to_string())
                 .add_attribute("minter", token_instantiate_msg.token_info.minter)
                 .add_attribute("symbol", token_instantiate_msg.token_info.symbol)
                 .add_attribute(
@@ -323,6 +490,14 @@ pub fn execute_create_collection(
                         .unwrap_or(Timestamp::from_nanos(0))
                         .to_string(),
                 )
+                .check_add_attribute(
+                    &collection_config.end_time,
+                    "end_time",
+                    collection_config
+                        .end_time
+                        .unwrap_or(Timestamp::from_nanos(0))
+                        .to_string(),
+                )
                 .check_add_attribute(
                     &collection_config.max_token_limit,
                     "max_token_limit",
@@ -341,6 +516,11 @@ pub fn execute_create_collection(
                         .as_ref()
                         .unwrap_or(&String::from("")),
                 )
+                .check_add_attribute(
+                    &provenance_hash,
+                    "provenance_hash",
+                    provenance_hash.clone().unwrap_or_default(),
+                )
                 .get(),
         ))
 }
@@ -378,6 +558,100 @@ pub fn execute_update_public_collection_creation(
     )
 }
 
+fn execute_update_collection_info(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    collection_info: CollectionInfoMsg,
+) -> Result<Response, ContractError> {
+    check_collection_privileges(&deps, &env, &info, collection_id)?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    COLLECTION_INFO.update(deps.storage, collection_id, |current| -> StdResult<_> {
+        let mut current = current.ok_or(StdError::NotFound {
+            kind: "Collection info".to_string(),
+        })?;
+        current.name = collection_info.name.clone();
+        current.description = collection_info.description.clone();
+        current.image = collection_info.image.clone();
+        current.external_link = collection_info.external_link.clone();
+        Ok(current)
+    })?;
+
+    Ok(
+        ResponseHelper::new_module("mint", "update_collection_info").add_event(
+            EventHelper::new("mint_update_collection_info")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("collection_name", collection_info.name)
+                .add_attribute("description", collection_info.description)
+                .add_attribute("image", collection_info.image)
+                .check_add_attribute(
+                    &collection_info.external_link,
+                    "external_link",
+                    collection_info
+                        .external_link
+                        .as_ref()
+                        .unwrap_or(&String::from("")),
+                )
+                .get(),
+        ),
+    )
+}
+
+fn execute_archive_collection(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+) -> Result<Response, ContractError> {
+    check_collection_privileges(&deps, &env, &info, collection_id)?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    ARCHIVED_COLLECTIONS.save(deps.storage, collection_id, &true)?;
+    MINT_LOCKS.save(deps.storage, collection_id, &true)?;
+
+    // Archived collections are no longer waiting for approval or minting
+    PENDING_COLLECTIONS.remove(deps.storage, collection_id);
+    MINT_BATCHES.remove(deps.storage, collection_id);
+
+    Ok(
+        ResponseHelper::new_module("mint", "archive_collection").add_event(
+            EventHelper::new("mint_archive_collection")
+                .add_attribute("collection_id", collection_id.to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_update_revenue_split(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    revenue_split: Vec<RevenueShare>,
+) -> Result<Response, ContractError> {
+    check_collection_privileges(&deps, &env, &info, collection_id)?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    // Split is saved on the fee module and validated there
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    let fee_module_addr =
+        StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string())?;
+    let fee_module = KompleFeeModule(fee_module_addr);
+    let revenue_shares = revenue_split.len();
+    let msg = fee_module.set_revenue_split_msg(collection_id, revenue_split)?;
+
+    Ok(ResponseHelper::new_module("mint", "update_revenue_split")
+        .add_message(msg)
+        .add_event(
+            EventHelper::new("mint_update_revenue_split")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("revenue_shares", revenue_shares.to_string())
+                .get(),
+        ))
+}
+
 pub fn execute_update_collection_mint_lock(
     deps: DepsMut,
     env: Env,
@@ -396,6 +670,7 @@ pub fn execute_update_collection_mint_lock(
         hub_addr,
         operators,
     )?;
+    check_collection_archived(deps.storage, collection_id)?;
 
     MINT_LOCKS.save(deps.storage, collection_id, &lock)?;
 
@@ -409,27 +684,118 @@ pub fn execute_update_collection_mint_lock(
     )
 }
 
+/// Public mint shared by the native and cw20 mint messages.
+struct MintRequest {
+    collection_id: u32,
+    recipient: String,
+    quantity: u32,
+    metadata_ids: Option<Vec<u32>>,
+    proof: Option<Vec<String>>,
+    voucher: Option<(MintVoucher, Binary)>,
+}
+
 fn execute_mint(
-    deps: DepsMut,
-    _env: Env,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    collection_id: u32,
-    metadata_id: Option<u32>,
+    mint_request: MintRequest,
+    cw20_token_amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
-    let mint_lock = MINT_LOCKS.load(deps.storage, collection_id)?;
+    let mint_lock = MINT_LOCKS.load(deps.storage, mint_request.collection_id)?;
     if mint_lock {
         return Err(ContractError::LockedMint {});
     }
 
-    let mint_msg = MintMsg {
-        collection_id,
-        recipient: info.sender.to_string(),
-        metadata_id,
-    };
-    let msgs: Vec<CosmosMsg> =
-        process_minting_prices(&deps, &info, &info.sender.to_string(), collection_id, None)?;
+    if mint_request.quantity == 0
+        || matches!(&mint_request.metadata_ids, Some(ids) if ids.len() != mint_request.quantity as usize)
+    {
+        return Err(ContractError::InvalidQuantity {});
+    }
+
+    let mut msgs: Vec<CosmosMsg> =
+        process_mint_payment(&mut deps, &env, &info, &mint_request, cw20_token_amount)?;
+
+    // Shuffled tokens are reserved on the token module and drawn on the next seed reveal
+    if let Some(mut metadata_shuffle) =
+        METADATA_SHUFFLES.may_load(deps.storage, mint_request.collection_id)?
+    {
+        if mint_request.metadata_ids.is_some() {
+            return Err(ContractError::MetadataIdNotAllowed {});
+        }
+        if metadata_shuffle.remaining - metadata_shuffle.pending < mint_request.quantity {
+            return Err(ContractError::MetadataPoolEmpty {});
+        }
+        metadata_shuffle.pending += mint_request.quantity;
+        METADATA_SHUFFLES.save(deps.storage, mint_request.collection_id, &metadata_shuffle)?;
+
+        let recipient = deps.api.addr_validate(&mint_request.recipient)?;
+        SHUFFLED_MINTS.update(
+            deps.storage,
+            (mint_request.collection_id, &recipient),
+            |shuffled_mint| -> StdResult<_> {
+                Ok(ShuffledMint {
+                    quantity: shuffled_mint.map_or(0, |shuffled_mint| shuffled_mint.quantity)
+                        + mint_request.quantity,
+                    height: env.block.height,
+                })
+            },
+        )?;
+
+        let collection_addr = COLLECTION_ADDRS.load(deps.storage, mint_request.collection_id)?;
+        let msg = KompleTokenModule(collection_addr)
+            .reserve_mint_msg(recipient.to_string(), mint_request.quantity)?;
+        msgs.push(msg.into());
+
+        return Ok(ResponseHelper::new_module("mint", "mint")
+            .add_messages(msgs)
+            .add_event(
+                EventHelper::new("mint_mint")
+                    .add_attribute("recipient", mint_request.recipient)
+                    .add_attribute("collection_id", mint_request.collection_id.to_string())
+                    .add_attribute("quantity", mint_request.quantity.to_string())
+                    .add_attribute("is_pending", "true")
+                    .get(),
+            ));
+    }
+
+    let metadata_ids = shuffled_metadata_ids(
+        deps.storage,
+        &block_entropy(&env),
+        mint_request.collection_id,
+        &mint_request.recipient,
+        mint_request.quantity,
+        mint_request.metadata_ids.clone(),
+    )?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, mint_request.collection_id)?;
+    let msg = KompleTokenModule(collection_addr).mint_batch_msg(
+        mint_request.recipient.clone(),
+        mint_request.quantity,
+        metadata_ids.clone(),
+    )?;
+    msgs.push(msg.into());
+
+    let metadata_ids = metadata_ids.map(|ids| {
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    });
 
-    _execute_mint(deps, "mint", msgs, mint_msg)
+    Ok(ResponseHelper::new_module("mint", "mint")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("mint_mint")
+                .add_attribute("recipient", mint_request.recipient)
+                .add_attribute("collection_id", mint_request.collection_id.to_string())
+                .add_attribute("quantity", mint_request.quantity.to_string())
+                .check_add_attribute(
+                    &metadata_ids,
+                    "metadata_ids",
+                    metadata_ids.clone().unwrap_or_default(),
+                )
+                .get(),
+        ))
 }
 
 fn execute_admin_mint(
@@ -454,6 +820,16 @@ fn execute_admin_mint(
 
     let recipient = deps.api.addr_validate(&recipient)?;
 
+    let metadata_id = shuffled_metadata_ids(
+        deps.storage,
+        &block_entropy(&env),
+        collection_id,
+        recipient.as_str(),
+        1,
+        metadata_id.map(|id| vec![id]),
+    )?
+    .map(|ids| ids[0]);
+
     let msgs: Vec<CosmosMsg> = vec![];
     let mint_msg = MintMsg {
         collection_id,
@@ -464,100 +840,56 @@ fn execute_admin_mint(
     _execute_mint(deps, "admin_mint", msgs, mint_msg)
 }
 
-fn execute_permission_mint(
+fn execute_admin_mint_batch(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    permission_msg: Binary,
-    mint_msg: MintMsg,
+    collection_id: u32,
+    recipients: Vec<(String, Option<u32>)>,
 ) -> Result<Response, ContractError> {
     let hub_addr = HUB_ADDR.may_load(deps.storage)?;
     let operators = OPERATORS.may_load(deps.storage)?;
     let config = CONFIG.load(deps.storage)?;
+
     check_admin_privileges(
         &info.sender,
         &env.contract.address,
         &config.admin,
-        hub_addr.clone(),
+        hub_addr,
         operators,
     )?;
 
-    let permission_module_addr = StorageHelper::query_module_address(
-        &deps.querier,
-        &hub_addr.unwrap(),
-        Modules::Permission.to_string(),
-    )?;
-
-    let mut msgs: Vec<WasmMsg> = vec![];
-
-    let permission_msg = PermissionExecuteMsg::Check {
-        module: Modules::Mint.to_string(),
-        msg: permission_msg,
-    };
-    msgs.push(WasmMsg::Execute {
-        contract_addr: permission_module_addr.to_string(),
-        msg: to_binary(&permission_msg)?,
-        funds: info.funds.clone(),
-    });
-
-    msgs.push(WasmMsg::Execute {
-        contract_addr: env.contract.address.to_string(),
-        msg: to_binary(&ExecuteMsg::AdminMint {
-            collection_id: mint_msg.collection_id,
-            recipient: mint_msg.recipient.clone(),
-            metadata_id: mint_msg.metadata_id,
-        })?,
-        funds: info.funds,
-    });
-
-    Ok(ResponseHelper::new_module("mint", "permission_mint")
-        .add_messages(msgs)
-        .add_event(
-            EventHelper::new("mint_permission_mint")
-                .add_attribute("collection_id", mint_msg.collection_id.to_string())
-                .add_attribute("recipient", mint_msg.recipient)
-                .check_add_attribute(
-                    &mint_msg.metadata_id,
-                    "metadata_id",
-                    mint_msg.metadata_id.as_ref().unwrap_or(&0).to_string(),
-                )
-                .get(),
-        ))
-}
-
-fn _execute_mint(
-    deps: DepsMut,
-    action: &str,
-    mut msgs: Vec<CosmosMsg>,
-    mint_msg: MintMsg,
-) -> Result<Response, ContractError> {
-    let collection_addr = COLLECTION_ADDRS.load(deps.storage, mint_msg.collection_id)?;
+    if recipients.is_empty() {
+        return Err(ContractError::InvalidQuantity {});
+    }
+    if MINT_BATCHES.has(deps.storage, collection_id) {
+        return Err(ContractError::PendingMintBatch {});
+    }
 
-    let msg = KompleTokenModule(collection_addr)
-        .mint_msg(mint_msg.recipient.clone(), mint_msg.metadata_id)?;
-    msgs.push(msg.into());
+    let recipients = recipients
+        .iter()
+        .map(|(recipient, metadata_id)| Ok((deps.api.addr_validate(recipient)?, *metadata_id)))
+        .collect::<StdResult<Vec<(Addr, Option<u32>)>>>()?;
 
-    Ok(ResponseHelper::new_module("mint", action)
-        .add_messages(msgs)
-        .add_event(
-            EventHelper::new(format!("mint_{}", action))
-                .add_attribute("recipient", mint_msg.recipient)
-                .add_attribute("collection_id", mint_msg.collection_id.to_string())
-                .check_add_attribute(
-                    &mint_msg.metadata_id,
-                    "metadata_id",
-                    mint_msg.metadata_id.as_ref().unwrap_or(&0).to_string(),
-                )
-                .get(),
-        ))
+    _execute_mint_batch(
+        deps,
+        &env,
+        "admin_mint_batch",
+        collection_id,
+        MintBatch {
+            recipients,
+            cursor: 0,
+        },
+        MINT_BATCH_LIMIT,
+    )
 }
 
-fn execute_update_linked_collections(
+fn execute_resume_admin_mint_batch(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     collection_id: u32,
-    linked_collections: Vec<u32>,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
     let hub_addr = HUB_ADDR.may_load(deps.storage)?;
     let operators = OPERATORS.may_load(deps.storage)?;
@@ -571,11 +903,232 @@ fn execute_update_linked_collections(
         operators,
     )?;
 
-    if linked_collections.contains(&collection_id) {
-        return Err(ContractError::SelfLinkedCollection {});
-    };
+    let limit = limit.unwrap_or(MINT_BATCH_LIMIT).min(MINT_BATCH_LIMIT);
+    if limit == 0 {
+        return Err(ContractError::InvalidQuantity {});
+    }
 
-    let mut ids_to_check = vec![collection_id];
+    let mint_batch = MINT_BATCHES
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::NoPendingMintBatch {})?;
+
+    _execute_mint_batch(
+        deps,
+        &env,
+        "resume_admin_mint_batch",
+        collection_id,
+        mint_batch,
+        limit,
+    )
+}
+
+fn execute_cancel_admin_mint_batch(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    let mint_batch = MINT_BATCHES
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::NoPendingMintBatch {})?;
+    MINT_BATCHES.remove(deps.storage, collection_id);
+
+    let dropped = mint_batch.recipients.len() as u32 - mint_batch.cursor;
+
+    Ok(
+        ResponseHelper::new_module("mint", "cancel_admin_mint_batch").add_event(
+            EventHelper::new("mint_cancel_admin_mint_batch")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("cursor", mint_batch.cursor.to_string())
+                .add_attribute("dropped", dropped.to_string())
+                .get(),
+        ),
+    )
+}
+
+// Mints the next recipients of the batch starting from the cursor
+// Saves the batch if there are recipients left, removes it otherwise
+fn _execute_mint_batch(
+    deps: DepsMut,
+    env: &Env,
+    action: &str,
+    collection_id: u32,
+    mut mint_batch: MintBatch,
+    limit: u32,
+) -> Result<Response, ContractError> {
+    check_collection_archived(deps.storage, collection_id)?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, collection_id)?;
+    let token_module = KompleTokenModule(collection_addr);
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for (recipient, metadata_id) in mint_batch
+        .recipients
+        .iter()
+        .skip(mint_batch.cursor as usize)
+        .take(limit as usize)
+    {
+        let metadata_id = shuffled_metadata_ids(
+            deps.storage,
+            &block_entropy(env),
+            collection_id,
+            recipient.as_str(),
+            1,
+            metadata_id.map(|id| vec![id]),
+        )?
+        .map(|ids| ids[0]);
+        msgs.push(
+            token_module
+                .mint_msg(recipient.to_string(), metadata_id)?
+                .into(),
+        );
+    }
+
+    mint_batch.cursor += msgs.len() as u32;
+    let remaining = mint_batch.recipients.len() as u32 - mint_batch.cursor;
+    match remaining {
+        0 => MINT_BATCHES.remove(deps.storage, collection_id),
+        _ => MINT_BATCHES.save(deps.storage, collection_id, &mint_batch)?,
+    };
+
+    Ok(ResponseHelper::new_module("mint", action)
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new(format!("mint_{}", action))
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("cursor", mint_batch.cursor.to_string())
+                .add_attribute("remaining", remaining.to_string())
+                .get(),
+        ))
+}
+
+fn execute_permission_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    permission_msg: Binary,
+    mint_msg: MintMsg,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr.clone(),
+        operators,
+    )?;
+
+    let permission_module_addr = StorageHelper::query_module_address(
+        &deps.querier,
+        &hub_addr.unwrap(),
+        Modules::Permission.to_string(),
+    )?;
+
+    let mut msgs: Vec<WasmMsg> = vec![];
+
+    let permission_msg = PermissionExecuteMsg::Check {
+        module: Modules::Mint.to_string(),
+        msg: permission_msg,
+    };
+    msgs.push(WasmMsg::Execute {
+        contract_addr: permission_module_addr.to_string(),
+        msg: to_binary(&permission_msg)?,
+        funds: info.funds.clone(),
+    });
+
+    msgs.push(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::AdminMint {
+            collection_id: mint_msg.collection_id,
+            recipient: mint_msg.recipient.clone(),
+            metadata_id: mint_msg.metadata_id,
+        })?,
+        funds: info.funds,
+    });
+
+    Ok(ResponseHelper::new_module("mint", "permission_mint")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("mint_permission_mint")
+                .add_attribute("collection_id", mint_msg.collection_id.to_string())
+                .add_attribute("recipient", mint_msg.recipient)
+                .check_add_attribute(
+                    &mint_msg.metadata_id,
+                    "metadata_id",
+                    mint_msg.metadata_id.as_ref().unwrap_or(&0).to_string(),
+                )
+                .get(),
+        ))
+}
+
+fn _execute_mint(
+    deps: DepsMut,
+    action: &str,
+    mut msgs: Vec<CosmosMsg>,
+    mint_msg: MintMsg,
+) -> Result<Response, ContractError> {
+    check_collection_archived(deps.storage, mint_msg.collection_id)?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, mint_msg.collection_id)?;
+
+    let msg = KompleTokenModule(collection_addr)
+        .mint_msg(mint_msg.recipient.clone(), mint_msg.metadata_id)?;
+    msgs.push(msg.into());
+
+    Ok(ResponseHelper::new_module("mint", action)
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new(format!("mint_{}", action))
+                .add_attribute("recipient", mint_msg.recipient)
+                .add_attribute("collection_id", mint_msg.collection_id.to_string())
+                .check_add_attribute(
+                    &mint_msg.metadata_id,
+                    "metadata_id",
+                    mint_msg.metadata_id.as_ref().unwrap_or(&0).to_string(),
+                )
+                .get(),
+        ))
+}
+
+fn execute_update_linked_collections(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    linked_collections: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    if linked_collections.contains(&collection_id) {
+        return Err(ContractError::SelfLinkedCollection {});
+    };
+
+    let mut ids_to_check = vec![collection_id];
     ids_to_check.extend(&linked_collections);
     check_collection_ids_exists(&deps, &ids_to_check)?;
 
@@ -606,6 +1159,7 @@ fn execute_update_collection_status(
         hub_addr,
         operators,
     )?;
+    check_collection_archived(deps.storage, collection_id)?;
 
     match is_blacklist {
         // Blacklist case
@@ -640,6 +1194,7 @@ fn execute_update_collection_status(
 
             BLACKLIST_COLLECTION_ADDRS.remove(deps.storage, collection_id);
             COLLECTION_ADDRS.save(deps.storage, collection_id, &collection_addr.unwrap())?;
+            PENDING_COLLECTIONS.remove(deps.storage, collection_id);
         }
     }
 
@@ -701,47 +1256,1125 @@ fn execute_update_creators(
     )
 }
 
-fn execute_receive(
+fn execute_update_creator_collection_limit(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
-    cw20_receive_msg: Cw20ReceiveMsg,
+    limit: Option<u32>,
 ) -> Result<Response, ContractError> {
-    let msg: ReceiveMsg = from_binary(&cw20_receive_msg.msg)?;
-    let sender = cw20_receive_msg.sender;
-    let amount = cw20_receive_msg.amount;
-    match msg {
-        ReceiveMsg::Mint {
-            collection_id,
-            metadata_id,
-        } => {
-            let mint_lock = MINT_LOCKS.load(deps.storage, collection_id)?;
-            if mint_lock {
-                return Err(ContractError::LockedMint {});
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    match limit {
+        Some(limit) => CREATOR_COLLECTION_LIMIT.save(deps.storage, &limit)?,
+        None => CREATOR_COLLECTION_LIMIT.remove(deps.storage),
+    };
+
+    Ok(
+        ResponseHelper::new_module("mint", "update_creator_collection_limit").add_event(
+            EventHelper::new("mint_update_creator_collection_limit")
+                .check_add_attribute(&limit, "limit", limit.unwrap_or(0).to_string())
+                .get(),
+        ),
+    )
+}
+
+fn execute_update_creation_fee_fund_info(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fund_info: Option<CollectionFundInfo>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+
+    let mut event = EventHelper::new("mint_update_creation_fee_fund_info");
+
+    match fund_info {
+        Some(fund_info) => {
+            let cw20_address = match fund_info.cw20_address {
+                Some(addr) => Some(deps.api.addr_validate(&addr)?),
+                None => None,
+            };
+            let fund_info = FundInfo {
+                is_native: fund_info.is_native,
+                denom: fund_info.denom,
+                cw20_address,
+            };
+
+            if !fund_info.is_native {
+                check_cw20_fund_info(&deps, &fund_info)?;
+            };
+            CREATION_FEE_FUND_INFO.save(deps.storage, &fund_info)?;
+
+            event = event
+                .add_attribute("is_native", fund_info.is_native.to_string())
+                .add_attribute("denom", fund_info.denom)
+                .check_add_attribute(
+                    &fund_info.cw20_address,
+                    "cw20_address",
+                    fund_info
+                        .cw20_address
+                        .as_ref()
+                        .unwrap_or(&Addr::unchecked(""))
+                        .to_string(),
+                );
+        }
+        None => CREATION_FEE_FUND_INFO.remove(deps.storage),
+    };
+
+    Ok(ResponseHelper::new_module("mint", "update_creation_fee_fund_info").add_event(event.get()))
+}
+
+fn execute_update_dutch_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    dutch_auction: Option<DutchAuction>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    if !COLLECTION_ADDRS.has(deps.storage, collection_id) {
+        return Err(ContractError::CollectionIdNotFound {});
+    }
+
+    if let Some(current) = DUTCH_AUCTIONS.may_load(deps.storage, collection_id)? {
+        let is_started = current.start_time <= env.block.time;
+        if is_started && (current.rebate || env.block.time < current.end_time) {
+            return Err(ContractError::DutchAuctionStarted {});
+        }
+    }
+
+    let mut event = EventHelper::new("mint_update_dutch_auction")
+        .add_attribute("collection_id", collection_id.to_string());
+
+    match dutch_auction {
+        Some(dutch_auction) => {
+            let duration = dutch_auction
+                .end_time
+                .seconds()
+                .saturating_sub(dutch_auction.start_time.seconds());
+            if dutch_auction.start_price <= dutch_auction.resting_price
+                || dutch_auction.start_time <= env.block.time
+                || dutch_auction.step_interval == 0
+                || dutch_auction.step_interval > duration
+            {
+                return Err(ContractError::InvalidDutchAuction {});
+            }
+
+            DUTCH_AUCTIONS.save(deps.storage, collection_id, &dutch_auction)?;
+            DUTCH_AUCTION_CLEARING_PRICES.remove(deps.storage, collection_id);
+
+            event = event
+                .add_attribute("start_price", dutch_auction.start_price)
+                .add_attribute("resting_price", dutch_auction.resting_price)
+                .add_attribute("start_time", dutch_auction.start_time.to_string())
+                .add_attribute("end_time", dutch_auction.end_time.to_string())
+                .add_attribute("step_interval", dutch_auction.step_interval.to_string())
+                .add_attribute("rebate", dutch_auction.rebate.to_string());
+        }
+        None => DUTCH_AUCTIONS.remove(deps.storage, collection_id),
+    };
+
+    Ok(ResponseHelper::new_module("mint", "update_dutch_auction").add_event(event.get()))
+}
+
+fn execute_claim_dutch_auction_rebate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    address: Option<String>,
+) -> Result<Response, ContractError> {
+    let dutch_auction = DUTCH_AUCTIONS
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::NoRebate {})?;
+    if env.block.time < dutch_auction.end_time {
+        return Err(ContractError::DutchAuctionNotEnded {});
+    }
+
+    let address = match address {
+        Some(address) => deps.api.addr_validate(&address)?,
+        None => info.sender,
+    };
+    let auction_mint = DUTCH_AUCTION_MINTS
+        .may_load(deps.storage, (collection_id, &address))?
+        .ok_or(ContractError::NoRebate {})?;
+    DUTCH_AUCTION_MINTS.remove(deps.storage, (collection_id, &address));
+
+    // Minters pay the clearing price and the rest of the kept price is refunded
+    let clearing_price = DUTCH_AUCTION_CLEARING_PRICES
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::ClearingPriceNotFound {})?;
+    let rebate = auction_mint.paid - clearing_price * Uint128::from(auction_mint.quantity);
+
+    let fund_info = COLLECTION_FUND_INFO.load(deps.storage, collection_id)?;
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !rebate.is_zero() {
+        msgs.push(send_funds_msg(&fund_info, &address, rebate)?);
+    }
+
+    Ok(
+        ResponseHelper::new_module("mint", "claim_dutch_auction_rebate")
+            .add_messages(msgs)
+            .add_event(
+                EventHelper::new("mint_claim_dutch_auction_rebate")
+                    .add_attribute("collection_id", collection_id.to_string())
+                    .add_attribute("address", address)
+                    .add_attribute("clearing_price", clearing_price)
+                    .add_attribute("rebate", rebate)
+                    .get(),
+            ),
+    )
+}
+
+fn execute_settle_dutch_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+) -> Result<Response, ContractError> {
+    check_collection_privileges(&deps, &env, &info, collection_id)?;
+
+    let dutch_auction = DUTCH_AUCTIONS
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::NoUnsettledRevenue {})?;
+    if env.block.time < dutch_auction.end_time {
+        return Err(ContractError::DutchAuctionNotEnded {});
+    }
+
+    let quantity = DUTCH_AUCTION_UNSETTLED
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::NoUnsettledRevenue {})?;
+    DUTCH_AUCTION_UNSETTLED.remove(deps.storage, collection_id);
+
+    // Price between the resting and clearing price is paid out for every token
+    let clearing_price = DUTCH_AUCTION_CLEARING_PRICES
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::ClearingPriceNotFound {})?;
+    let revenue = (clearing_price - dutch_auction.resting_price) * Uint128::from(quantity);
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    if !revenue.is_zero() {
+        let hub_addr = HUB_ADDR.load(deps.storage)?;
+        let fund_info = COLLECTION_FUND_INFO.load(deps.storage, collection_id)?;
+        msgs = process_price(
+            &deps,
+            &hub_addr,
+            &fund_info,
+            collection_id,
+            revenue,
+            env.contract.address.as_str(),
+        )?;
+    }
+
+    Ok(ResponseHelper::new_module("mint", "settle_dutch_auction")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("mint_settle_dutch_auction")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("clearing_price", clearing_price)
+                .add_attribute("quantity", quantity.to_string())
+                .add_attribute("revenue", revenue)
+                .get(),
+        ))
+}
+
+// Price drops from the start price to the resting price in equal steps
+fn dutch_auction_price(dutch_auction: &DutchAuction, time: Timestamp) -> Uint128 {
+    let duration = dutch_auction.end_time.seconds() - dutch_auction.start_time.seconds();
+    let total_steps = duration / dutch_auction.step_interval;
+    let elapsed_steps = time
+        .seconds()
+        .saturating_sub(dutch_auction.start_time.seconds())
+        / dutch_auction.step_interval;
+
+    let price_drop = (dutch_auction.start_price - dutch_auction.resting_price)
+        .multiply_ratio(elapsed_steps.min(total_steps), total_steps);
+    dutch_auction.start_price - price_drop
+}
+
+fn execute_update_bonding_curve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    bonding_curve: Option<BondingCurve>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    if !COLLECTION_ADDRS.has(deps.storage, collection_id) {
+        return Err(ContractError::CollectionIdNotFound {});
+    }
+
+    let reserve = BONDING_CURVE_RESERVES
+        .may_load(deps.storage, collection_id)?
+        .unwrap_or_default();
+    if !reserve.is_zero() {
+        return Err(ContractError::ReserveNotEmpty {});
+    }
+
+    let mut event = EventHelper::new("mint_update_bonding_curve")
+        .add_attribute("collection_id", collection_id.to_string());
+
+    match bonding_curve {
+        Some(bonding_curve) => {
+            let is_valid_curve = match &bonding_curve.curve {
+                Curve::Linear { .. } => true,
+                Curve::Exponential { growth_rate, .. } => !growth_rate.is_zero(),
+                Curve::Piecewise { steps } => {
+                    steps.first().map(|step| step.0) == Some(0)
+                        && steps.windows(2).all(|pair| pair[0].0 < pair[1].0)
+                }
+            };
+            let is_valid_ratio = match bonding_curve.reserve_ratio {
+                Some(reserve_ratio) => !reserve_ratio.is_zero() && reserve_ratio <= Decimal::one(),
+                None => true,
+            };
+            if !is_valid_curve || !is_valid_ratio {
+                return Err(ContractError::InvalidBondingCurve {});
+            }
+
+            BONDING_CURVES.save(deps.storage, collection_id, &bonding_curve)?;
+
+            event = event.check_add_attribute(
+                &bonding_curve.reserve_ratio,
+                "reserve_ratio",
+                bonding_curve.reserve_ratio.unwrap_or_default().to_string(),
+            );
+        }
+        None => BONDING_CURVES.remove(deps.storage, collection_id),
+    };
+
+    Ok(ResponseHelper::new_module("mint", "update_bonding_curve").add_event(event.get()))
+}
+
+fn execute_sell_back(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    token_id: u32,
+) -> Result<Response, ContractError> {
+    // Archived collections are mint locked for good, but their reserve stays open
+    let mint_lock = MINT_LOCKS.load(deps.storage, collection_id)?;
+    if mint_lock && !ARCHIVED_COLLECTIONS.has(deps.storage, collection_id) {
+        return Err(ContractError::LockedMint {});
+    }
+
+    let bonding_curve = BONDING_CURVES.may_load(deps.storage, collection_id)?;
+    let reserve_ratio = bonding_curve
+        .as_ref()
+        .and_then(|bonding_curve| bonding_curve.reserve_ratio)
+        .ok_or(ContractError::SellBackDisabled {})?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, collection_id)?;
+    let owner = StorageHelper::query_token_owner(&deps.querier, &collection_addr, &token_id)?;
+    if owner != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    // Refund is the reserve share of the last minted token price
+    let supply = bonding_curve_supply(deps.as_ref(), collection_id)?
+        .checked_sub(1)
+        .ok_or(ContractError::SellBackDisabled {})?;
+    let price = bonding_curve_price(&bonding_curve.unwrap().curve, supply)?;
+    let refund = price.mul(reserve_ratio);
+
+    let reserve = BONDING_CURVE_RESERVES
+        .may_load(deps.storage, collection_id)?
+        .unwrap_or_default();
+    BONDING_CURVE_RESERVES.save(
+        deps.storage,
+        collection_id,
+        &reserve.checked_sub(refund).map_err(StdError::from)?,
+    )?;
+    BONDING_CURVE_SUPPLIES.save(deps.storage, collection_id, &supply)?;
+
+    let fund_info = COLLECTION_FUND_INFO.load(deps.storage, collection_id)?;
+    let mut msgs: Vec<CosmosMsg> = vec![KompleTokenModule(collection_addr)
+        .burn_msg(token_id.to_string())?
+        .into()];
+    if !refund.is_zero() {
+        msgs.push(send_funds_msg(&fund_info, &info.sender, refund)?);
+    }
+
+    Ok(ResponseHelper::new_module("mint", "sell_back")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("mint_sell_back")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("token_id", token_id.to_string())
+                .add_attribute("owner", info.sender)
+                .add_attribute("refund", refund)
+                .get(),
+        ))
+}
+
+// Supply only counts the tokens minted on the curve, not admin mints or other burns
+fn bonding_curve_supply(deps: Deps, collection_id: u32) -> StdResult<u32> {
+    Ok(BONDING_CURVE_SUPPLIES
+        .may_load(deps.storage, collection_id)?
+        .unwrap_or(0))
+}
+
+fn bonding_curve_price(curve: &Curve, supply: u32) -> StdResult<Uint128> {
+    let price = match curve {
+        Curve::Linear { base_price, slope } => {
+            base_price.checked_add(slope.checked_mul(Uint128::from(supply))?)?
+        }
+        Curve::Exponential {
+            base_price,
+            growth_rate,
+        } => {
+            let multiplier = (Decimal::one() + growth_rate)
+                .checked_pow(supply)
+                .map_err(|err| StdError::generic_err(err.to_string()))?;
+            base_price
+                .checked_multiply_ratio(multiplier.atomics(), Decimal::one().atomics())
+                .map_err(|err| StdError::generic_err(err.to_string()))?
+        }
+        Curve::Piecewise { steps } => steps
+            .iter()
+            .rev()
+            .find(|(step_supply, _)| *step_supply <= supply)
+            .map(|(_, price)| *price)
+            .unwrap_or_default(),
+    };
+    Ok(price)
+}
+
+// Shuffled collections commit the provenance of the metadata pool on creation
+fn save_metadata_shuffle(
+    storage: &mut dyn Storage,
+    collection_id: u32,
+    collection_info: &CollectionInfo,
+    metadata_shuffle: &MetadataShuffleInfo,
+) -> Result<(), ContractError> {
+    // Standard collections always link the metadata of the token id
+    if collection_info.collection_type == Collections::Standard || metadata_shuffle.pool_size == 0 {
+        return Err(ContractError::InvalidMetadataShuffle {});
+    }
+
+    let provenance_hash = metadata_shuffle.provenance_hash.to_lowercase();
+    let seed_commitment = metadata_shuffle.seed_commitment.to_lowercase();
+    for hash in [&provenance_hash, &seed_commitment] {
+        match hex::decode(hash) {
+            Ok(hash) if hash.len() == 32 => {}
+            _ => return Err(ContractError::InvalidMetadataShuffle {}),
+        };
+    }
+
+    METADATA_SHUFFLES.save(
+        storage,
+        collection_id,
+        &MetadataShuffle {
+            provenance_hash: provenance_hash.clone(),
+            pool_size: metadata_shuffle.pool_size,
+            remaining: metadata_shuffle.pool_size,
+            pending: 0,
+            seed: provenance_hash,
+            seed_commitment,
+        },
+    )?;
+    Ok(())
+}
+
+fn execute_reveal_shuffle_seed(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    seed: String,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    check_collection_privileges(&deps, &env, &info, collection_id)?;
+
+    let limit = limit
+        .unwrap_or(SHUFFLE_REVEAL_LIMIT)
+        .min(SHUFFLE_REVEAL_LIMIT);
+    if limit == 0 {
+        return Err(ContractError::InvalidQuantity {});
+    }
+
+    let mut metadata_shuffle = METADATA_SHUFFLES
+        .may_load(deps.storage, collection_id)?
+        .ok_or(ContractError::InvalidMetadataShuffle {})?;
+    if hex::encode(Sha256::digest(seed.as_bytes())) != metadata_shuffle.seed_commitment {
+        return Err(ContractError::InvalidShuffleSeed {});
+    }
+
+    // Mints of this block could still be made after the seed is public
+    let shuffled_mints = SHUFFLED_MINTS
+        .prefix(collection_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| {
+            item.as_ref().map_or(true, |(_, shuffled_mint)| {
+                shuffled_mint.height < env.block.height
+            })
+        })
+        .take(limit as usize)
+        .collect::<StdResult<Vec<(Addr, ShuffledMint)>>>()?;
+    if shuffled_mints.is_empty() {
+        return Err(ContractError::NoShuffledMint {});
+    }
+
+    // Seed is used once and its pre-image is the next commitment
+    metadata_shuffle.seed_commitment = seed.clone();
+    metadata_shuffle.pending -= shuffled_mints
+        .iter()
+        .map(|(_, shuffled_mint)| shuffled_mint.quantity)
+        .sum::<u32>();
+    METADATA_SHUFFLES.save(deps.storage, collection_id, &metadata_shuffle)?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, collection_id)?;
+    let token_module = KompleTokenModule(collection_addr);
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for (address, shuffled_mint) in shuffled_mints.iter() {
+        SHUFFLED_MINTS.remove(deps.storage, (collection_id, address));
+
+        let metadata_ids = shuffled_metadata_ids(
+            deps.storage,
+            seed.as_bytes(),
+            collection_id,
+            address.as_str(),
+            shuffled_mint.quantity,
+            None,
+        )?
+        .unwrap_or_default();
+        msgs.push(
+            token_module
+                .mint_reserved_msg(address.to_string(), metadata_ids)?
+                .into(),
+        );
+    }
+
+    Ok(ResponseHelper::new_module("mint", "reveal_shuffle_seed")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("mint_reveal_shuffle_seed")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attribute("seed", seed)
+                .add_attribute("drawn", shuffled_mints.len().to_string())
+                .get(),
+        ))
+}
+
+// Entropy of draws that are made in the same transaction
+// Only used by the admin mints, public mints are drawn with the revealed seed
+fn block_entropy(env: &Env) -> Vec<u8> {
+    let mut entropy = env.block.height.to_be_bytes().to_vec();
+    entropy.extend(env.block.time.nanos().to_be_bytes());
+    if let Some(transaction) = &env.transaction {
+        entropy.extend(transaction.index.to_be_bytes());
+    }
+    entropy
+}
+
+// Draws the metadata ids from the remaining pool of a shuffled collection
+// Metadata ids are returned unchanged if the collection is not shuffled
+fn shuffled_metadata_ids(
+    storage: &mut dyn Storage,
+    entropy: &[u8],
+    collection_id: u32,
+    recipient: &str,
+    quantity: u32,
+    metadata_ids: Option<Vec<u32>>,
+) -> Result<Option<Vec<u32>>, ContractError> {
+    let mut metadata_shuffle = match METADATA_SHUFFLES.may_load(storage, collection_id)? {
+        Some(metadata_shuffle) => metadata_shuffle,
+        None => return Ok(metadata_ids),
+    };
+
+    if metadata_ids.is_some() {
+        return Err(ContractError::MetadataIdNotAllowed {});
+    }
+    if metadata_shuffle.remaining - metadata_shuffle.pending < quantity {
+        return Err(ContractError::MetadataPoolEmpty {});
+    }
+
+    let mut drawn_ids: Vec<u32> = vec![];
+    for _ in 0..quantity {
+        let mut hasher = Sha256::new();
+        hasher.update(metadata_shuffle.seed.as_bytes());
+        hasher.update(entropy);
+        hasher.update(recipient.as_bytes());
+        let seed = hasher.finalize();
+
+        let mut random_bytes = [0u8; 8];
+        random_bytes.copy_from_slice(&seed[..8]);
+        let position =
+            (u64::from_be_bytes(random_bytes) % metadata_shuffle.remaining as u64) as u32;
+        let last_position = metadata_shuffle.remaining - 1;
+
+        // Swap the drawn id with the last one in the pool and shrink the pool
+        let metadata_id = METADATA_SHUFFLE_POOL
+            .may_load(storage, (collection_id, position))?
+            .unwrap_or(position + 1);
+        let last_metadata_id = METADATA_SHUFFLE_POOL
+            .may_load(storage, (collection_id, last_position))?
+            .unwrap_or(last_position + 1);
+        METADATA_SHUFFLE_POOL.save(storage, (collection_id, position), &last_metadata_id)?;
+        METADATA_SHUFFLE_POOL.remove(storage, (collection_id, last_position));
+
+        metadata_shuffle.remaining = last_position;
+        metadata_shuffle.seed = hex::encode(seed);
+        drawn_ids.push(metadata_id);
+    }
+
+    METADATA_SHUFFLES.save(storage, collection_id, &metadata_shuffle)?;
+
+    Ok(Some(drawn_ids))
+}
+
+fn execute_update_voucher_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    voucher_key: Option<VoucherKey>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    if !COLLECTION_ADDRS.has(deps.storage, collection_id) {
+        return Err(ContractError::CollectionIdNotFound {});
+    }
+
+    let mut event = EventHelper::new("mint_update_voucher_key")
+        .add_attribute("collection_id", collection_id.to_string());
+
+    match voucher_key {
+        Some(voucher_key) => {
+            let (key_type, is_valid_key) = match &voucher_key {
+                VoucherKey::Secp256k1 { pubkey } => {
+                    ("secp256k1", pubkey.len() == 33 || pubkey.len() == 65)
+                }
+                VoucherKey::Ed25519 { pubkey } => ("ed25519", pubkey.len() == 32),
+            };
+            if !is_valid_key {
+                return Err(ContractError::InvalidVoucherKey {});
+            }
+
+            VOUCHER_KEYS.save(deps.storage, collection_id, &voucher_key)?;
+
+            event = event.add_attribute("key_type", key_type);
+        }
+        None => VOUCHER_KEYS.remove(deps.storage, collection_id),
+    };
+
+    Ok(ResponseHelper::new_module("mint", "update_voucher_key").add_event(event.get()))
+}
+
+fn execute_receive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    cw20_receive_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let msg: ReceiveMsg = from_binary(&cw20_receive_msg.msg)?;
+    let recipient = cw20_receive_msg.sender;
+    let mint_request = match msg {
+        ReceiveMsg::CreateCollection(create_collection_msg) => {
+            let creator = deps.api.addr_validate(&recipient)?;
+            return execute_create_collection(
+                deps,
+                env,
+                info,
+                creator,
+                *create_collection_msg,
+                Some(cw20_receive_msg.amount),
+            );
+        }
+        ReceiveMsg::Mint {
+            collection_id,
+            quantity,
+            metadata_ids,
+        } => MintRequest {
+            collection_id,
+            recipient,
+            quantity,
+            metadata_ids,
+            proof: None,
+            voucher: None,
+        },
+        ReceiveMsg::MintWithProof {
+            collection_id,
+            quantity,
+            metadata_ids,
+            proof,
+        } => MintRequest {
+            collection_id,
+            recipient,
+            quantity,
+            metadata_ids,
+            proof: Some(proof),
+            voucher: None,
+        },
+        ReceiveMsg::MintWithVoucher {
+            quantity,
+            metadata_ids,
+            voucher,
+            signature,
+        } => MintRequest {
+            collection_id: voucher.collection_id,
+            recipient,
+            quantity,
+            metadata_ids,
+            proof: None,
+            voucher: Some((voucher, signature)),
+        },
+    };
+
+    execute_mint(deps, env, info, mint_request, Some(cw20_receive_msg.amount))
+}
+
+pub fn execute_update_mint_phases(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    collection_id: u32,
+    phases: Vec<MintPhaseMsg>,
+) -> Result<Response, ContractError> {
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    )?;
+    check_collection_archived(deps.storage, collection_id)?;
+
+    check_collection_ids_exists(&deps, &vec![collection_id])?;
+
+    let collection_fund_info = COLLECTION_FUND_INFO.load(deps.storage, collection_id)?;
+
+    let mut event_attributes: Vec<Attribute> = vec![];
+    let mut mint_phases: Vec<MintPhase> = vec![];
+    for phase in phases {
+        check_mint_phase_msg(&deps, &phase, mint_phases.last())?;
+        if mint_phases.iter().any(|p| p.name == phase.name) {
+            return Err(ContractError::InvalidMintPhases {});
+        }
+
+        let fund_info = match phase.fund_info {
+            Some(fund_info) => {
+                let cw20_address = match fund_info.cw20_address {
+                    Some(addr) => Some(deps.api.addr_validate(&addr)?),
+                    None => None,
+                };
+                let fund_info = FundInfo {
+                    is_native: fund_info.is_native,
+                    denom: fund_info.denom,
+                    cw20_address,
+                };
+                if !fund_info.is_native {
+                    check_cw20_fund_info(&deps, &fund_info)?;
+                };
+                fund_info
+            }
+            None => collection_fund_info.clone(),
+        };
+
+        event_attributes.push(Attribute {
+            key: "phases".to_string(),
+            value: phase.name.to_string(),
+        });
+        mint_phases.push(MintPhase {
+            name: phase.name,
+            start_time: phase.start_time,
+            end_time: phase.end_time,
+            price: phase.price,
+            fund_info,
+            per_address_limit: phase.per_address_limit,
+            max_token_limit: phase.max_token_limit,
+            eligibility: phase.eligibility,
+        });
+    }
+
+    match mint_phases.is_empty() {
+        true => MINT_PHASES.remove(deps.storage, collection_id),
+        false => MINT_PHASES.save(deps.storage, collection_id, &mint_phases)?,
+    };
+
+    Ok(
+        ResponseHelper::new_module("mint", "update_mint_phases").add_event(
+            EventHelper::new("mint_update_mint_phases")
+                .add_attribute("collection_id", collection_id.to_string())
+                .add_attributes(event_attributes)
+                .get(),
+        ),
+    )
+}
+
+// Validates the phase values and the order with the previous phase
+fn check_mint_phase_msg(
+    deps: &DepsMut,
+    phase: &MintPhaseMsg,
+    previous_phase: Option<&MintPhase>,
+) -> Result<(), ContractError> {
+    if phase.name.is_empty()
+        || phase.per_address_limit == Some(0)
+        || phase.max_token_limit == Some(0)
+    {
+        return Err(ContractError::InvalidMintPhases {});
+    }
+    if let Some(end_time) = phase.end_time {
+        if end_time <= phase.start_time {
+            return Err(ContractError::InvalidMintPhases {});
+        }
+    }
+    if let Some(previous_phase) = previous_phase {
+        let previous_end = previous_phase.end_time.unwrap_or(previous_phase.start_time);
+        if phase.start_time <= previous_phase.start_time || phase.start_time < previous_end {
+            return Err(ContractError::InvalidMintPhases {});
+        }
+    }
+
+    match &phase.eligibility {
+        Some(Eligibility::Whitelist { address }) => {
+            deps.api.addr_validate(address)?;
+        }
+        Some(Eligibility::MerkleRoot { root }) => {
+            let root = hex::decode(root).map_err(|_| ContractError::InvalidMintPhases {})?;
+            if root.len() != 32 {
+                return Err(ContractError::InvalidMintPhases {});
+            }
+        }
+        Some(Eligibility::Holder { collection_id }) => {
+            check_collection_ids_exists(deps, &vec![*collection_id])?;
+        }
+        None => {}
+    }
+
+    Ok(())
+}
+
+// Uses the active mint phase if the collection has phases
+// Otherwise the prices are resolved from the fee module
+fn process_mint_payment(
+    deps: &mut DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    mint_request: &MintRequest,
+    cw20_token_amount: Option<Uint128>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    if let Some((voucher, signature)) = &mint_request.voucher {
+        check_mint_voucher(deps, env, mint_request, voucher, signature)?;
+        let fund_info = COLLECTION_FUND_INFO.load(deps.storage, mint_request.collection_id)?;
+        return process_fixed_price(
+            deps,
+            info,
+            mint_request,
+            &fund_info,
+            voucher.price,
+            cw20_token_amount,
+        );
+    }
+
+    let phases = MINT_PHASES.may_load(deps.storage, mint_request.collection_id)?;
+    match phases {
+        Some(phases) => {
+            let phase = check_mint_phase(deps, env, mint_request, phases)?;
+            process_phase_price(deps, info, mint_request, &phase, cw20_token_amount)
+        }
+        None => process_minting_prices(deps, env, info, mint_request, cw20_token_amount),
+    }
+}
+
+// Active phase is the last started phase if it has not ended
+fn active_mint_phase(phases: Vec<MintPhase>, time: Timestamp) -> Option<MintPhase> {
+    phases
+        .into_iter()
+        .rev()
+        .find(|phase| phase.start_time <= time)
+        .filter(|phase| match phase.end_time {
+            Some(end_time) => time < end_time,
+            None => true,
+        })
+}
+
+// Finds the active phase and checks the eligibility and limits of the recipient
+// Increases the phase mint counts if the checks pass
+fn check_mint_phase(
+    deps: &mut DepsMut,
+    env: &Env,
+    mint_request: &MintRequest,
+    phases: Vec<MintPhase>,
+) -> Result<MintPhase, ContractError> {
+    let collection_id = mint_request.collection_id;
+    let quantity = mint_request.quantity;
+    let phase = active_mint_phase(phases, env.block.time).ok_or(ContractError::NoActivePhase {})?;
+
+    let recipient = deps.api.addr_validate(&mint_request.recipient)?;
+
+    let is_eligible = match &phase.eligibility {
+        Some(Eligibility::Whitelist { address }) => {
+            let query_key =
+                StorageHelper::get_map_storage_key(WHITELIST_NAMESPACE, &[recipient.as_bytes()])?;
+            let res = StorageHelper::query_storage::<bool>(
+                &deps.querier,
+                &Addr::unchecked(address),
+                &query_key,
+            )?;
+            res.is_some()
+        }
+        Some(Eligibility::MerkleRoot { root }) => match &mint_request.proof {
+            Some(proof) => verify_merkle_proof(recipient.as_str(), proof, root)?,
+            None => false,
+        },
+        Some(Eligibility::Holder {
+            collection_id: holder_collection_id,
+        }) => {
+            let collection_addr = COLLECTION_ADDRS.load(deps.storage, *holder_collection_id)?;
+            let res: TokensResponse = deps.querier.query_wasm_smart(
+                collection_addr,
+                &Cw721QueryMsg::Tokens {
+                    owner: recipient.to_string(),
+                    start_after: None,
+                    limit: Some(1),
+                },
+            )?;
+            !res.tokens.is_empty()
+        }
+        None => true,
+    };
+    if !is_eligible {
+        return Err(ContractError::NotEligible {});
+    }
+
+    let phase_mints = PHASE_MINTS
+        .may_load(deps.storage, (collection_id, &phase.name))?
+        .unwrap_or(0);
+    if phase.max_token_limit.is_some() && phase_mints + quantity > phase.max_token_limit.unwrap() {
+        return Err(ContractError::PhaseTokenLimitReached {});
+    }
+
+    let addr_mints = PHASE_ADDR_MINTS
+        .may_load(deps.storage, (collection_id, &phase.name, &recipient))?
+        .unwrap_or(0);
+    if phase.per_address_limit.is_some() && addr_mints + quantity > phase.per_address_limit.unwrap()
+    {
+        return Err(ContractError::PhaseAddressLimitReached {});
+    }
+
+    PHASE_MINTS.save(
+        deps.storage,
+        (collection_id, &phase.name),
+        &(phase_mints + quantity),
+    )?;
+    PHASE_ADDR_MINTS.save(
+        deps.storage,
+        (collection_id, &phase.name, &recipient),
+        &(addr_mints + quantity),
+    )?;
+
+    Ok(phase)
+}
+
+// Checks the signature, expiry, quantity and nonce of the voucher
+// Nonce is saved as used if the voucher is valid
+fn check_mint_voucher(
+    deps: &mut DepsMut,
+    env: &Env,
+    mint_request: &MintRequest,
+    voucher: &MintVoucher,
+    signature: &Binary,
+) -> Result<(), ContractError> {
+    let voucher_key = VOUCHER_KEYS
+        .may_load(deps.storage, voucher.collection_id)?
+        .ok_or(ContractError::InvalidVoucher {})?;
+
+    if voucher.recipient != mint_request.recipient {
+        return Err(ContractError::InvalidVoucher {});
+    }
+    if mint_request.quantity > voucher.max_quantity {
+        return Err(ContractError::InvalidQuantity {});
+    }
+    if env.block.time >= voucher.expiry {
+        return Err(ContractError::VoucherExpired {});
+    }
+    if VOUCHER_NONCES.has(deps.storage, (voucher.collection_id, voucher.nonce)) {
+        return Err(ContractError::VoucherUsed {});
+    }
+
+    // Contract address is signed as well so that vouchers cannot be used on other mint modules
+    let mut hasher = Sha256::new();
+    hasher.update(env.contract.address.as_bytes());
+    hasher.update(to_vec(voucher)?);
+    let message_hash = hasher.finalize();
+
+    let is_valid = match voucher_key {
+        VoucherKey::Secp256k1 { pubkey } => {
+            deps.api.secp256k1_verify(&message_hash, signature, &pubkey)
+        }
+        VoucherKey::Ed25519 { pubkey } => {
+            deps.api.ed25519_verify(&message_hash, signature, &pubkey)
+        }
+    };
+    if !matches!(is_valid, Ok(true)) {
+        return Err(ContractError::InvalidVoucher {});
+    }
+
+    VOUCHER_NONCES.save(deps.storage, (voucher.collection_id, voucher.nonce), &true)?;
+
+    Ok(())
+}
+
+// Checks the sent funds against the phase price and creates the payment messages
+fn process_phase_price(
+    deps: &DepsMut,
+    info: &MessageInfo,
+    mint_request: &MintRequest,
+    phase: &MintPhase,
+    cw20_token_amount: Option<Uint128>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    process_fixed_price(
+        deps,
+        info,
+        mint_request,
+        &phase.fund_info,
+        phase.price,
+        cw20_token_amount,
+    )
+}
+
+// Charges the price for each token of the request
+fn process_fixed_price(
+    deps: &DepsMut,
+    info: &MessageInfo,
+    mint_request: &MintRequest,
+    fund_info: &FundInfo,
+    price: Uint128,
+    cw20_token_amount: Option<Uint128>,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let total_price = price * Uint128::from(mint_request.quantity);
+    if total_price.is_zero() {
+        return Ok(vec![]);
+    }
+
+    match fund_info.is_native {
+        true => check_single_coin(
+            info,
+            Coin {
+                denom: fund_info.denom.to_string(),
+                amount: total_price,
+            },
+        )?,
+        false => {
+            if info.sender != *fund_info.cw20_address.as_ref().unwrap()
+                || cw20_token_amount != Some(total_price)
+            {
+                return Err(FundsError::InvalidCw20Token {}.into());
             }
+        }
+    };
 
-            let mint_msg = MintMsg {
-                collection_id,
-                recipient: info.sender.to_string(),
-                metadata_id,
-            };
-            let msgs: Vec<CosmosMsg> =
-                process_minting_prices(&deps, &info, &sender, collection_id, Some(amount))?;
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+
+    process_price(
+        deps,
+        &hub_addr,
+        fund_info,
+        mint_request.collection_id,
+        total_price,
+        &mint_request.recipient,
+    )
+}
 
-            _execute_mint(deps, "mint", msgs, mint_msg)
+// Hashes the address leaf with the proof and compares it with the root
+// Pairs are sorted before hashing so the proof does not need the positions
+fn verify_merkle_proof(address: &str, proof: &[String], root: &str) -> Result<bool, ContractError> {
+    let mut hash: [u8; 32] = Sha256::digest(address.as_bytes()).into();
+    for proof_hash in proof {
+        let proof_hash: [u8; 32] = hex::decode(proof_hash)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ContractError::NotEligible {})?;
+
+        let mut hasher = Sha256::new();
+        match hash <= proof_hash {
+            true => {
+                hasher.update(hash);
+                hasher.update(proof_hash);
+            }
+            false => {
+                hasher.update(proof_hash);
+                hasher.update(hash);
+            }
         }
+        hash = hasher.finalize().into();
     }
+    Ok(hex::encode(hash) == root.to_lowercase())
 }
 
 fn process_minting_prices(
-    deps: &DepsMut,
+    deps: &mut DepsMut,
+    env: &Env,
     info: &MessageInfo,
-    recipient: &str,
-    collection_id: u32,
+    mint_request: &MintRequest,
     cw20_token_amount: Option<Uint128>,
 ) -> Result<Vec<CosmosMsg>, ContractError> {
     let hub_addr = HUB_ADDR.load(deps.storage)?;
-    let config = CONFIG.load(deps.storage)?;
+    let collection_id = mint_request.collection_id;
+    let recipient = mint_request.recipient.as_str();
+    let quantity = Uint128::from(mint_request.quantity);
 
     let mut msgs: Vec<CosmosMsg> = vec![];
     let mut total_price = Uint128::zero();
@@ -784,42 +2417,37 @@ fn process_minting_prices(
                 }
 
                 // If fee module is registered, check for whitelist minting price
-                if fee_module_addr.is_some() {
+                if let Some(fee_module_addr) = &fee_module_addr {
                     // Whitelist is active and user is member
                     let res = StorageHelper::query_fixed_fee(
                         &deps.querier,
-                        &fee_module_addr.as_ref().unwrap(),
+                        fee_module_addr,
                         Modules::Mint.to_string(),
                         MintFees::new_whitelist_price(collection_id),
+                        env.block.time,
                     );
 
                     // If whitelist price exists
                     if let Ok(fixed_fee_response) = res {
-                        // Create send message if not zero
-                        if !fixed_fee_response.value.is_zero() {
-                            let msg = match collection_fund_info.is_native {
-                                true => CosmosMsg::Bank(BankMsg::Send {
-                                    to_address: config.admin.to_string(),
-                                    amount: coins(
-                                        fixed_fee_response.value.u128(),
-                                        collection_fund_info.denom.to_string(),
-                                    ),
-                                }),
-                                false => CosmosMsg::Wasm(WasmMsg::Execute {
-                                    contract_addr: collection_fund_info
-                                        .cw20_address
-                                        .as_ref()
-                                        .unwrap()
-                                        .to_string(),
-                                    msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                                        recipient: config.admin.to_string(),
-                                        amount: fixed_fee_response.value,
-                                    })?,
-                                    funds: vec![],
-                                }),
-                            };
-                            msgs.push(msg.into());
-                            total_price += fixed_fee_response.value;
+                        let price = apply_discount(
+                            deps,
+                            fee_module_addr,
+                            &MintFees::new_whitelist_price(collection_id),
+                            recipient,
+                            fixed_fee_response.value,
+                        )? * quantity;
+
+                        // Create payment messages if not zero
+                        if !price.is_zero() {
+                            msgs.append(&mut process_price(
+                                deps,
+                                &hub_addr,
+                                &collection_fund_info,
+                                collection_id,
+                                price,
+                                recipient,
+                            )?);
+                            total_price += price;
                         }
                     }
                 };
@@ -829,41 +2457,126 @@ fn process_minting_prices(
         }
     }
 
-    // Standard collection mint flow
-    if !is_whitelist {
+    // Dutch auction mint flow
+    let dutch_auction = DUTCH_AUCTIONS.may_load(deps.storage, collection_id)?;
+    let bonding_curve = BONDING_CURVES.may_load(deps.storage, collection_id)?;
+    if let (false, Some(dutch_auction)) = (is_whitelist, dutch_auction) {
+        let unit_price = dutch_auction_price(&dutch_auction, env.block.time);
+        let price = unit_price * quantity;
+
+        // Price above the resting price is kept for the rebates
+        let payout = match dutch_auction.rebate && env.block.time < dutch_auction.end_time {
+            true => {
+                let recipient = deps.api.addr_validate(recipient)?;
+                DUTCH_AUCTION_MINTS.update(
+                    deps.storage,
+                    (collection_id, &recipient),
+                    |auction_mint| -> StdResult<_> {
+                        let auction_mint = auction_mint.unwrap_or(DutchAuctionMint {
+                            quantity: 0,
+                            paid: Uint128::zero(),
+                        });
+                        Ok(DutchAuctionMint {
+                            quantity: auction_mint.quantity + mint_request.quantity,
+                            paid: auction_mint.paid + price,
+                        })
+                    },
+                )?;
+                DUTCH_AUCTION_UNSETTLED.update(
+                    deps.storage,
+                    collection_id,
+                    |unsettled| -> StdResult<_> {
+                        Ok(unsettled.unwrap_or_default() + mint_request.quantity)
+                    },
+                )?;
+                DUTCH_AUCTION_CLEARING_PRICES.save(deps.storage, collection_id, &unit_price)?;
+                dutch_auction.resting_price * quantity
+            }
+            false => price,
+        };
+
+        if !payout.is_zero() {
+            msgs.append(&mut process_price(
+                deps,
+                &hub_addr,
+                &collection_fund_info,
+                collection_id,
+                payout,
+                recipient,
+            )?);
+        }
+        total_price += price;
+    } else if let (false, Some(bonding_curve)) = (is_whitelist, bonding_curve) {
+        // Bonding curve mint flow
+        let supply = bonding_curve_supply(deps.as_ref(), collection_id)?;
+        BONDING_CURVE_SUPPLIES.save(
+            deps.storage,
+            collection_id,
+            &(supply + mint_request.quantity),
+        )?;
+        let prices = (supply..supply + mint_request.quantity)
+            .map(|supply| bonding_curve_price(&bonding_curve.curve, supply))
+            .collect::<StdResult<Vec<Uint128>>>()?;
+        let price: Uint128 = prices.iter().sum();
+
+        // Reserve share is kept for each token to match the sell back price
+        let reserve: Uint128 = match bonding_curve.reserve_ratio {
+            Some(reserve_ratio) => prices.iter().map(|price| (*price).mul(reserve_ratio)).sum(),
+            None => Uint128::zero(),
+        };
+        if !reserve.is_zero() {
+            BONDING_CURVE_RESERVES.update(
+                deps.storage,
+                collection_id,
+                |current| -> StdResult<_> { Ok(current.unwrap_or_default() + reserve) },
+            )?;
+        }
+
+        let payout = price - reserve;
+        if !payout.is_zero() {
+            msgs.append(&mut process_price(
+                deps,
+                &hub_addr,
+                &collection_fund_info,
+                collection_id,
+                payout,
+                recipient,
+            )?);
+        }
+        total_price += price;
+    } else if !is_whitelist {
+        // Standard collection mint flow
         // If fee module is registered, check for standard minting price
-        if fee_module_addr.is_some() {
+        if let Some(fee_module_addr) = fee_module_addr {
             // Token mint price
             let res = StorageHelper::query_fixed_fee(
                 &deps.querier,
-                &fee_module_addr.unwrap(),
+                &fee_module_addr,
                 Modules::Mint.to_string(),
                 MintFees::new_price(collection_id),
+                env.block.time,
             );
             if let Ok(fixed_fee_response) = res {
-                let msg = match collection_fund_info.is_native {
-                    true => CosmosMsg::Bank(BankMsg::Send {
-                        to_address: config.admin.to_string(),
-                        amount: coins(
-                            fixed_fee_response.value.u128(),
-                            collection_fund_info.denom.to_string(),
-                        ),
-                    }),
-                    false => CosmosMsg::Wasm(WasmMsg::Execute {
-                        contract_addr: collection_fund_info
-                            .cw20_address
-                            .as_ref()
-                            .unwrap()
-                            .to_string(),
-                        msg: to_binary(&Cw20ExecuteMsg::Transfer {
-                            recipient: config.admin.to_string(),
-                            amount: fixed_fee_response.value,
-                        })?,
-                        funds: vec![],
-                    }),
-                };
-                msgs.push(msg.into());
-                total_price += fixed_fee_response.value;
+                let price = apply_discount(
+                    deps,
+                    &fee_module_addr,
+                    &MintFees::new_price(collection_id),
+                    recipient,
+                    fixed_fee_response.value,
+                )? * quantity;
+
+                // Fully discounted mints are free
+                if !price.is_zero() {
+                    msgs.append(&mut process_price(
+                        deps,
+                        &hub_addr,
+                        &collection_fund_info,
+                        collection_id,
+                        price,
+                        recipient,
+                    )?);
+                    total_price += price;
+                }
             }
         }
     }
@@ -878,7 +2591,7 @@ fn process_minting_prices(
                 },
             )?;
         } else {
-            if cw20_token_amount.is_none() && cw20_token_amount.unwrap() != total_price {
+            if cw20_token_amount != Some(total_price) {
                 return Err(FundsError::InvalidCw20Token {}.into());
             };
         }
@@ -887,6 +2600,234 @@ fn process_minting_prices(
     Ok(msgs)
 }
 
+/// Creates the payment message of the public collection creation fee.
+///
+/// Creation fee is the fixed fees under the creation module name.
+/// Only the configured creation fee token is accepted and
+/// the paid amount is checked by the fee module on distribution.
+fn process_creation_fee(
+    deps: &DepsMut,
+    hub_addr: Option<Addr>,
+    info: &MessageInfo,
+    cw20_token_amount: Option<Uint128>,
+) -> Result<Option<CosmosMsg>, ContractError> {
+    let fee_module_addr = match hub_addr {
+        Some(hub_addr) => {
+            StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string())
+                .ok()
+        }
+        None => None,
+    };
+    let fee_module = match fee_module_addr {
+        Some(fee_module_addr) => KompleFeeModule(fee_module_addr),
+        None => return Ok(None),
+    };
+
+    let creation_fee =
+        fee_module.query_total_fixed_fees(&deps.querier, &MintFees::new_creation())?;
+    if creation_fee.is_zero() {
+        return Ok(None);
+    }
+
+    let fund_info = CREATION_FEE_FUND_INFO
+        .may_load(deps.storage)?
+        .ok_or(ContractError::CreationFeeFundInfoNotFound {})?;
+
+    let amount = match fund_info.is_native {
+        true => {
+            if cw20_token_amount.is_some() {
+                return Err(FundsError::InvalidCw20Token {}.into());
+            }
+            if info.funds.len() != 1 {
+                return Err(FundsError::MissingFunds {}.into());
+            }
+            if info.funds[0].denom != fund_info.denom {
+                return Err(FundsError::InvalidDenom {
+                    got: info.funds[0].denom.to_string(),
+                    expected: fund_info.denom,
+                }
+                .into());
+            }
+            info.funds[0].amount
+        }
+        false => match cw20_token_amount {
+            Some(amount) if info.sender == *fund_info.cw20_address.as_ref().unwrap() => amount,
+            _ => return Err(FundsError::InvalidCw20Token {}.into()),
+        },
+    };
+
+    let msg =
+        fee_module.distribute_fixed_funds_msg(&fund_info, MintFees::new_creation(), amount)?;
+    Ok(Some(msg.into()))
+}
+
+/// Creates the payment messages for a mint price.
+///
+/// Platform fees on the Marbu fee module are taken out of the price.
+/// The rest is distributed with the revenue split of the collection
+/// or sent to the admin if the collection has no split.
+fn process_price(
+    deps: &DepsMut,
+    hub_addr: &Addr,
+    fund_info: &FundInfo,
+    collection_id: u32,
+    price: Uint128,
+    recipient: &str,
+) -> Result<Vec<CosmosMsg>, ContractError> {
+    let mut msgs: Vec<CosmosMsg> = vec![];
+
+    let mut platform_fee = Uint128::zero();
+    let res =
+        StorageHelper::query_storage::<Addr>(&deps.querier, hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
+    if let Some(marbu_fee_module) = res {
+        let marbu_fee_module = KompleFeeModule(marbu_fee_module);
+        let fee_percentage = marbu_fee_module.query_total_discounted_percentage_fees(
+            &deps.querier,
+            Modules::Mint.as_str(),
+            recipient,
+        )?;
+        platform_fee = fee_percentage.mul(price);
+
+        if !platform_fee.is_zero() {
+            msgs.push(
+                marbu_fee_module
+                    .distribute_funds_msg(
+                        fund_info,
+                        Modules::Mint.to_string(),
+                        platform_fee,
+                        Some(recipient.to_string()),
+                    )?
+                    .into(),
+            );
+        }
+    }
+
+    let revenue = price - platform_fee;
+    if revenue.is_zero() {
+        return Ok(msgs);
+    }
+
+    // Revenue split is the percentage fees under the collection revenue module name
+    let revenue_module_name = MintFees::new_revenue(collection_id);
+    let fee_module =
+        StorageHelper::query_module_address(&deps.querier, hub_addr, Modules::Fee.to_string())
+            .ok()
+            .map(KompleFeeModule);
+    let split_percentage = match &fee_module {
+        Some(fee_module) => {
+            fee_module.query_total_percentage_fees(&deps.querier, &revenue_module_name)?
+        }
+        None => Decimal::zero(),
+    };
+    match fee_module {
+        Some(fee_module) if !split_percentage.is_zero() => msgs.push(
+            fee_module
+                .distribute_funds_msg(fund_info, revenue_module_name, revenue, None)?
+                .into(),
+        ),
+        _ => {
+            let admin = CONFIG.load(deps.storage)?.admin;
+            msgs.push(send_funds_msg(fund_info, &admin, revenue)?);
+        }
+    }
+
+    Ok(msgs)
+}
+
+// Transfers native or cw20 funds held by this contract
+fn send_funds_msg(fund_info: &FundInfo, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    let msg = match fund_info.is_native {
+        true => CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(amount.u128(), fund_info.denom.to_string()),
+        }),
+        false => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: fund_info.cw20_address.as_ref().unwrap().to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    };
+    Ok(msg)
+}
+
+/// Applies the holder discount of the recipient to a mint price.
+///
+/// Recipients exempt from the fee get the full discount.
+fn apply_discount(
+    deps: &DepsMut,
+    fee_module_addr: &Addr,
+    fee_name: &str,
+    recipient: &str,
+    price: Uint128,
+) -> Result<Uint128, ContractError> {
+    let discount = KompleFeeModule(fee_module_addr.clone()).query_discount(
+        &deps.querier,
+        Modules::Mint.as_str(),
+        fee_name,
+        recipient,
+    )?;
+    Ok(price.mul(Decimal::one() - discount))
+}
+
+/// Checks the admin privileges or the creator of the collection.
+///
+/// Creator is read from the token module config of the collection.
+fn check_collection_privileges(
+    deps: &DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    collection_id: u32,
+) -> Result<(), ContractError> {
+    let collection_addr = may_load_collection_addr(deps.storage, collection_id)?
+        .ok_or(ContractError::CollectionIdNotFound {})?;
+
+    let hub_addr = HUB_ADDR.may_load(deps.storage)?;
+    let operators = OPERATORS.may_load(deps.storage)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let privilege_check = check_admin_privileges(
+        &info.sender,
+        &env.contract.address,
+        &config.admin,
+        hub_addr,
+        operators,
+    );
+    if privilege_check.is_err() {
+        let token_config = StorageHelper::query_storage::<TokenConfig>(
+            &deps.querier,
+            &collection_addr,
+            CONFIG_NAMESPACE,
+        )?;
+        match token_config {
+            Some(token_config) if token_config.creator == info.sender => {}
+            _ => privilege_check?,
+        }
+    };
+
+    Ok(())
+}
+
+fn check_collection_archived(
+    storage: &dyn Storage,
+    collection_id: u32,
+) -> Result<(), ContractError> {
+    if ARCHIVED_COLLECTIONS.has(storage, collection_id) {
+        return Err(ContractError::CollectionArchived {});
+    }
+    Ok(())
+}
+
+// Loads the collection address from the whitelisted or blacklisted collections
+fn may_load_collection_addr(storage: &dyn Storage, collection_id: u32) -> StdResult<Option<Addr>> {
+    match COLLECTION_ADDRS.may_load(storage, collection_id)? {
+        Some(collection_addr) => Ok(Some(collection_addr)),
+        None => BLACKLIST_COLLECTION_ADDRS.may_load(storage, collection_id),
+    }
+}
+
 fn check_collection_ids_exists(
     deps: &DepsMut,
     collection_ids: &Vec<u32>,
@@ -900,7 +2841,7 @@ fn check_collection_ids_exists(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::CollectionAddress { collection_id } => {
@@ -920,6 +2861,63 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         } => to_binary(&query_collections(deps, blacklist, start_after, limit)?),
         QueryMsg::Creators {} => to_binary(&query_creators(deps)?),
         QueryMsg::MintLock { collection_id } => to_binary(&query_mint_lock(deps, collection_id)?),
+        QueryMsg::Phases { collection_id } => to_binary(&query_phases(deps, collection_id)?),
+        QueryMsg::CurrentMintPrice { collection_id } => {
+            to_binary(&query_current_mint_price(deps, env, collection_id)?)
+        }
+        QueryMsg::DutchAuction { collection_id } => {
+            to_binary(&query_dutch_auction(deps, collection_id)?)
+        }
+        QueryMsg::BondingCurve { collection_id } => {
+            to_binary(&query_bonding_curve(deps, collection_id)?)
+        }
+        QueryMsg::BondingCurveReserve { collection_id } => {
+            to_binary(&query_bonding_curve_reserve(deps, collection_id)?)
+        }
+        QueryMsg::PendingMintBatch { collection_id } => {
+            to_binary(&query_pending_mint_batch(deps, collection_id)?)
+        }
+        QueryMsg::MetadataShuffle { collection_id } => {
+            to_binary(&query_metadata_shuffle(deps, collection_id)?)
+        }
+        QueryMsg::ShuffledMint {
+            collection_id,
+            address,
+        } => to_binary(&query_shuffled_mint(deps, collection_id, address)?),
+        QueryMsg::VerifyProvenance {
+            collection_id,
+            start_after,
+            provenance_hash,
+            limit,
+        } => to_binary(&query_verify_provenance(
+            deps,
+            collection_id,
+            start_after,
+            provenance_hash,
+            limit,
+        )?),
+        QueryMsg::VoucherKey { collection_id } => {
+            to_binary(&query_voucher_key(deps, collection_id)?)
+        }
+        QueryMsg::VoucherNonceUsed {
+            collection_id,
+            nonce,
+        } => to_binary(&query_voucher_nonce_used(deps, collection_id, nonce)?),
+        QueryMsg::CreatorCollectionLimit {} => to_binary(&query_creator_collection_limit(deps)?),
+        QueryMsg::CreationFeeFundInfo {} => to_binary(&query_creation_fee_fund_info(deps)?),
+        QueryMsg::CreatorCollections { address } => {
+            to_binary(&query_creator_collections(deps, address)?)
+        }
+        QueryMsg::PendingCollections { start_after, limit } => {
+            to_binary(&query_pending_collections(deps, start_after, limit)?)
+        }
+        QueryMsg::ArchivedCollections { start_after, limit } => {
+            to_binary(&query_archived_collections(deps, start_after, limit)?)
+        }
+        QueryMsg::FeeQuote {
+            collection_id,
+            minter,
+        } => to_binary(&query_fee_quote(deps, env, collection_id, minter)?),
     }
 }
 
@@ -981,6 +2979,10 @@ fn query_collections(
 
     let collections = collections_state
         .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((collection_id, _)) => !ARCHIVED_COLLECTIONS.has(deps.storage, *collection_id),
+            Err(_) => true,
+        })
         .take(limit)
         .map(|item| {
             let (collection_id, address) = item.unwrap();
@@ -1008,6 +3010,295 @@ fn query_mint_lock(deps: Deps, collection_id: u32) -> StdResult<ResponseWrapper<
     Ok(ResponseWrapper::new("mint_lock", mint_lock))
 }
 
+fn query_phases(deps: Deps, collection_id: u32) -> StdResult<ResponseWrapper<Vec<MintPhase>>> {
+    let phases = MINT_PHASES
+        .may_load(deps.storage, collection_id)?
+        .unwrap_or_default();
+    Ok(ResponseWrapper::new("phases", phases))
+}
+
+fn query_current_mint_price(
+    deps: Deps,
+    env: Env,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Uint128>> {
+    let price = match (
+        MINT_PHASES.may_load(deps.storage, collection_id)?,
+        DUTCH_AUCTIONS.may_load(deps.storage, collection_id)?,
+        BONDING_CURVES.may_load(deps.storage, collection_id)?,
+    ) {
+        (Some(phases), _, _) => match active_mint_phase(phases, env.block.time) {
+            Some(phase) => phase.price,
+            None => return Err(StdError::generic_err("No active mint phase")),
+        },
+        (None, Some(dutch_auction), _) => dutch_auction_price(&dutch_auction, env.block.time),
+        (None, None, Some(bonding_curve)) => {
+            let supply = bonding_curve_supply(deps, collection_id)?;
+            bonding_curve_price(&bonding_curve.curve, supply)?
+        }
+        (None, None, None) => {
+            let hub_addr = HUB_ADDR.load(deps.storage)?;
+            StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string())
+                .and_then(|fee_module_addr| {
+                    StorageHelper::query_fixed_fee(
+                        &deps.querier,
+                        &fee_module_addr,
+                        Modules::Mint.to_string(),
+                        MintFees::new_price(collection_id),
+                        env.block.time,
+                    )
+                })
+                .map(|fixed_fee_response| fixed_fee_response.value)
+                .unwrap_or_default()
+        }
+    };
+    Ok(ResponseWrapper::new("current_mint_price", price))
+}
+
+fn query_fee_quote(
+    deps: Deps,
+    env: Env,
+    collection_id: u32,
+    minter: Option<String>,
+) -> StdResult<ResponseWrapper<FeeQuoteResponse>> {
+    let hub_addr = HUB_ADDR.load(deps.storage)?;
+    let mut price = query_current_mint_price(deps, env, collection_id)?.data;
+
+    // Holder discounts only apply to the standard mint price
+    let is_standard_price = !MINT_PHASES.has(deps.storage, collection_id)
+        && !DUTCH_AUCTIONS.has(deps.storage, collection_id)
+        && !BONDING_CURVES.has(deps.storage, collection_id);
+    if let (Some(minter), true) = (&minter, is_standard_price) {
+        let fee_module_addr =
+            StorageHelper::query_module_address(&deps.querier, &hub_addr, Modules::Fee.to_string());
+        if let Ok(fee_module_addr) = fee_module_addr {
+            let discount = KompleFeeModule(fee_module_addr).query_discount(
+                &deps.querier,
+                Modules::Mint.as_str(),
+                &MintFees::new_price(collection_id),
+                minter,
+            )?;
+            price = price.mul(Decimal::one() - discount);
+        }
+    }
+
+    let mut platform_fee = Uint128::zero();
+    let res =
+        StorageHelper::query_storage::<Addr>(&deps.querier, &hub_addr, MARBU_FEE_MODULE_NAMESPACE)?;
+    if let Some(marbu_fee_module) = res {
+        let marbu_fee_module = KompleFeeModule(marbu_fee_module);
+        let fee_percentage = match &minter {
+            Some(minter) => marbu_fee_module.query_total_discounted_percentage_fees(
+                &deps.querier,
+                Modules::Mint.as_str(),
+                minter,
+            )?,
+            None => marbu_fee_module
+                .query_total_percentage_fees(&deps.querier, Modules::Mint.as_str())?,
+        };
+        platform_fee = fee_percentage.mul(price);
+    };
+
+    let payout = price.checked_sub(platform_fee)?;
+
+    Ok(ResponseWrapper::new(
+        "fee_quote",
+        FeeQuoteResponse {
+            price,
+            platform_fee,
+            payout,
+        },
+    ))
+}
+
+fn query_dutch_auction(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Option<DutchAuction>>> {
+    let dutch_auction = DUTCH_AUCTIONS.may_load(deps.storage, collection_id)?;
+    Ok(ResponseWrapper::new("dutch_auction", dutch_auction))
+}
+
+fn query_bonding_curve(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Option<BondingCurve>>> {
+    let bonding_curve = BONDING_CURVES.may_load(deps.storage, collection_id)?;
+    Ok(ResponseWrapper::new("bonding_curve", bonding_curve))
+}
+
+fn query_bonding_curve_reserve(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Uint128>> {
+    let reserve = BONDING_CURVE_RESERVES
+        .may_load(deps.storage, collection_id)?
+        .unwrap_or_default();
+    Ok(ResponseWrapper::new("bonding_curve_reserve", reserve))
+}
+
+fn query_pending_mint_batch(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Option<MintBatch>>> {
+    let mint_batch = MINT_BATCHES.may_load(deps.storage, collection_id)?;
+    Ok(ResponseWrapper::new("pending_mint_batch", mint_batch))
+}
+
+fn query_metadata_shuffle(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Option<MetadataShuffle>>> {
+    let metadata_shuffle = METADATA_SHUFFLES.may_load(deps.storage, collection_id)?;
+    Ok(ResponseWrapper::new("metadata_shuffle", metadata_shuffle))
+}
+
+fn query_shuffled_mint(
+    deps: Deps,
+    collection_id: u32,
+    address: String,
+) -> StdResult<ResponseWrapper<Option<ShuffledMint>>> {
+    let addr = deps.api.addr_validate(&address)?;
+    let shuffled_mint = SHUFFLED_MINTS.may_load(deps.storage, (collection_id, &addr))?;
+    Ok(ResponseWrapper::new("shuffled_mint", shuffled_mint))
+}
+
+fn query_verify_provenance(
+    deps: Deps,
+    collection_id: u32,
+    start_after: Option<u32>,
+    provenance_hash: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ResponseWrapper<ProvenanceResponse>> {
+    let metadata_shuffle = METADATA_SHUFFLES.load(deps.storage, collection_id)?;
+
+    let collection_addr = COLLECTION_ADDRS.load(deps.storage, collection_id)?;
+    let sub_modules = StorageHelper::query_token_sub_modules(&deps.querier, &collection_addr)?;
+    let metadata_addr = sub_modules.metadata.ok_or(StdError::NotFound {
+        kind: "Metadata module".to_string(),
+    })?;
+
+    let limit = limit
+        .unwrap_or(PROVENANCE_PAGE_LIMIT)
+        .min(PROVENANCE_PAGE_LIMIT);
+    let start = start_after.unwrap_or(0);
+    let end = start.saturating_add(limit).min(metadata_shuffle.pool_size);
+
+    let mut provenance_hash = provenance_hash.unwrap_or_default();
+    for metadata_id in (start + 1)..=end {
+        let raw_metadata =
+            StorageHelper::query_raw_metadata_bytes(&deps.querier, &metadata_addr, metadata_id)?
+                .unwrap_or_default();
+
+        let mut hasher = Sha256::new();
+        hasher.update(
+            hex::decode(&provenance_hash).map_err(|err| StdError::generic_err(err.to_string()))?,
+        );
+        hasher.update(Sha256::digest(raw_metadata.as_slice()));
+        provenance_hash = hex::encode(hasher.finalize());
+    }
+
+    let verified = match end >= metadata_shuffle.pool_size {
+        true => Some(provenance_hash == metadata_shuffle.provenance_hash),
+        false => None,
+    };
+    Ok(ResponseWrapper::new(
+        "verify_provenance",
+        ProvenanceResponse {
+            metadata_id: end,
+            provenance_hash,
+            verified,
+        },
+    ))
+}
+
+fn query_voucher_key(
+    deps: Deps,
+    collection_id: u32,
+) -> StdResult<ResponseWrapper<Option<VoucherKey>>> {
+    let voucher_key = VOUCHER_KEYS.may_load(deps.storage, collection_id)?;
+    Ok(ResponseWrapper::new("voucher_key", voucher_key))
+}
+
+fn query_voucher_nonce_used(
+    deps: Deps,
+    collection_id: u32,
+    nonce: u64,
+) -> StdResult<ResponseWrapper<bool>> {
+    let is_used = VOUCHER_NONCES.has(deps.storage, (collection_id, nonce));
+    Ok(ResponseWrapper::new("voucher_nonce_used", is_used))
+}
+
+fn query_creator_collection_limit(deps: Deps) -> StdResult<ResponseWrapper<Option<u32>>> {
+    let limit = CREATOR_COLLECTION_LIMIT.may_load(deps.storage)?;
+    Ok(ResponseWrapper::new("creator_collection_limit", limit))
+}
+
+fn query_creation_fee_fund_info(deps: Deps) -> StdResult<ResponseWrapper<Option<FundInfo>>> {
+    let fund_info = CREATION_FEE_FUND_INFO.may_load(deps.storage)?;
+    Ok(ResponseWrapper::new("creation_fee_fund_info", fund_info))
+}
+
+fn query_creator_collections(deps: Deps, address: String) -> StdResult<ResponseWrapper<u32>> {
+    let addr = deps.api.addr_validate(&address)?;
+    let count = CREATOR_COLLECTIONS
+        .may_load(deps.storage, &addr)?
+        .unwrap_or(0);
+    Ok(ResponseWrapper::new("creator_collections", count))
+}
+
+fn query_pending_collections(
+    deps: Deps,
+    start_after: Option<u32>,
+    limit: Option<u8>,
+) -> StdResult<ResponseWrapper<Vec<CollectionsResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let collections = PENDING_COLLECTIONS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|collection_id| {
+            let collection_id = collection_id?;
+            let address = BLACKLIST_COLLECTION_ADDRS.load(deps.storage, collection_id)?;
+            Ok(CollectionsResponse {
+                collection_id,
+                address: address.to_string(),
+            })
+        })
+        .collect::<StdResult<Vec<CollectionsResponse>>>()?;
+
+    Ok(ResponseWrapper::new("pending_collections", collections))
+}
+
+fn query_archived_collections(
+    deps: Deps,
+    start_after: Option<u32>,
+    limit: Option<u8>,
+) -> StdResult<ResponseWrapper<Vec<CollectionsResponse>>> {
+    let limit = limit.unwrap_or(30) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let collections = ARCHIVED_COLLECTIONS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|collection_id| {
+            let collection_id = collection_id?;
+            let address = may_load_collection_addr(deps.storage, collection_id)?.ok_or(
+                StdError::NotFound {
+                    kind: "Collection".to_string(),
+                },
+            )?;
+            Ok(CollectionsResponse {
+                collection_id,
+                address: address.to_string(),
+            })
+        })
+        .collect::<StdResult<Vec<CollectionsResponse>>>()?;
+
+    Ok(ResponseWrapper::new("archived_collections", collections))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     if msg.id != TOKEN_INSTANTIATE_REPLY_ID {
@@ -1018,11 +3309,16 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     match reply {
         Ok(res) => {
             let collection_id = COLLECTION_ID.load(deps.storage)?;
-            COLLECTION_ADDRS.save(
-                deps.storage,
-                collection_id,
-                &Addr::unchecked(res.contract_address),
-            )?;
+            let collection_addr = Addr::unchecked(res.contract_address);
+            // Public collections are blacklisted until they are whitelisted
+            match PENDING_COLLECTIONS.has(deps.storage, collection_id) {
+                true => BLACKLIST_COLLECTION_ADDRS.save(
+                    deps.storage,
+                    collection_id,
+                    &collection_addr,
+                )?,
+                false => COLLECTION_ADDRS.save(deps.storage, collection_id, &collection_addr)?,
+            };
             Ok(Response::default().add_attribute("action", "instantiate_token_reply"))
         }
         Err(_) => Err(ContractError::TokenInstantiateError {}),
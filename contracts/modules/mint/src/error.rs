@@ -40,6 +40,93 @@ pub enum ContractError {
     #[error("Whitelist price is not set")]
     WhitelistPriceNotSet {},
 
+    #[error("Invalid mint phases")]
+    InvalidMintPhases {},
+
+    #[error("No active mint phase")]
+    NoActivePhase {},
+
+    #[error("Address is not eligible for the mint phase")]
+    NotEligible {},
+
+    #[error("Mint phase per address limit reached")]
+    PhaseAddressLimitReached {},
+
+    #[error("Mint phase token limit reached")]
+    PhaseTokenLimitReached {},
+
+    #[error("Invalid mint quantity")]
+    InvalidQuantity {},
+
+    #[error("No pending mint batch")]
+    NoPendingMintBatch {},
+
+    #[error("Collection has a pending mint batch")]
+    PendingMintBatch {},
+
+    #[error("Invalid dutch auction")]
+    InvalidDutchAuction {},
+
+    #[error("Dutch auction has already started")]
+    DutchAuctionStarted {},
+
+    #[error("Dutch auction has not ended")]
+    DutchAuctionNotEnded {},
+
+    #[error("No rebate to claim")]
+    NoRebate {},
+
+    #[error("Dutch auction clearing price not found")]
+    ClearingPriceNotFound {},
+
+    #[error("No dutch auction revenue to settle")]
+    NoUnsettledRevenue {},
+
+    #[error("Invalid bonding curve")]
+    InvalidBondingCurve {},
+
+    #[error("Bonding curve reserve is not empty")]
+    ReserveNotEmpty {},
+
+    #[error("Selling back is not enabled")]
+    SellBackDisabled {},
+
+    #[error("Invalid metadata shuffle")]
+    InvalidMetadataShuffle {},
+
+    #[error("No shuffled mint to draw")]
+    NoShuffledMint {},
+
+    #[error("Seed does not match the seed commitment")]
+    InvalidShuffleSeed {},
+
+    #[error("Metadata id cannot be chosen for shuffled collections")]
+    MetadataIdNotAllowed {},
+
+    #[error("Metadata pool is empty")]
+    MetadataPoolEmpty {},
+
+    #[error("Invalid voucher key")]
+    InvalidVoucherKey {},
+
+    #[error("Invalid mint voucher")]
+    InvalidVoucher {},
+
+    #[error("Mint voucher has expired")]
+    VoucherExpired {},
+
+    #[error("Mint voucher is already used")]
+    VoucherUsed {},
+
+    #[error("Creator collection limit is reached")]
+    CreatorCollectionLimitReached {},
+
+    #[error("Creation fee fund info is not found")]
+    CreationFeeFundInfoNotFound {},
+
+    #[error("Collection is archived")]
+    CollectionArchived {},
+
     #[error("{0}")]
     Util(#[from] UtilError),
 
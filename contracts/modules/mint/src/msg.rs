@@ -1,11 +1,15 @@
-use crate::state::{CollectionInfo, Config};
+use crate::state::{
+    BondingCurve, CollectionInfo, Config, DutchAuction, Eligibility, MetadataShuffle, MintBatch,
+    MintPhase, ShuffledMint, VoucherKey,
+};
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Timestamp, Uint128};
 use cw20::Cw20ReceiveMsg;
 use komple_framework_token_module::{
     msg::{MetadataInfo, TokenInfo},
     state::CollectionConfig,
 };
+use komple_framework_types::modules::fee::{FundInfo, RevenueShare};
 use komple_framework_types::shared::execute::SharedExecuteMsg;
 use komple_framework_types::shared::query::ResponseWrapper;
 
@@ -16,12 +20,28 @@ pub struct CollectionFundInfo {
     pub cw20_address: Option<String>,
 }
 
+/// Provenance commitment of a shuffled collection.
+///
+/// Provenance hash is the hex encoded sha256 hash chain
+/// over the first `pool_size` raw metadata entries.
+///
+/// Seed commitment is the hex encoded sha256 hash of the first seed
+/// revealed with `RevealShuffleSeed`.
+#[cw_serde]
+pub struct MetadataShuffleInfo {
+    pub provenance_hash: String,
+    pub pool_size: u32,
+    pub seed_commitment: String,
+}
+
 #[cw_serde]
 pub enum ExecuteMsg {
     /// Admin and public message.
     ///
     /// Create a new collection.
     /// This can be executed by both admin and users based on configuration.
+    /// Public collections pay the creation fee and are blacklisted until whitelisted by the admin.
+    /// Metadata shuffle commits the provenance hash of a shuffled collection.
     CreateCollection {
         code_id: u64,
         collection_info: CollectionInfo,
@@ -30,6 +50,7 @@ pub enum ExecuteMsg {
         metadata_info: MetadataInfo,
         fund_info: CollectionFundInfo,
         linked_collections: Option<Vec<u32>>,
+        metadata_shuffle: Option<MetadataShuffleInfo>,
     },
     /// Admin message.
     ///
@@ -38,6 +59,33 @@ pub enum ExecuteMsg {
     UpdatePublicCollectionCreation {
         public_collection_creation: bool,
     },
+    /// Admin and creator message.
+    ///
+    /// Update the general information of a collection.
+    /// Collection type cannot be changed.
+    UpdateCollectionInfo {
+        collection_id: u32,
+        collection_info: CollectionInfoMsg,
+    },
+    /// Admin and creator message.
+    ///
+    /// Archive a collection.
+    /// Archived collections cannot be minted or updated
+    /// and are not listed in the collections query.
+    /// Bonding curve sell backs, Dutch auction rebate claims and settlements,
+    /// and shuffled mint reveals stay open.
+    ArchiveCollection {
+        collection_id: u32,
+    },
+    /// Admin and creator message.
+    ///
+    /// Replace the revenue split of a collection on the fee module.
+    /// Shares must add up to exactly one.
+    /// Sending an empty list removes the split.
+    UpdateRevenueSplit {
+        collection_id: u32,
+        revenue_split: Vec<RevenueShare>,
+    },
     /// Admin message.
     ///
     /// Update the configuration for collection mint lock.
@@ -47,11 +95,34 @@ pub enum ExecuteMsg {
     },
     /// Public message.
     ///
-    /// Mint a new token on a collection.
-    /// Additional metadata id can be provided to link to a certain metadata.
+    /// Mint new tokens on a collection.
+    /// Price and limits are checked for the whole quantity.
+    /// Additional metadata ids can be provided to link to certain metadata,
+    /// one for each minted token.
     Mint {
         collection_id: u32,
-        metadata_id: Option<u32>,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+    },
+    /// Public message.
+    ///
+    /// Same as `Mint` message but with a merkle proof
+    /// for mint phases with a merkle root eligibility.
+    MintWithProof {
+        collection_id: u32,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+        proof: Vec<String>,
+    },
+    /// Public message.
+    ///
+    /// Mint with a voucher signed by the collection voucher key.
+    /// Voucher price is used instead of the mint phases and public prices.
+    MintWithVoucher {
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+        voucher: MintVoucher,
+        signature: Binary,
     },
     /// Admin message.
     ///
@@ -63,6 +134,32 @@ pub enum ExecuteMsg {
     },
     /// Admin message.
     ///
+    /// Mint tokens to many recipients with optional metadata ids.
+    /// At most 50 recipients are minted in a transaction and the rest
+    /// are saved to be minted with `ResumeAdminMintBatch` message.
+    /// A new batch cannot be started while the collection has a pending batch.
+    AdminMintBatch {
+        collection_id: u32,
+        recipients: Vec<(String, Option<u32>)>,
+    },
+    /// Admin message.
+    ///
+    /// Mint the next recipients of the pending admin mint batch.
+    /// Limit lowers the number of recipients minted in the transaction
+    /// for batches that do not fit in the gas limit.
+    ResumeAdminMintBatch {
+        collection_id: u32,
+        limit: Option<u32>,
+    },
+    /// Admin message.
+    ///
+    /// Cancel the pending admin mint batch of a collection.
+    /// Recipients that are not minted yet are dropped.
+    CancelAdminMintBatch {
+        collection_id: u32,
+    },
+    /// Admin message.
+    ///
     /// Same as `Mint` message but can be used with permissions.
     PermissionMint {
         permission_msg: Binary,
@@ -99,9 +196,115 @@ pub enum ExecuteMsg {
     UpdateCreators {
         addrs: Vec<String>,
     },
+    /// Admin message.
+    ///
+    /// Set or remove the number of collections an address
+    /// can create with public collection creation.
+    UpdateCreatorCollectionLimit {
+        limit: Option<u32>,
+    },
+    /// Admin message.
+    ///
+    /// Set or remove the native or cw20 token accepted
+    /// for the public collection creation fee.
+    UpdateCreationFeeFundInfo {
+        fund_info: Option<CollectionFundInfo>,
+    },
+    /// Admin message.
+    ///
+    /// Replace the mint phases of a collection.
+    /// Phases must be ordered by start time and cannot overlap.
+    /// Sending an empty list removes the phases.
+    UpdateMintPhases {
+        collection_id: u32,
+        phases: Vec<MintPhaseMsg>,
+    },
+    /// Admin message.
+    ///
+    /// Set or remove the dutch auction of a collection.
+    /// Cannot be changed while the auction is running
+    /// or after it has started in rebate mode.
+    UpdateDutchAuction {
+        collection_id: u32,
+        dutch_auction: Option<DutchAuction>,
+    },
+    /// Public message.
+    ///
+    /// Claim the dutch auction rebate of an address after the auction ends.
+    /// Rebate is sent to the address, so this can be executed by anyone.
+    ClaimDutchAuctionRebate {
+        collection_id: u32,
+        address: Option<String>,
+    },
+    /// Admin and creator message.
+    ///
+    /// Pay out the dutch auction revenue above the resting price
+    /// for all the tokens minted in rebate mode after the auction ends.
+    /// Revenue is settled once, independent of the rebate claims.
+    SettleDutchAuction {
+        collection_id: u32,
+    },
+    /// Admin message.
+    ///
+    /// Set or remove the bonding curve of a collection.
+    /// Cannot be changed while the reserve holds funds.
+    UpdateBondingCurve {
+        collection_id: u32,
+        bonding_curve: Option<BondingCurve>,
+    },
+    /// Public message.
+    ///
+    /// Burn a token and get the reserve share of its curve price back.
+    /// Mint module must be approved for the token.
+    SellBack {
+        collection_id: u32,
+        token_id: u32,
+    },
+    /// Admin and creator message.
+    ///
+    /// Reveal the committed seed and draw the shuffled mints made before this block.
+    /// Sha256 hash of the seed must match the seed commitment,
+    /// and the revealed seed becomes the commitment of the next reveal.
+    /// Shuffled mints are drawn in address order up to the limit.
+    RevealShuffleSeed {
+        collection_id: u32,
+        seed: String,
+        limit: Option<u32>,
+    },
+    /// Admin message.
+    ///
+    /// Set or remove the public key that signs the mint vouchers of a collection.
+    UpdateVoucherKey {
+        collection_id: u32,
+        voucher_key: Option<VoucherKey>,
+    },
     Receive(Cw20ReceiveMsg),
 }
 
+/// Message used to define a mint phase.
+///
+/// Collection fund info is used if fund info is not provided.
+#[cw_serde]
+pub struct MintPhaseMsg {
+    pub name: String,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub price: Uint128,
+    pub fund_info: Option<CollectionFundInfo>,
+    pub per_address_limit: Option<u32>,
+    pub max_token_limit: Option<u32>,
+    pub eligibility: Option<Eligibility>,
+}
+
+/// Message used to update the general information of a collection.
+#[cw_serde]
+pub struct CollectionInfoMsg {
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub external_link: Option<String>,
+}
+
 impl From<ExecuteMsg> for SharedExecuteMsg {
     fn from(msg: ExecuteMsg) -> Self {
         match msg {
@@ -111,11 +314,40 @@ impl From<ExecuteMsg> for SharedExecuteMsg {
     }
 }
 
+/// Message used to create a collection with a cw20 creation fee.
+///
+/// Fields are the same as the create collection execute message.
+#[cw_serde]
+pub struct CreateCollectionMsg {
+    pub code_id: u64,
+    pub collection_info: CollectionInfo,
+    pub collection_config: CollectionConfig,
+    pub token_info: TokenInfo,
+    pub metadata_info: MetadataInfo,
+    pub fund_info: CollectionFundInfo,
+    pub linked_collections: Option<Vec<u32>>,
+    pub metadata_shuffle: Option<MetadataShuffleInfo>,
+}
+
 #[cw_serde]
 pub enum ReceiveMsg {
+    CreateCollection(Box<CreateCollectionMsg>),
     Mint {
         collection_id: u32,
-        metadata_id: Option<u32>,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+    },
+    MintWithProof {
+        collection_id: u32,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+        proof: Vec<String>,
+    },
+    MintWithVoucher {
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+        voucher: MintVoucher,
+        signature: Binary,
     },
 }
 
@@ -138,6 +370,8 @@ pub enum QueryMsg {
     #[returns(ResponseWrapper<Vec<u32>>)]
     LinkedCollections { collection_id: u32 },
     /// List the collections with pagination.
+    ///
+    /// Archived collections are not listed.
     #[returns(ResponseWrapper<Vec<CollectionsResponse>>)]
     Collections {
         blacklist: bool,
@@ -150,6 +384,76 @@ pub enum QueryMsg {
     /// Get the mint lock for collection
     #[returns(ResponseWrapper<bool>)]
     MintLock { collection_id: u32 },
+    /// Get the mint phases of a collection.
+    #[returns(ResponseWrapper<Vec<MintPhase>>)]
+    Phases { collection_id: u32 },
+    /// Get the current public mint price of a collection before discounts.
+    ///
+    /// Price of the active phase is returned if the collection has phases.
+    #[returns(ResponseWrapper<Uint128>)]
+    CurrentMintPrice { collection_id: u32 },
+    /// Get the dutch auction of a collection.
+    #[returns(ResponseWrapper<Option<DutchAuction>>)]
+    DutchAuction { collection_id: u32 },
+    /// Get the bonding curve of a collection.
+    #[returns(ResponseWrapper<Option<BondingCurve>>)]
+    BondingCurve { collection_id: u32 },
+    /// Get the bonding curve reserve of a collection.
+    #[returns(ResponseWrapper<Uint128>)]
+    BondingCurveReserve { collection_id: u32 },
+    /// Get the pending admin mint batch of a collection.
+    #[returns(ResponseWrapper<Option<MintBatch>>)]
+    PendingMintBatch { collection_id: u32 },
+    /// Get the metadata shuffle of a collection.
+    #[returns(ResponseWrapper<Option<MetadataShuffle>>)]
+    MetadataShuffle { collection_id: u32 },
+    /// Get the shuffled mint of an address waiting for the seed reveal.
+    #[returns(ResponseWrapper<Option<ShuffledMint>>)]
+    ShuffledMint { collection_id: u32, address: String },
+    /// Check the committed provenance hash against the current raw metadata.
+    /// Raw metadata is hashed in pages, the returned metadata id and hash continue the check.
+    #[returns(ResponseWrapper<ProvenanceResponse>)]
+    VerifyProvenance {
+        collection_id: u32,
+        start_after: Option<u32>,
+        provenance_hash: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Get the voucher key of a collection.
+    #[returns(ResponseWrapper<Option<VoucherKey>>)]
+    VoucherKey { collection_id: u32 },
+    /// Check if a voucher nonce is used for a collection.
+    #[returns(ResponseWrapper<bool>)]
+    VoucherNonceUsed { collection_id: u32, nonce: u64 },
+    /// Get the number of collections an address can create with public collection creation.
+    #[returns(ResponseWrapper<Option<u32>>)]
+    CreatorCollectionLimit {},
+    /// Get the native or cw20 token accepted for the public collection creation fee.
+    #[returns(ResponseWrapper<Option<FundInfo>>)]
+    CreationFeeFundInfo {},
+    /// Get the number of collections created by an address with public collection creation.
+    #[returns(ResponseWrapper<u32>)]
+    CreatorCollections { address: String },
+    /// List the public collections waiting to be whitelisted with pagination.
+    #[returns(ResponseWrapper<Vec<CollectionsResponse>>)]
+    PendingCollections {
+        start_after: Option<u32>,
+        limit: Option<u8>,
+    },
+    /// List the archived collections with pagination.
+    #[returns(ResponseWrapper<Vec<CollectionsResponse>>)]
+    ArchivedCollections {
+        start_after: Option<u32>,
+        limit: Option<u8>,
+    },
+    /// Get the platform fee of a mint at the current public mint price.
+    ///
+    /// Holder discounts of the minter are applied if the minter is given.
+    #[returns(ResponseWrapper<FeeQuoteResponse>)]
+    FeeQuote {
+        collection_id: u32,
+        minter: Option<String>,
+    },
 }
 
 /// Message used to mint new tokens on a collection.
@@ -160,9 +464,44 @@ pub struct MintMsg {
     pub metadata_id: Option<u32>,
 }
 
+/// Off-chain signed permission to mint on a collection.
+///
+/// The signed message is the sha256 of the mint module address
+/// followed by the JSON encoded voucher.
+/// Price is the price of a single token in the collection funds.
+#[cw_serde]
+pub struct MintVoucher {
+    pub recipient: String,
+    pub collection_id: u32,
+    pub max_quantity: u32,
+    pub price: Uint128,
+    pub expiry: Timestamp,
+    pub nonce: u64,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
 
+/// The fees of a single mint.
+///
+/// Platform fees are defined on the Marbu fee module.
+/// Payout is the amount left for the collection revenue.
+#[cw_serde]
+pub struct ProvenanceResponse {
+    /// Last raw metadata id in the hash.
+    pub metadata_id: u32,
+    pub provenance_hash: String,
+    /// Set after the last raw metadata of the pool is hashed.
+    pub verified: Option<bool>,
+}
+
+#[cw_serde]
+pub struct FeeQuoteResponse {
+    pub price: Uint128,
+    pub platform_fee: Uint128,
+    pub payout: Uint128,
+}
+
 #[cw_serde]
 pub struct CollectionsResponse {
     pub collection_id: u32,
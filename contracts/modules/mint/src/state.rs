@@ -3,13 +3,21 @@ use komple_framework_types::shared::{
     CONFIG_NAMESPACE, EXECUTE_LOCK_NAMESPACE, OPERATORS_NAMESPACE, PARENT_ADDR_NAMESPACE,
 };
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 use komple_framework_types::modules::fee::{FundInfo, FUND_INFO_NAMESPACE};
 use komple_framework_types::modules::mint::{
-    Collections, BLACKLIST_COLLECTION_ADDRS_NAMESPACE, COLLECTION_ADDRS_NAMESPACE,
-    COLLECTION_ID_NAMESPACE, COLLECTION_INFO_NAMESPACE, CREATORS_NAMESPACE,
-    LINKED_COLLECTIONS_NAMESPACE, MINT_LOCKS_NAMESPACE,
+    Collections, ARCHIVED_COLLECTIONS_NAMESPACE, BLACKLIST_COLLECTION_ADDRS_NAMESPACE,
+    BONDING_CURVES_NAMESPACE, BONDING_CURVE_RESERVES_NAMESPACE, BONDING_CURVE_SUPPLIES_NAMESPACE,
+    COLLECTION_ADDRS_NAMESPACE, COLLECTION_ID_NAMESPACE, COLLECTION_INFO_NAMESPACE,
+    CREATION_FEE_FUND_INFO_NAMESPACE, CREATORS_NAMESPACE, CREATOR_COLLECTIONS_NAMESPACE,
+    CREATOR_COLLECTION_LIMIT_NAMESPACE, DUTCH_AUCTIONS_NAMESPACE,
+    DUTCH_AUCTION_CLEARING_PRICES_NAMESPACE, DUTCH_AUCTION_MINTS_NAMESPACE,
+    DUTCH_AUCTION_UNSETTLED_NAMESPACE, LINKED_COLLECTIONS_NAMESPACE, METADATA_SHUFFLES_NAMESPACE,
+    METADATA_SHUFFLE_POOL_NAMESPACE, MINT_BATCHES_NAMESPACE, MINT_LOCKS_NAMESPACE,
+    MINT_PHASES_NAMESPACE, PENDING_COLLECTIONS_NAMESPACE, PHASE_ADDR_MINTS_NAMESPACE,
+    PHASE_MINTS_NAMESPACE, SHUFFLED_MINTS_NAMESPACE, VOUCHER_KEYS_NAMESPACE,
+    VOUCHER_NONCES_NAMESPACE,
 };
 
 #[cw_serde]
@@ -68,3 +76,227 @@ pub const MINT_LOCKS: Map<u32, bool> = Map::new(MINT_LOCKS_NAMESPACE);
 
 /// Map of collection ids to fund infos.
 pub const COLLECTION_FUND_INFO: Map<u32, FundInfo> = Map::new(FUND_INFO_NAMESPACE);
+
+/// The requirement for minting in a phase.
+#[cw_serde]
+pub enum Eligibility {
+    /// Address is a member of the whitelist contract.
+    Whitelist { address: String },
+    /// Address is a leaf of the merkle tree.
+    ///
+    /// Leaves are sha256 hashes of the addresses and pairs are hashed in sorted order.
+    MerkleRoot { root: String },
+    /// Address holds a token of the collection.
+    Holder { collection_id: u32 },
+}
+
+/// A mint phase of a collection.
+///
+/// The phase is active from its start time until its end time
+/// or until the start time of the next phase.
+#[cw_serde]
+pub struct MintPhase {
+    pub name: String,
+    pub start_time: Timestamp,
+    pub end_time: Option<Timestamp>,
+    pub price: Uint128,
+    pub fund_info: FundInfo,
+    pub per_address_limit: Option<u32>,
+    pub max_token_limit: Option<u32>,
+    pub eligibility: Option<Eligibility>,
+}
+
+/// Map of collection ids to mint phases ordered by start time.
+///
+/// Collections without phases use the fee module prices and the whitelist.
+pub const MINT_PHASES: Map<u32, Vec<MintPhase>> = Map::new(MINT_PHASES_NAMESPACE);
+
+/// The number of tokens minted in a phase.
+///
+/// Collection id and phase name are used as the key.
+pub const PHASE_MINTS: Map<(u32, &str), u32> = Map::new(PHASE_MINTS_NAMESPACE);
+
+/// The number of tokens minted by an address in a phase.
+///
+/// Collection id, phase name and address are used as the key.
+pub const PHASE_ADDR_MINTS: Map<(u32, &str, &Addr), u32> = Map::new(PHASE_ADDR_MINTS_NAMESPACE);
+
+/// An admin mint batch that is split across transactions.
+///
+/// Recipients before the cursor are already minted.
+#[cw_serde]
+pub struct MintBatch {
+    pub recipients: Vec<(Addr, Option<u32>)>,
+    pub cursor: u32,
+}
+
+/// Map of collection ids to pending admin mint batches.
+pub const MINT_BATCHES: Map<u32, MintBatch> = Map::new(MINT_BATCHES_NAMESPACE);
+
+/// A dutch auction for the public mint price of a collection.
+///
+/// The price drops from the start price to the resting price
+/// in equal steps between the start and end times.
+/// In rebate mode the price above the resting price is kept
+/// until the auction ends and the difference to the clearing price
+/// is refunded to the minters.
+#[cw_serde]
+pub struct DutchAuction {
+    pub start_price: Uint128,
+    pub resting_price: Uint128,
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    /// Seconds between the price drops.
+    pub step_interval: u64,
+    pub rebate: bool,
+}
+
+/// Map of collection ids to dutch auctions.
+///
+/// Dutch auction price is used instead of the fee module price.
+pub const DUTCH_AUCTIONS: Map<u32, DutchAuction> = Map::new(DUTCH_AUCTIONS_NAMESPACE);
+
+/// Map of collection ids to the last price paid during the dutch auction.
+pub const DUTCH_AUCTION_CLEARING_PRICES: Map<u32, Uint128> =
+    Map::new(DUTCH_AUCTION_CLEARING_PRICES_NAMESPACE);
+
+/// Tokens minted by an address during a dutch auction in rebate mode.
+#[cw_serde]
+pub struct DutchAuctionMint {
+    pub quantity: u32,
+    pub paid: Uint128,
+}
+
+/// The dutch auction mints waiting for the rebate claim.
+///
+/// Collection id and address are used as the key.
+pub const DUTCH_AUCTION_MINTS: Map<(u32, &Addr), DutchAuctionMint> =
+    Map::new(DUTCH_AUCTION_MINTS_NAMESPACE);
+
+/// Map of collection ids to the number of tokens minted in rebate mode
+/// whose revenue above the resting price is not settled yet.
+pub const DUTCH_AUCTION_UNSETTLED: Map<u32, u32> = Map::new(DUTCH_AUCTION_UNSETTLED_NAMESPACE);
+
+/// The price function of a bonding curve.
+///
+/// Supply is the number of tokens minted and not sold back.
+#[cw_serde]
+pub enum Curve {
+    /// Price is `base_price + slope * supply`.
+    Linear { base_price: Uint128, slope: Uint128 },
+    /// Price is `base_price * (1 + growth_rate) ^ supply`.
+    Exponential {
+        base_price: Uint128,
+        growth_rate: Decimal,
+    },
+    /// Price of the last step with a supply lower than or equal to the supply.
+    ///
+    /// Steps are supply and price pairs starting from zero supply.
+    Piecewise { steps: Vec<(u32, Uint128)> },
+}
+
+/// A bonding curve for the public mint price of a collection.
+///
+/// If reserve ratio is set, that share of the mint price is kept
+/// in the reserve and tokens can be sold back to the curve.
+#[cw_serde]
+pub struct BondingCurve {
+    pub curve: Curve,
+    pub reserve_ratio: Option<Decimal>,
+}
+
+/// Map of collection ids to bonding curves.
+///
+/// Dutch auctions take precedence over bonding curves.
+pub const BONDING_CURVES: Map<u32, BondingCurve> = Map::new(BONDING_CURVES_NAMESPACE);
+
+/// Map of collection ids to the funds kept for selling back to the curve.
+pub const BONDING_CURVE_RESERVES: Map<u32, Uint128> = Map::new(BONDING_CURVE_RESERVES_NAMESPACE);
+
+/// Map of collection ids to the number of tokens minted on the curve
+/// and not sold back.
+pub const BONDING_CURVE_SUPPLIES: Map<u32, u32> = Map::new(BONDING_CURVE_SUPPLIES_NAMESPACE);
+
+/// Shuffled metadata assignment of a collection.
+///
+/// Metadata ids are drawn from the remaining pool with a seeded Fisher-Yates
+/// instead of being picked by the minter.
+/// Public mints are reserved and drawn with a seed revealed by the creator in a later block,
+/// so the minter cannot pick or retry the draw.
+/// Provenance hash is a sha256 hash chain over the raw metadata entries in metadata id order.
+/// Each step hashes the previous hash followed by the sha256 of the entry.
+#[cw_serde]
+pub struct MetadataShuffle {
+    pub provenance_hash: String,
+    /// Number of raw metadata entries committed on collection creation.
+    pub pool_size: u32,
+    pub remaining: u32,
+    /// Tokens paid for and waiting for their draw.
+    pub pending: u32,
+    /// Rolled forward with the draw entropy on every draw.
+    pub seed: String,
+    /// Hex encoded sha256 hash of the next seed to reveal.
+    pub seed_commitment: String,
+}
+
+/// Map of collection ids to metadata shuffles.
+pub const METADATA_SHUFFLES: Map<u32, MetadataShuffle> = Map::new(METADATA_SHUFFLES_NAMESPACE);
+
+/// Public mint of a shuffled collection waiting for its draw.
+#[cw_serde]
+pub struct ShuffledMint {
+    pub quantity: u32,
+    /// Block height of the last mint, the draw is revealed after this block.
+    pub height: u64,
+}
+
+/// The shuffled mints waiting for the seed reveal.
+///
+/// Collection id and recipient are used as the key.
+pub const SHUFFLED_MINTS: Map<(u32, &Addr), ShuffledMint> = Map::new(SHUFFLED_MINTS_NAMESPACE);
+
+/// Metadata ids swapped into the remaining pool positions.
+///
+/// Collection id and pool position are used as the key.
+/// Positions without a value hold the metadata id `position + 1`.
+pub const METADATA_SHUFFLE_POOL: Map<(u32, u32), u32> = Map::new(METADATA_SHUFFLE_POOL_NAMESPACE);
+
+/// Public key used to sign the mint vouchers of a collection.
+#[cw_serde]
+pub enum VoucherKey {
+    /// Compressed or uncompressed secp256k1 public key.
+    Secp256k1 {
+        pubkey: Binary,
+    },
+    Ed25519 {
+        pubkey: Binary,
+    },
+}
+
+/// Map of collection ids to voucher keys.
+pub const VOUCHER_KEYS: Map<u32, VoucherKey> = Map::new(VOUCHER_KEYS_NAMESPACE);
+
+/// Used voucher nonces.
+///
+/// Collection id and nonce are used as the key.
+pub const VOUCHER_NONCES: Map<(u32, u64), bool> = Map::new(VOUCHER_NONCES_NAMESPACE);
+
+/// Maximum number of collections an address can create with public collection creation.
+pub const CREATOR_COLLECTION_LIMIT: Item<u32> = Item::new(CREATOR_COLLECTION_LIMIT_NAMESPACE);
+
+/// Map of addresses to the number of collections created with public collection creation.
+pub const CREATOR_COLLECTIONS: Map<&Addr, u32> = Map::new(CREATOR_COLLECTIONS_NAMESPACE);
+
+/// Native or cw20 token accepted for the public collection creation fee.
+pub const CREATION_FEE_FUND_INFO: Item<FundInfo> = Item::new(CREATION_FEE_FUND_INFO_NAMESPACE);
+
+/// Map of collection ids to the creators of publicly created collections.
+///
+/// These collections are blacklisted until they are whitelisted by the admin.
+pub const PENDING_COLLECTIONS: Map<u32, Addr> = Map::new(PENDING_COLLECTIONS_NAMESPACE);
+
+/// Archived collection ids.
+///
+/// Archived collections are read-only, cannot be minted
+/// and are not listed with the other collections.
+pub const ARCHIVED_COLLECTIONS: Map<u32, bool> = Map::new(ARCHIVED_COLLECTIONS_NAMESPACE);
@@ -1,34 +1,48 @@
-use cosmwasm_std::{to_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{to_binary, to_vec, Addr, Binary, Coin, Decimal, Empty, Uint128};
 use cw20::Cw20Coin;
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 use cw20_base::msg::InstantiateMsg as Cw20InstantiateMsg;
 use cw721_base::msg::ExecuteMsg as Cw721ExecuteMsg;
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
-use komple_framework_fee_module::msg::ExecuteMsg as FeeExecuteMsg;
+use k256::ecdsa::{signature::DigestSigner, Signature, SigningKey};
+use komple_framework_fee_module::msg::{ExecuteMsg as FeeExecuteMsg, QueryMsg as FeeQueryMsg};
+use komple_framework_fee_module::ContractError as FeeContractError;
 use komple_framework_hub_module::msg::{
     ExecuteMsg as HubExecuteMsg, InstantiateMsg as HubInstantiateMsg,
 };
 use komple_framework_hub_module::state::HubInfo;
 use komple_framework_metadata_module::msg::InstantiateMsg as MetadataInstantiateMsg;
-use komple_framework_mint_module::msg::{CollectionFundInfo, ExecuteMsg};
-use komple_framework_mint_module::state::CollectionInfo;
+use komple_framework_mint_module::msg::{
+    CollectionFundInfo, CollectionsResponse, ExecuteMsg, FeeQuoteResponse, MintVoucher, QueryMsg,
+};
+use komple_framework_mint_module::state::{
+    BondingCurve, CollectionInfo, Curve, DutchAuction, VoucherKey,
+};
 use komple_framework_mint_module::ContractError;
 use komple_framework_token_module::msg::{ExecuteMsg as TokenExecuteMsg, MetadataInfo, TokenInfo};
 use komple_framework_token_module::state::CollectionConfig;
 use komple_framework_types::modules::fee::MintFees;
-use komple_framework_types::modules::fee::{Fees, FixedPayment};
+use komple_framework_types::modules::fee::{
+    DiscountConditions, DiscountRule, Fees, FixedPayment, PercentagePayment, RevenueShare,
+};
 use komple_framework_types::modules::metadata::Metadata as MetadataType;
 use komple_framework_types::modules::mint::Collections;
 use komple_framework_types::modules::Modules;
+use komple_framework_types::shared::query::ResponseWrapper;
 use komple_framework_types::shared::RegisterMsg;
+use komple_framework_utils::funds::FundsError;
 use komple_framework_utils::storage::StorageHelper;
 use komple_framework_whitelist_module::msg::InstantiateMsg as WhitelistInstantiateMsg;
 use komple_framework_whitelist_module::state::WhitelistConfig;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
 
 pub const USER: &str = "juno..user";
 pub const USER2: &str = "juno..user2";
 pub const ADMIN: &str = "juno..admin";
+pub const KOMPLE: &str = "juno..komple";
 pub const NATIVE_DENOM: &str = "native_denom";
+pub const OTHER_DENOM: &str = "other_denom";
 pub const CW20_DENOM: &str = "cwdenom";
 
 pub fn hub_module() -> Box<dyn Contract<Empty>> {
@@ -104,16 +118,22 @@ pub fn mock_app() -> App {
             .init_balance(
                 storage,
                 &Addr::unchecked(USER),
-                vec![Coin {
-                    denom: NATIVE_DENOM.to_string(),
-                    amount: Uint128::new(1000),
-                }],
+                vec![
+                    Coin {
+                        denom: NATIVE_DENOM.to_string(),
+                        amount: Uint128::new(1000),
+                    },
+                    Coin {
+                        denom: OTHER_DENOM.to_string(),
+                        amount: Uint128::new(1000),
+                    },
+                ],
             )
             .unwrap();
     })
 }
 
-fn setup_hub_module(app: &mut App) -> Addr {
+fn setup_hub_module(app: &mut App, marbu_fee_module: Option<String>) -> Addr {
     let hub_code_id = app.store_code(hub_module());
 
     let msg = HubInstantiateMsg {
@@ -123,7 +143,7 @@ fn setup_hub_module(app: &mut App) -> Addr {
             image: "https://example.com/image.png".to_string(),
             external_link: None,
         },
-        marbu_fee_module: None,
+        marbu_fee_module,
     };
     let register_msg = RegisterMsg {
         admin: ADMIN.to_string(),
@@ -178,12 +198,14 @@ pub fn create_collection(app: &mut App, mint_module_addr: &Addr, fund_info: Coll
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -198,6 +220,7 @@ pub fn create_collection(app: &mut App, mint_module_addr: &Addr, fund_info: Coll
             token_info,
             fund_info,
             linked_collections: None,
+            metadata_shuffle: None,
         },
         &[],
     )
@@ -227,6 +250,67 @@ pub fn set_minting_price(
             data: to_binary(&FixedPayment {
                 address: Some(ADMIN.to_string()),
                 value: Uint128::new(value),
+                group: None,
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+pub fn create_collection_msg(app: &mut App, mint_module_addr: &Addr) -> ExecuteMsg {
+    let token_code_id = app.store_code(token_module());
+    let metadata_code_id = app.store_code(metadata_module());
+    ExecuteMsg::CreateCollection {
+        code_id: token_code_id,
+        collection_config: CollectionConfig {
+            per_address_limit: None,
+            start_time: None,
+            end_time: None,
+            max_token_limit: None,
+            ipfs_link: Some("some-link".to_string()),
+        },
+        collection_info: CollectionInfo {
+            collection_type: Collections::Standard,
+            name: "Test Collection".to_string(),
+            description: "Test Collection".to_string(),
+            image: "https://image.com".to_string(),
+            external_link: None,
+        },
+        metadata_info: MetadataInfo {
+            instantiate_msg: MetadataInstantiateMsg {
+                metadata_type: MetadataType::Standard,
+                reveal: None,
+            },
+            code_id: metadata_code_id,
+        },
+        token_info: TokenInfo {
+            symbol: "TEST".to_string(),
+            minter: mint_module_addr.to_string(),
+        },
+        fund_info: CollectionFundInfo {
+            is_native: true,
+            denom: NATIVE_DENOM.to_string(),
+            cw20_address: None,
+        },
+        linked_collections: None,
+        metadata_shuffle: None,
+    }
+}
+
+pub fn set_creation_fee(app: &mut App, fee_module_addr: &Addr, value: u128) {
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        fee_module_addr.clone(),
+        &FeeExecuteMsg::SetFee {
+            fee_type: Fees::Fixed,
+            module_name: MintFees::new_creation(),
+            fee_name: "komple".to_string(),
+            data: to_binary(&FixedPayment {
+                address: Some(ADMIN.to_string()),
+                value: Uint128::new(value),
+                group: None,
             })
             .unwrap(),
         },
@@ -235,6 +319,21 @@ pub fn set_minting_price(
     .unwrap();
 }
 
+pub fn revenue_split_msg(revenue_split: Vec<(&str, &str, &str)>) -> ExecuteMsg {
+    ExecuteMsg::UpdateRevenueSplit {
+        collection_id: 1,
+        revenue_split: revenue_split
+            .into_iter()
+            .map(|(name, address, share)| RevenueShare {
+                name: name.to_string(),
+                address: Some(address.to_string()),
+                share: Decimal::from_str(share).unwrap(),
+                group: None,
+            })
+            .collect(),
+    }
+}
+
 pub fn create_whitelist(app: &mut App, collection_addr: &Addr) {
     let whitelist_code_id = app.store_code(whitelist_module());
     let msg: Cw721ExecuteMsg<Empty, TokenExecuteMsg> = Cw721ExecuteMsg::Extension {
@@ -255,6 +354,38 @@ pub fn create_whitelist(app: &mut App, collection_addr: &Addr) {
         .unwrap();
 }
 
+// Creates the platform fee module with a 10% mint fee
+fn setup_marbu_fee_module(app: &mut App) -> Addr {
+    let fee_code_id = app.store_code(fee_module());
+    let msg = RegisterMsg {
+        admin: ADMIN.to_string(),
+        data: None,
+    };
+    let marbu_fee_module_addr = app
+        .instantiate_contract(fee_code_id, Addr::unchecked(ADMIN), &msg, &[], "test", None)
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        marbu_fee_module_addr.clone(),
+        &FeeExecuteMsg::SetFee {
+            fee_type: Fees::Percentage,
+            module_name: Modules::Mint.to_string(),
+            fee_name: "komple".to_string(),
+            data: to_binary(&PercentagePayment {
+                address: Some(KOMPLE.to_string()),
+                value: Decimal::from_str("0.1").unwrap(),
+                group: None,
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    marbu_fee_module_addr
+}
+
 fn setup_cw20_token(app: &mut App) -> Addr {
     let code_id = app.store_code(cw20_contract());
     let msg = Cw20InstantiateMsg {
@@ -284,7 +415,7 @@ mod execute {
             #[test]
             fn test_standard_price() {
                 let mut app = mock_app();
-                let hub_addr = setup_hub_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, None);
 
                 // Register Mint Module
                 let mint_code_id = app.store_code(mint_module());
@@ -326,7 +457,8 @@ mod execute {
                     mint_module_addr.clone(),
                     &ExecuteMsg::Mint {
                         collection_id: 1,
-                        metadata_id: None,
+                        quantity: 1,
+                        metadata_ids: None,
                     },
                     &[Coin {
                         amount: Uint128::new(5),
@@ -340,7 +472,8 @@ mod execute {
                     mint_module_addr.clone(),
                     &ExecuteMsg::Mint {
                         collection_id: 1,
-                        metadata_id: None,
+                        quantity: 1,
+                        metadata_ids: None,
                     },
                     &[Coin {
                         amount: Uint128::new(10),
@@ -354,11 +487,10 @@ mod execute {
             }
 
             #[test]
-            fn test_whitelist_price() {
+            fn test_staged_price() {
                 let mut app = mock_app();
-                let hub_addr = setup_hub_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, None);
 
-                // Register Mint Module
                 let mint_code_id = app.store_code(mint_module());
                 register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
                 let mint_module_addr = StorageHelper::query_module_address(
@@ -368,7 +500,6 @@ mod execute {
                 )
                 .unwrap();
 
-                // Register fee module
                 let fee_code_id = app.store_code(fee_module());
                 register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
                 let fee_module_addr = StorageHelper::query_module_address(
@@ -378,7 +509,6 @@ mod execute {
                 )
                 .unwrap();
 
-                // Create collection
                 create_collection(
                     &mut app,
                     &mint_module_addr,
@@ -388,106 +518,71 @@ mod execute {
                         cw20_address: None,
                     },
                 );
-                let collection_addr =
-                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
-                        .unwrap();
-
-                // Create whitelist
-                create_whitelist(&mut app, &collection_addr);
-
-                // Set whitelist price
-                set_minting_price(
-                    &mut app,
-                    &fee_module_addr,
-                    MintFees::Whitelist.as_str(),
-                    1,
-                    10,
-                );
 
-                app.update_block(|block| {
-                    block.time = block.time.plus_seconds(2);
-                });
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 10);
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    fee_module_addr.clone(),
+                    &FeeExecuteMsg::UpdateNoticePeriod {
+                        module_name: Modules::Mint.to_string(),
+                        notice_period: 3600,
+                    },
+                    &[],
+                )
+                .unwrap();
 
-                // Throw error if invalid fund
-                let err = app
-                    .execute_contract(
-                        Addr::unchecked(USER2),
-                        mint_module_addr.clone(),
-                        &ExecuteMsg::Mint {
-                            collection_id: 1,
-                            metadata_id: None,
-                        },
-                        &[],
-                    )
-                    .unwrap_err();
-                assert_eq!(
-                    err.source().unwrap().to_string(),
-                    ContractError::AddressNotWhitelisted {}.to_string()
-                );
+                // New price is staged until the notice period passes
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 20);
 
-                // Throw error if invalid fund
+                let msg = ExecuteMsg::Mint {
+                    collection_id: 1,
+                    quantity: 1,
+                    metadata_ids: None,
+                };
                 app.execute_contract(
                     Addr::unchecked(USER),
                     mint_module_addr.clone(),
-                    &ExecuteMsg::Mint {
-                        collection_id: 1,
-                        metadata_id: None,
-                    },
+                    &msg,
                     &[Coin {
-                        amount: Uint128::new(5),
+                        amount: Uint128::new(10),
                         denom: NATIVE_DENOM.to_string(),
                     }],
                 )
-                .unwrap_err();
+                .unwrap();
 
-                // Success
+                app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+                // Due price is used before the fee module saves it
                 app.execute_contract(
                     Addr::unchecked(USER),
                     mint_module_addr.clone(),
-                    &ExecuteMsg::Mint {
-                        collection_id: 1,
-                        metadata_id: None,
-                    },
+                    &msg,
                     &[Coin {
                         amount: Uint128::new(10),
                         denom: NATIVE_DENOM.to_string(),
                     }],
                 )
-                .unwrap();
-
-                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
-                assert_eq!(res.amount, Uint128::new(10));
-
-                app.update_block(|block| block.time = block.time.plus_seconds(100));
-
+                .unwrap_err();
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    mint_module_addr.clone(),
-                    &ExecuteMsg::Mint {
-                        collection_id: 1,
-                        metadata_id: None,
-                    },
+                    mint_module_addr,
+                    &msg,
                     &[Coin {
-                        amount: Uint128::new(10),
+                        amount: Uint128::new(20),
                         denom: NATIVE_DENOM.to_string(),
                     }],
                 )
                 .unwrap();
 
                 let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
-                assert_eq!(res.amount, Uint128::new(10));
+                assert_eq!(res.amount, Uint128::new(30));
             }
-        }
-
-        mod cw20_token {
-            use super::*;
 
             #[test]
-            fn test_standard_price() {
+            fn test_batch_mint() {
                 let mut app = mock_app();
-                let hub_addr = setup_hub_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, None);
 
-                // Register Mint Module
                 let mint_code_id = app.store_code(mint_module());
                 register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
                 let mint_module_addr = StorageHelper::query_module_address(
@@ -497,7 +592,6 @@ mod execute {
                 )
                 .unwrap();
 
-                // Register fee module
                 let fee_code_id = app.store_code(fee_module());
                 register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
                 let fee_module_addr = StorageHelper::query_module_address(
@@ -507,71 +601,55 @@ mod execute {
                 )
                 .unwrap();
 
-                let cw20_addr = setup_cw20_token(&mut app);
-
-                // Create collection
                 create_collection(
                     &mut app,
                     &mint_module_addr,
                     CollectionFundInfo {
-                        is_native: false,
-                        denom: CW20_DENOM.to_string(),
-                        cw20_address: Some(cw20_addr.to_string()),
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
                     },
                 );
 
-                // Set normal price
                 set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 10);
 
-                // Throw error if invalid fund
+                let msg = ExecuteMsg::Mint {
+                    collection_id: 1,
+                    quantity: 3,
+                    metadata_ids: None,
+                };
+
+                // Price of a single token is not enough
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    cw20_addr.clone(),
-                    &Cw20ExecuteMsg::Send {
-                        contract: mint_module_addr.to_string(),
-                        amount: Uint128::new(5),
-                        msg: to_binary(&ExecuteMsg::Mint {
-                            collection_id: 1,
-                            metadata_id: None,
-                        })
-                        .unwrap(),
-                    },
-                    &[],
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[Coin {
+                        amount: Uint128::new(10),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
                 )
                 .unwrap_err();
 
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    cw20_addr.clone(),
-                    &Cw20ExecuteMsg::Send {
-                        contract: mint_module_addr.to_string(),
-                        amount: Uint128::new(10),
-                        msg: to_binary(&ExecuteMsg::Mint {
-                            collection_id: 1,
-                            metadata_id: None,
-                        })
-                        .unwrap(),
-                    },
-                    &[],
+                    mint_module_addr,
+                    &msg,
+                    &[Coin {
+                        amount: Uint128::new(30),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
                 )
                 .unwrap();
 
-                let res: BalanceResponse = app
-                    .wrap()
-                    .query_wasm_smart(
-                        cw20_addr,
-                        &Cw20QueryMsg::Balance {
-                            address: ADMIN.to_string(),
-                        },
-                    )
-                    .unwrap();
-                assert_eq!(res.balance, Uint128::new(10));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(30));
             }
 
             #[test]
             fn test_whitelist_price() {
                 let mut app = mock_app();
-                let hub_addr = setup_hub_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, None);
 
                 // Register Mint Module
                 let mint_code_id = app.store_code(mint_module());
@@ -593,16 +671,14 @@ mod execute {
                 )
                 .unwrap();
 
-                let cw20_addr = setup_cw20_token(&mut app);
-
                 // Create collection
                 create_collection(
                     &mut app,
                     &mint_module_addr,
                     CollectionFundInfo {
-                        is_native: false,
-                        denom: CW20_DENOM.to_string(),
-                        cw20_address: Some(cw20_addr.to_string()),
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
                     },
                 );
                 let collection_addr =
@@ -625,62 +701,1511 @@ mod execute {
                     block.time = block.time.plus_seconds(2);
                 });
 
+                // Throw error if invalid fund
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER2),
+                        mint_module_addr.clone(),
+                        &ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        },
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::AddressNotWhitelisted {}.to_string()
+                );
+
                 // Throw error if invalid fund
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    cw20_addr.clone(),
-                    &Cw20ExecuteMsg::Send {
-                        contract: mint_module_addr.to_string(),
-                        amount: Uint128::new(5),
-                        msg: to_binary(&ExecuteMsg::Mint {
-                            collection_id: 1,
-                            metadata_id: None,
-                        })
-                        .unwrap(),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
                     },
-                    &[],
+                    &[Coin {
+                        amount: Uint128::new(5),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
                 )
                 .unwrap_err();
 
                 // Success
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    cw20_addr.clone(),
-                    &Cw20ExecuteMsg::Send {
-                        contract: mint_module_addr.to_string(),
-                        amount: Uint128::new(10),
-                        msg: to_binary(&ExecuteMsg::Mint {
-                            collection_id: 1,
-                            metadata_id: None,
-                        })
-                        .unwrap(),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
                     },
-                    &[],
+                    &[Coin {
+                        amount: Uint128::new(10),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
                 )
                 .unwrap();
 
-                let res: BalanceResponse = app
-                    .wrap()
-                    .query_wasm_smart(
-                        cw20_addr.clone(),
-                        &Cw20QueryMsg::Balance {
-                            address: ADMIN.to_string(),
-                        },
-                    )
-                    .unwrap();
-                assert_eq!(res.balance, Uint128::new(10));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(10));
 
                 app.update_block(|block| block.time = block.time.plus_seconds(100));
 
                 app.execute_contract(
                     Addr::unchecked(USER),
-                    cw20_addr.clone(),
-                    &Cw20ExecuteMsg::Send {
-                        contract: mint_module_addr.to_string(),
-                        amount: Uint128::new(10),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(10),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(10));
+            }
+
+            #[test]
+            fn test_revenue_split() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+                create_whitelist(&mut app, &collection_addr);
+
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 200);
+                set_minting_price(
+                    &mut app,
+                    &fee_module_addr,
+                    MintFees::Whitelist.as_str(),
+                    1,
+                    100,
+                );
+
+                // Shares must add up to exactly one and have unique names
+                for revenue_split in [
+                    vec![
+                        ("artist", "juno..artist", "0.5"),
+                        ("hub_admin", ADMIN, "0.4"),
+                    ],
+                    vec![
+                        ("artist", "juno..artist", "0.5"),
+                        ("hub_admin", ADMIN, "0.6"),
+                    ],
+                    vec![("artist", "juno..artist", "1"), ("hub_admin", ADMIN, "0")],
+                    vec![("artist", "juno..artist", "0.5"), ("artist", ADMIN, "0.5")],
+                ] {
+                    let err = app
+                        .execute_contract(
+                            Addr::unchecked(ADMIN),
+                            mint_module_addr.clone(),
+                            &revenue_split_msg(revenue_split),
+                            &[],
+                        )
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().source().unwrap().to_string(),
+                        FeeContractError::InvalidRevenueSplit {}.to_string()
+                    );
+                }
+
+                // Artist, hub admin, platform and charity split the revenue 5:2:2:1
+                let revenue_split = vec![
+                    ("artist", "juno..artist", "0.5"),
+                    ("hub_admin", ADMIN, "0.2"),
+                    ("platform", KOMPLE, "0.2"),
+                    ("charity", "juno..charity", "0.1"),
+                ];
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &revenue_split_msg(revenue_split.clone()),
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    mint_module_addr.clone(),
+                    &revenue_split_msg(revenue_split),
+                    &[],
+                )
+                .unwrap();
+
+                // Split is saved as the percentage fees of the collection revenue module name
+                let res: ResponseWrapper<Decimal> = app
+                    .wrap()
+                    .query_wasm_smart(
+                        fee_module_addr.clone(),
+                        &FeeQueryMsg::TotalPercentageFees {
+                            module_name: MintFees::new_revenue(1),
+                            start_after: None,
+                            limit: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.data, Decimal::one());
+
+                // Whitelist price is split
+                app.update_block(|block| block.time = block.time.plus_seconds(2));
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(100),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app
+                    .wrap()
+                    .query_balance("juno..artist", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(res.amount, Uint128::new(50));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(20));
+                let res = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(20));
+                let res = app
+                    .wrap()
+                    .query_balance("juno..charity", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(res.amount, Uint128::new(10));
+
+                // Public price is split the same way
+                app.update_block(|block| block.time = block.time.plus_seconds(100));
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(200),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app
+                    .wrap()
+                    .query_balance("juno..artist", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(res.amount, Uint128::new(150));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(60));
+                let res = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(60));
+                let res = app
+                    .wrap()
+                    .query_balance("juno..charity", NATIVE_DENOM)
+                    .unwrap();
+                assert_eq!(res.amount, Uint128::new(30));
+
+                // Revenue is tracked per share on the fee module
+                let res: ResponseWrapper<Vec<Coin>> = app
+                    .wrap()
+                    .query_wasm_smart(
+                        fee_module_addr,
+                        &FeeQueryMsg::FeeRevenue {
+                            module_name: MintFees::new_revenue(1),
+                            fee_name: "artist".to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.data, vec![Coin::new(150, NATIVE_DENOM)]);
+            }
+
+            #[test]
+            fn test_holder_discount() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                // Membership collection is free to mint
+                let fund_info = CollectionFundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                };
+                create_collection(&mut app, &mint_module_addr, fund_info.clone());
+                create_collection(&mut app, &mint_module_addr, fund_info);
+                let membership_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 2, 10);
+
+                // Holders of a membership token get half of the price off
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    fee_module_addr.clone(),
+                    &FeeExecuteMsg::SetDiscount {
+                        module_name: Modules::Mint.to_string(),
+                        fee_name: MintFees::new_price(2),
+                        rules: vec![DiscountRule {
+                            collection_addr: membership_addr.to_string(),
+                            condition: DiscountConditions::MinTokens { amount: 1 },
+                            value: Decimal::percent(50),
+                        }],
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                // Not a holder yet
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 2,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(5),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap_err();
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 2,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(5),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(5));
+            }
+
+            #[test]
+            fn test_exempt_minter() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let fund_info = CollectionFundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                };
+                create_collection(&mut app, &mint_module_addr, fund_info);
+
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 10);
+
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    fee_module_addr.clone(),
+                    &FeeExecuteMsg::AddExemptions {
+                        module_name: Modules::Mint.to_string(),
+                        fee_name: MintFees::new_price(1),
+                        addrs: vec![USER.to_string()],
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                // Exempt minters do not pay the price
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::zero());
+            }
+
+            #[test]
+            fn test_platform_fee() {
+                let mut app = mock_app();
+                let marbu_fee_module_addr = setup_marbu_fee_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, Some(marbu_fee_module_addr.to_string()));
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let fund_info = CollectionFundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                };
+                create_collection(&mut app, &mint_module_addr, fund_info);
+
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 100);
+
+                let res: ResponseWrapper<FeeQuoteResponse> = app
+                    .wrap()
+                    .query_wasm_smart(
+                        mint_module_addr.clone(),
+                        &QueryMsg::FeeQuote {
+                            collection_id: 1,
+                            minter: Some(USER.to_string()),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(
+                    res.data,
+                    FeeQuoteResponse {
+                        price: Uint128::new(100),
+                        platform_fee: Uint128::new(10),
+                        payout: Uint128::new(90),
+                    }
+                );
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 1,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        denom: NATIVE_DENOM.to_string(),
+                        amount: Uint128::new(100),
+                    }],
+                )
+                .unwrap();
+
+                // Platform fee is taken out of the price
+                let res = app.wrap().query_balance(KOMPLE, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(10));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(90));
+            }
+
+            #[test]
+            fn test_dutch_auction() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                let start_time = app.block_info().time.plus_seconds(10);
+                let dutch_auction = DutchAuction {
+                    start_price: Uint128::new(100),
+                    resting_price: Uint128::new(20),
+                    start_time,
+                    end_time: start_time.plus_seconds(100),
+                    step_interval: 20,
+                    rebate: false,
+                };
+
+                // Resting price must be lower than start price
+                let msg = ExecuteMsg::UpdateDutchAuction {
+                    collection_id: 1,
+                    dutch_auction: Some(DutchAuction {
+                        resting_price: Uint128::new(100),
+                        ..dutch_auction.clone()
+                    }),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidDutchAuction {}.to_string()
+                );
+
+                let msg = ExecuteMsg::UpdateDutchAuction {
+                    collection_id: 1,
+                    dutch_auction: Some(dutch_auction.clone()),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let msg = QueryMsg::CurrentMintPrice { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(100));
+
+                // Two steps after the start
+                app.update_block(|block| block.time = start_time.plus_seconds(45));
+
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(68));
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 2,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(136),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(136));
+
+                let msg = ExecuteMsg::UpdateDutchAuction {
+                    collection_id: 1,
+                    dutch_auction: None,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::DutchAuctionStarted {}.to_string()
+                );
+
+                app.update_block(|block| block.time = start_time.plus_seconds(200));
+
+                let msg = QueryMsg::CurrentMintPrice { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> =
+                    app.wrap().query_wasm_smart(mint_module_addr, &msg).unwrap();
+                assert_eq!(res.data, Uint128::new(20));
+            }
+
+            #[test]
+            fn test_dutch_auction_rebate() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                let start_time = app.block_info().time.plus_seconds(10);
+                let msg = ExecuteMsg::UpdateDutchAuction {
+                    collection_id: 1,
+                    dutch_auction: Some(DutchAuction {
+                        start_price: Uint128::new(100),
+                        resting_price: Uint128::new(20),
+                        start_time,
+                        end_time: start_time.plus_seconds(100),
+                        step_interval: 20,
+                        rebate: true,
+                    }),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let mint_msg = ExecuteMsg::Mint {
+                    collection_id: 1,
+                    quantity: 1,
+                    metadata_ids: None,
+                };
+                app.update_block(|block| block.time = start_time);
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &mint_msg,
+                    &[Coin {
+                        amount: Uint128::new(100),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                // Three steps after the start
+                app.update_block(|block| block.time = start_time.plus_seconds(60));
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &mint_msg,
+                    &[Coin {
+                        amount: Uint128::new(52),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                // Only the resting price is paid out during the auction
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(40));
+
+                let msg = ExecuteMsg::ClaimDutchAuctionRebate {
+                    collection_id: 1,
+                    address: None,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::DutchAuctionNotEnded {}.to_string()
+                );
+
+                app.update_block(|block| block.time = start_time.plus_seconds(100));
+
+                // Anyone can claim the rebate for the minter
+                let msg = ExecuteMsg::ClaimDutchAuctionRebate {
+                    collection_id: 1,
+                    address: Some(USER.to_string()),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                // Both tokens are paid with the clearing price of 52
+                let res = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(896));
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(40));
+
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::NoRebate {}.to_string()
+                );
+
+                let msg = ExecuteMsg::SettleDutchAuction { collection_id: 1 };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+
+                // Revenue above the resting price is paid out once
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(104));
+
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::NoUnsettledRevenue {}.to_string()
+                );
+            }
+
+            #[test]
+            fn test_bonding_curve() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                // Piecewise steps must start from zero supply
+                let msg = ExecuteMsg::UpdateBondingCurve {
+                    collection_id: 1,
+                    bonding_curve: Some(BondingCurve {
+                        curve: Curve::Piecewise {
+                            steps: vec![(1, Uint128::new(10))],
+                        },
+                        reserve_ratio: None,
+                    }),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidBondingCurve {}.to_string()
+                );
+
+                let msg = ExecuteMsg::UpdateBondingCurve {
+                    collection_id: 1,
+                    bonding_curve: Some(BondingCurve {
+                        curve: Curve::Linear {
+                            base_price: Uint128::new(10),
+                            slope: Uint128::new(5),
+                        },
+                        reserve_ratio: None,
+                    }),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                // Each token is priced with the supply before it
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 2,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(25),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(25));
+
+                let query_msg = QueryMsg::CurrentMintPrice { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(20));
+
+                let msg = ExecuteMsg::UpdateBondingCurve {
+                    collection_id: 1,
+                    bonding_curve: Some(BondingCurve {
+                        curve: Curve::Exponential {
+                            base_price: Uint128::new(100),
+                            growth_rate: Decimal::from_str("0.1").unwrap(),
+                        },
+                        reserve_ratio: None,
+                    }),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr, &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(121));
+            }
+
+            #[test]
+            fn test_bonding_curve_sell_back() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                let msg = ExecuteMsg::UpdateBondingCurve {
+                    collection_id: 1,
+                    bonding_curve: Some(BondingCurve {
+                        curve: Curve::Linear {
+                            base_price: Uint128::new(10),
+                            slope: Uint128::new(10),
+                        },
+                        reserve_ratio: Some(Decimal::from_str("0.5").unwrap()),
+                    }),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 3,
+                        metadata_ids: None,
+                    },
+                    &[Coin {
+                        amount: Uint128::new(60),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                // Half of the price is kept in the reserve
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(30));
+                let query_msg = QueryMsg::BondingCurveReserve { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(30));
+
+                // Mint module needs approval to burn the token
+                let sell_back_msg = ExecuteMsg::SellBack {
+                    collection_id: 1,
+                    token_id: 2,
+                };
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &sell_back_msg,
+                    &[],
+                )
+                .unwrap_err();
+
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+                let msg: Cw721ExecuteMsg<Empty, TokenExecuteMsg> = Cw721ExecuteMsg::ApproveAll {
+                    operator: mint_module_addr.to_string(),
+                    expires: None,
+                };
+                app.execute_contract(Addr::unchecked(USER), collection_addr, &msg, &[])
+                    .unwrap();
+
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        mint_module_addr.clone(),
+                        &sell_back_msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &sell_back_msg,
+                    &[],
+                )
+                .unwrap();
+
+                // Refund is the reserve share of the last token price
+                let res = app.wrap().query_balance(USER, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(955));
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(15));
+
+                let query_msg = QueryMsg::CurrentMintPrice { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(30));
+
+                // Tokens minted outside the curve do not change the supply
+                let msg = ExecuteMsg::AdminMint {
+                    collection_id: 1,
+                    recipient: USER.to_string(),
+                    metadata_id: None,
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(30));
+
+                let msg = ExecuteMsg::UpdateCollectionMintLock {
+                    collection_id: 1,
+                    lock: true,
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+                let sell_back_msg = ExecuteMsg::SellBack {
+                    collection_id: 1,
+                    token_id: 1,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &sell_back_msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::LockedMint {}.to_string()
+                );
+
+                let msg = ExecuteMsg::UpdateBondingCurve {
+                    collection_id: 1,
+                    bonding_curve: None,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::ReserveNotEmpty {}.to_string()
+                );
+
+                // Archived collections keep the reserve open for sell backs
+                let msg = ExecuteMsg::ArchiveCollection { collection_id: 1 };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &sell_back_msg,
+                    &[],
+                )
+                .unwrap();
+
+                let query_msg = QueryMsg::BondingCurveReserve { collection_id: 1 };
+                let res: ResponseWrapper<Uint128> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr, &query_msg)
+                    .unwrap();
+                assert_eq!(res.data, Uint128::new(5));
+            }
+
+            #[test]
+            fn test_mint_voucher() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                let signing_key = SigningKey::from_bytes(&[1; 32]).unwrap();
+                let pubkey = Binary::from(signing_key.verifying_key().to_bytes().to_vec());
+                let sign = |voucher: &MintVoucher| {
+                    let mut hasher = Sha256::new();
+                    hasher.update(mint_module_addr.as_bytes());
+                    hasher.update(to_vec(voucher).unwrap());
+                    let signature: Signature = signing_key.sign_digest(hasher);
+                    Binary::from(signature.as_ref())
+                };
+
+                let msg = ExecuteMsg::UpdateVoucherKey {
+                    collection_id: 1,
+                    voucher_key: Some(VoucherKey::Secp256k1 {
+                        pubkey: Binary::from(vec![1; 20]),
+                    }),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidVoucherKey {}.to_string()
+                );
+
+                let msg = ExecuteMsg::UpdateVoucherKey {
+                    collection_id: 1,
+                    voucher_key: Some(VoucherKey::Secp256k1 { pubkey }),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let voucher = MintVoucher {
+                    recipient: USER.to_string(),
+                    collection_id: 1,
+                    max_quantity: 2,
+                    price: Uint128::new(10),
+                    expiry: app.block_info().time.plus_seconds(100),
+                    nonce: 1,
+                };
+                let funds = [Coin {
+                    amount: Uint128::new(20),
+                    denom: NATIVE_DENOM.to_string(),
+                }];
+
+                let msg = ExecuteMsg::MintWithVoucher {
+                    quantity: 3,
+                    metadata_ids: None,
+                    voucher: voucher.clone(),
+                    signature: sign(&voucher),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidQuantity {}.to_string()
+                );
+
+                // Signature of a different voucher
+                let msg = ExecuteMsg::MintWithVoucher {
+                    quantity: 2,
+                    metadata_ids: None,
+                    voucher: voucher.clone(),
+                    signature: sign(&MintVoucher {
+                        price: Uint128::new(1),
+                        ..voucher.clone()
+                    }),
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &msg,
+                        &funds,
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidVoucher {}.to_string()
+                );
+
+                // Voucher of another recipient
+                let msg = ExecuteMsg::MintWithVoucher {
+                    quantity: 2,
+                    metadata_ids: None,
+                    voucher: voucher.clone(),
+                    signature: sign(&voucher),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER2), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidVoucher {}.to_string()
+                );
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &funds,
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(20));
+
+                let query_msg = QueryMsg::VoucherNonceUsed {
+                    collection_id: 1,
+                    nonce: 1,
+                };
+                let res: ResponseWrapper<bool> = app
+                    .wrap()
+                    .query_wasm_smart(mint_module_addr.clone(), &query_msg)
+                    .unwrap();
+                assert!(res.data);
+
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &msg,
+                        &funds,
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::VoucherUsed {}.to_string()
+                );
+
+                app.update_block(|block| block.time = block.time.plus_seconds(100));
+
+                let voucher = MintVoucher {
+                    nonce: 2,
+                    ..voucher
+                };
+                let msg = ExecuteMsg::MintWithVoucher {
+                    quantity: 2,
+                    metadata_ids: None,
+                    voucher: voucher.clone(),
+                    signature: sign(&voucher),
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr, &msg, &funds)
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::VoucherExpired {}.to_string()
+                );
+            }
+
+            #[test]
+            fn test_mint_voucher_ed25519() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
+                );
+
+                let signing_key = ed25519_zebra::SigningKey::from([2; 32]);
+                let verification_key = ed25519_zebra::VerificationKey::from(&signing_key);
+                let msg = ExecuteMsg::UpdateVoucherKey {
+                    collection_id: 1,
+                    voucher_key: Some(VoucherKey::Ed25519 {
+                        pubkey: Binary::from(verification_key.as_ref()),
+                    }),
+                };
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let voucher = MintVoucher {
+                    recipient: USER.to_string(),
+                    collection_id: 1,
+                    max_quantity: 1,
+                    price: Uint128::new(15),
+                    expiry: app.block_info().time.plus_seconds(100),
+                    nonce: 1,
+                };
+                let mut hasher = Sha256::new();
+                hasher.update(mint_module_addr.as_bytes());
+                hasher.update(to_vec(&voucher).unwrap());
+                let signature = signing_key.sign(&hasher.finalize());
+
+                let msg = ExecuteMsg::MintWithVoucher {
+                    quantity: 1,
+                    metadata_ids: None,
+                    voucher,
+                    signature: Binary::from(<[u8; 64]>::from(signature)),
+                };
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr,
+                    &msg,
+                    &[Coin {
+                        amount: Uint128::new(15),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(15));
+            }
+        }
+
+        mod cw20_token {
+            use super::*;
+
+            #[test]
+            fn test_standard_price() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let cw20_addr = setup_cw20_token(&mut app);
+
+                // Create collection
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: false,
+                        denom: CW20_DENOM.to_string(),
+                        cw20_address: Some(cw20_addr.to_string()),
+                    },
+                );
+
+                // Set normal price
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 10);
+
+                // Throw error if invalid fund
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(5),
+                        msg: to_binary(&ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        })
+                        .unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap_err();
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(10),
+                        msg: to_binary(&ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        })
+                        .unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let res: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        cw20_addr,
+                        &Cw20QueryMsg::Balance {
+                            address: ADMIN.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.balance, Uint128::new(10));
+            }
+
+            #[test]
+            fn test_whitelist_price() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let cw20_addr = setup_cw20_token(&mut app);
+
+                // Create collection
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: false,
+                        denom: CW20_DENOM.to_string(),
+                        cw20_address: Some(cw20_addr.to_string()),
+                    },
+                );
+                let collection_addr =
+                    StorageHelper::query_collection_address(&app.wrap(), &mint_module_addr, &1)
+                        .unwrap();
+
+                // Create whitelist
+                create_whitelist(&mut app, &collection_addr);
+
+                // Set whitelist price
+                set_minting_price(
+                    &mut app,
+                    &fee_module_addr,
+                    MintFees::Whitelist.as_str(),
+                    1,
+                    10,
+                );
+
+                app.update_block(|block| {
+                    block.time = block.time.plus_seconds(2);
+                });
+
+                // Throw error if invalid fund
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(5),
+                        msg: to_binary(&ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        })
+                        .unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap_err();
+
+                // Success
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(10),
+                        msg: to_binary(&ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        })
+                        .unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let res: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        cw20_addr.clone(),
+                        &Cw20QueryMsg::Balance {
+                            address: ADMIN.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.balance, Uint128::new(10));
+
+                app.update_block(|block| block.time = block.time.plus_seconds(100));
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(10),
                         msg: to_binary(&ExecuteMsg::Mint {
                             collection_id: 1,
-                            metadata_id: None,
+                            quantity: 1,
+                            metadata_ids: None,
                         })
                         .unwrap(),
                     },
@@ -699,6 +2224,352 @@ mod execute {
                     .unwrap();
                 assert_eq!(res.balance, Uint128::new(10));
             }
+
+            #[test]
+            fn test_platform_fee() {
+                let mut app = mock_app();
+                let marbu_fee_module_addr = setup_marbu_fee_module(&mut app);
+                let hub_addr = setup_hub_module(&mut app, Some(marbu_fee_module_addr.to_string()));
+
+                // Register Mint Module
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                // Register fee module
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let cw20_addr = setup_cw20_token(&mut app);
+
+                create_collection(
+                    &mut app,
+                    &mint_module_addr,
+                    CollectionFundInfo {
+                        is_native: false,
+                        denom: CW20_DENOM.to_string(),
+                        cw20_address: Some(cw20_addr.to_string()),
+                    },
+                );
+
+                set_minting_price(&mut app, &fee_module_addr, MintFees::Price.as_str(), 1, 100);
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(100),
+                        msg: to_binary(&ExecuteMsg::Mint {
+                            collection_id: 1,
+                            quantity: 1,
+                            metadata_ids: None,
+                        })
+                        .unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                // Platform fee is taken out of the price
+                let res: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        cw20_addr.clone(),
+                        &Cw20QueryMsg::Balance {
+                            address: KOMPLE.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.balance, Uint128::new(10));
+                let res: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        cw20_addr,
+                        &Cw20QueryMsg::Balance {
+                            address: ADMIN.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.balance, Uint128::new(90));
+            }
+        }
+    }
+
+    mod create_collection {
+        use super::*;
+
+        mod native_token {
+            use super::*;
+
+            #[test]
+            fn test_creation_fee() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::UpdatePublicCollectionCreation {
+                        public_collection_creation: true,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                set_creation_fee(&mut app, &fee_module_addr, 100);
+
+                let msg = create_collection_msg(&mut app, &mint_module_addr);
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::CreationFeeFundInfoNotFound {}.to_string()
+                );
+
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &ExecuteMsg::UpdateCreationFeeFundInfo {
+                            fund_info: Some(CollectionFundInfo {
+                                is_native: true,
+                                denom: NATIVE_DENOM.to_string(),
+                                cw20_address: None,
+                            }),
+                        },
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::UpdateCreationFeeFundInfo {
+                        fund_info: Some(CollectionFundInfo {
+                            is_native: true,
+                            denom: NATIVE_DENOM.to_string(),
+                            cw20_address: None,
+                        }),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Funds(FundsError::MissingFunds {}).to_string()
+                );
+
+                // Only the configured denom is accepted
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &msg,
+                        &[Coin {
+                            amount: Uint128::new(100),
+                            denom: OTHER_DENOM.to_string(),
+                        }],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Funds(FundsError::InvalidDenom {
+                        got: OTHER_DENOM.to_string(),
+                        expected: NATIVE_DENOM.to_string(),
+                    })
+                    .to_string()
+                );
+
+                // Fee module checks the paid amount
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[Coin {
+                        amount: Uint128::new(50),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap_err();
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[Coin {
+                        amount: Uint128::new(100),
+                        denom: NATIVE_DENOM.to_string(),
+                    }],
+                )
+                .unwrap();
+
+                let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+                assert_eq!(res.amount, Uint128::new(100));
+
+                let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                    .wrap()
+                    .query_wasm_smart(
+                        mint_module_addr.clone(),
+                        &QueryMsg::PendingCollections {
+                            start_after: None,
+                            limit: None,
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.data.len(), 1);
+
+                // Admin does not pay the creation fee
+                let msg = create_collection_msg(&mut app, &mint_module_addr);
+                app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
+                    .unwrap();
+            }
+        }
+
+        mod cw20_token {
+            use super::*;
+
+            #[test]
+            fn test_creation_fee() {
+                let mut app = mock_app();
+                let hub_addr = setup_hub_module(&mut app, None);
+
+                let mint_code_id = app.store_code(mint_module());
+                register_module(&mut app, &hub_addr, Modules::Mint.to_string(), mint_code_id);
+                let mint_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Mint.to_string(),
+                )
+                .unwrap();
+
+                let fee_code_id = app.store_code(fee_module());
+                register_module(&mut app, &hub_addr, Modules::Fee.to_string(), fee_code_id);
+                let fee_module_addr = StorageHelper::query_module_address(
+                    &app.wrap(),
+                    &hub_addr,
+                    Modules::Fee.to_string(),
+                )
+                .unwrap();
+
+                let cw20_addr = setup_cw20_token(&mut app);
+
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::UpdatePublicCollectionCreation {
+                        public_collection_creation: true,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                set_creation_fee(&mut app, &fee_module_addr, 100);
+
+                app.execute_contract(
+                    Addr::unchecked(ADMIN),
+                    mint_module_addr.clone(),
+                    &ExecuteMsg::UpdateCreationFeeFundInfo {
+                        fund_info: Some(CollectionFundInfo {
+                            is_native: false,
+                            denom: CW20_DENOM.to_string(),
+                            cw20_address: Some(cw20_addr.to_string()),
+                        }),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let msg = create_collection_msg(&mut app, &mint_module_addr);
+                // Native funds are not accepted for a cw20 creation fee
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(USER),
+                        mint_module_addr.clone(),
+                        &msg,
+                        &[Coin {
+                            amount: Uint128::new(100),
+                            denom: NATIVE_DENOM.to_string(),
+                        }],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Funds(FundsError::InvalidCw20Token {}).to_string()
+                );
+
+                app.execute_contract(
+                    Addr::unchecked(USER),
+                    cw20_addr.clone(),
+                    &Cw20ExecuteMsg::Send {
+                        contract: mint_module_addr.to_string(),
+                        amount: Uint128::new(100),
+                        msg: to_binary(&msg).unwrap(),
+                    },
+                    &[],
+                )
+                .unwrap();
+
+                let res: BalanceResponse = app
+                    .wrap()
+                    .query_wasm_smart(
+                        cw20_addr,
+                        &Cw20QueryMsg::Balance {
+                            address: ADMIN.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.balance, Uint128::new(100));
+
+                let res: ResponseWrapper<u32> = app
+                    .wrap()
+                    .query_wasm_smart(
+                        mint_module_addr,
+                        &QueryMsg::CreatorCollections {
+                            address: USER.to_string(),
+                        },
+                    )
+                    .unwrap();
+                assert_eq!(res.data, 1);
+            }
         }
     }
 }
@@ -130,12 +130,14 @@ pub fn proper_instantiate(
     let collection_config = CollectionConfig {
         per_address_limit,
         start_time,
+        end_time: None,
         max_token_limit,
         ipfs_link,
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -157,6 +159,7 @@ pub fn proper_instantiate(
         token_info,
         metadata_info,
         linked_collections: None,
+        metadata_shuffle: None,
     };
     app.execute_contract(
         Addr::unchecked(ADMIN),
@@ -3,7 +3,7 @@ use cw721_base::msg::QueryMsg as Cw721QueryMsg;
 use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
 use komple_framework_metadata_module::msg::InstantiateMsg as MetadataInstantiateMsg;
 use komple_framework_mint_module::msg::{
-    CollectionFundInfo, CollectionsResponse, ExecuteMsg, QueryMsg,
+    CollectionFundInfo, CollectionInfoMsg, CollectionsResponse, ExecuteMsg, QueryMsg,
 };
 use komple_framework_mint_module::state::CollectionInfo;
 use komple_framework_mint_module::ContractError;
@@ -120,12 +120,14 @@ fn setup_collection(
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -142,6 +144,7 @@ fn setup_collection(
         token_info,
         fund_info,
         linked_collections,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(sender, minter_addr.clone(), &msg, &[])
@@ -186,7 +189,8 @@ mod actions {
 
             let msg = ExecuteMsg::Mint {
                 collection_id: 1,
-                metadata_id: None,
+                quantity: 1,
+                metadata_ids: None,
             };
             let _ = app
                 .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
@@ -222,7 +226,8 @@ mod actions {
 
             let msg = ExecuteMsg::Mint {
                 collection_id: 1,
-                metadata_id: None,
+                quantity: 1,
+                metadata_ids: None,
             };
             let err = app
                 .execute_contract(Addr::unchecked(USER), minter_addr, &msg, &[])
@@ -234,6 +239,692 @@ mod actions {
         }
     }
 
+    mod admin_mint_batch {
+        use super::*;
+        use cw721::OwnerOfResponse;
+        use komple_framework_mint_module::state::MintBatch;
+        use komple_framework_token_module::msg::QueryMsg as TokenQueryMsg;
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let mut recipients: Vec<(String, Option<u32>)> = (1..55)
+                .map(|index| (format!("juno..user{}", index), None))
+                .collect();
+            recipients.insert(0, (USER.to_string(), None));
+            recipients.push((USER.to_string(), None));
+
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PendingMintBatch { collection_id: 1 };
+            let res: ResponseWrapper<Option<MintBatch>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            let mint_batch = res.data.unwrap();
+            assert_eq!(mint_batch.cursor, 50);
+            assert_eq!(mint_batch.recipients.len(), 56);
+
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients: vec![(USER.to_string(), None)],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::PendingMintBatch {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ResumeAdminMintBatch {
+                collection_id: 1,
+                limit: None,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PendingMintBatch { collection_id: 1 };
+            let res: ResponseWrapper<Option<MintBatch>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data, None);
+
+            let msg = QueryMsg::CollectionAddress { collection_id: 1 };
+            let res: ResponseWrapper<String> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            let token_address = res.data;
+
+            let msg: Cw721QueryMsg<TokenQueryMsg> = Cw721QueryMsg::OwnerOf {
+                token_id: "56".to_string(),
+                include_expired: None,
+            };
+            let res: OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(token_address.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.owner, USER.to_string());
+
+            let msg: Cw721QueryMsg<TokenQueryMsg> = Cw721QueryMsg::Extension {
+                msg: TokenQueryMsg::MintedTokensPerAddress {
+                    address: USER.to_string(),
+                },
+            };
+            let res: ResponseWrapper<u32> =
+                app.wrap().query_wasm_smart(token_address, &msg).unwrap();
+            assert_eq!(res.data, 2);
+
+            let msg = ExecuteMsg::ResumeAdminMintBatch {
+                collection_id: 1,
+                limit: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoPendingMintBatch {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_resume_limit_and_cancel() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let recipients: Vec<(String, Option<u32>)> = (1..61)
+                .map(|index| (format!("juno..user{}", index), None))
+                .collect();
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::ResumeAdminMintBatch {
+                collection_id: 1,
+                limit: Some(0),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidQuantity {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ResumeAdminMintBatch {
+                collection_id: 1,
+                limit: Some(4),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PendingMintBatch { collection_id: 1 };
+            let res: ResponseWrapper<Option<MintBatch>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.unwrap().cursor, 54);
+
+            let msg = ExecuteMsg::CancelAdminMintBatch { collection_id: 1 };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::PendingMintBatch { collection_id: 1 };
+            let res: ResponseWrapper<Option<MintBatch>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data, None);
+
+            // A new batch can be started after the cancel
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients: vec![(USER.to_string(), None)],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::CancelAdminMintBatch { collection_id: 1 };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoPendingMintBatch {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients: vec![(USER.to_string(), None)],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+
+    mod metadata_shuffle {
+        use super::*;
+        use cosmwasm_std::to_vec;
+        use komple_framework_metadata_module::msg::{
+            ExecuteMsg as MetadataExecuteMsg, MetadataResponse, QueryMsg as MetadataQueryMsg,
+        };
+        use komple_framework_metadata_module::state::{MetaInfo, Metadata};
+        use komple_framework_mint_module::msg::{MetadataShuffleInfo, ProvenanceResponse};
+        use komple_framework_mint_module::state::{MetadataShuffle, ShuffledMint};
+        use komple_framework_token_module::msg::QueryMsg as TokenQueryMsg;
+        use komple_framework_token_module::ContractError as TokenContractError;
+        use komple_framework_types::modules::token::SubModules;
+        use sha2::{Digest, Sha256};
+
+        fn meta_info(index: u32) -> MetaInfo {
+            MetaInfo {
+                image: Some(format!("ipfs://xyz/{}", index)),
+                external_url: None,
+                description: None,
+                animation_url: None,
+                youtube_url: None,
+            }
+        }
+
+        // Provenance hash of the five raw metadata added to the collection
+        fn provenance_hash() -> String {
+            let mut provenance_hash = vec![];
+            for index in 1..=5 {
+                let metadata = Metadata {
+                    meta_info: meta_info(index),
+                    attributes: vec![],
+                };
+                let mut hasher = Sha256::new();
+                hasher.update(provenance_hash);
+                hasher.update(Sha256::digest(to_vec(&metadata).unwrap()));
+                provenance_hash = hasher.finalize().to_vec();
+            }
+            hex::encode(provenance_hash)
+        }
+
+        // Seeds in reveal order, each seed is the sha256 hash of the next one
+        // Returns the seeds and the seed commitment
+        fn seed_chain() -> (Vec<String>, String) {
+            let mut seeds = vec!["secret".to_string()];
+            for _ in 0..2 {
+                let seed = hex::encode(Sha256::digest(seeds[0].as_bytes()));
+                seeds.insert(0, seed);
+            }
+            let seed_commitment = hex::encode(Sha256::digest(seeds[0].as_bytes()));
+            (seeds, seed_commitment)
+        }
+
+        fn create_collection_msg(
+            app: &mut App,
+            minter_addr: &Addr,
+            collection_type: Collections,
+            metadata_shuffle: MetadataShuffleInfo,
+        ) -> ExecuteMsg {
+            let token_code_id = app.store_code(token_module());
+            let metadata_code_id = app.store_code(metadata_module());
+
+            let metadata_type = match collection_type {
+                Collections::Standard => MetadataType::Standard,
+                _ => MetadataType::Shared,
+            };
+            ExecuteMsg::CreateCollection {
+                code_id: token_code_id,
+                collection_config: CollectionConfig {
+                    per_address_limit: None,
+                    start_time: None,
+                    end_time: None,
+                    max_token_limit: None,
+                    ipfs_link: Some("some-link".to_string()),
+                },
+                collection_info: CollectionInfo {
+                    collection_type,
+                    name: "Test Collection".to_string(),
+                    description: "Test Description".to_string(),
+                    image: "ipfs://xyz".to_string(),
+                    external_link: None,
+                },
+                metadata_info: MetadataInfo {
+                    instantiate_msg: MetadataInstantiateMsg {
+                        metadata_type,
+                        reveal: None,
+                    },
+                    code_id: metadata_code_id,
+                },
+                token_info: TokenInfo {
+                    symbol: "TEST".to_string(),
+                    minter: minter_addr.to_string(),
+                },
+                fund_info: CollectionFundInfo {
+                    is_native: true,
+                    denom: NATIVE_DENOM.to_string(),
+                    cw20_address: None,
+                },
+                linked_collections: None,
+                metadata_shuffle: Some(metadata_shuffle),
+            }
+        }
+
+        // Creates a shuffled shared metadata collection with five raw metadata
+        // Returns the metadata address
+        fn setup_shuffled_collection(
+            app: &mut App,
+            minter_addr: &Addr,
+            per_address_limit: Option<u32>,
+        ) -> Addr {
+            let mut msg = create_collection_msg(
+                app,
+                minter_addr,
+                Collections::Komple,
+                MetadataShuffleInfo {
+                    provenance_hash: provenance_hash(),
+                    pool_size: 5,
+                    seed_commitment: seed_chain().1,
+                },
+            );
+            if let ExecuteMsg::CreateCollection {
+                collection_config, ..
+            } = &mut msg
+            {
+                collection_config.per_address_limit = per_address_limit;
+            }
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::CollectionAddress { collection_id: 1 };
+            let res: ResponseWrapper<String> =
+                app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
+            let msg: Cw721QueryMsg<TokenQueryMsg> = Cw721QueryMsg::Extension {
+                msg: TokenQueryMsg::SubModules {},
+            };
+            let res: ResponseWrapper<SubModules> =
+                app.wrap().query_wasm_smart(res.data, &msg).unwrap();
+            let metadata_addr = res.data.metadata.unwrap();
+
+            for index in 1..=5 {
+                let msg = MetadataExecuteMsg::AddMetadata {
+                    meta_info: meta_info(index),
+                    attributes: vec![],
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), metadata_addr.clone(), &msg, &[])
+                    .unwrap();
+            }
+
+            metadata_addr
+        }
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            let metadata_addr = setup_shuffled_collection(&mut app, &minter_addr, None);
+
+            let msg = QueryMsg::MetadataShuffle { collection_id: 1 };
+            let res: ResponseWrapper<Option<MetadataShuffle>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            let metadata_shuffle = res.data.unwrap();
+            assert_eq!(metadata_shuffle.provenance_hash, provenance_hash());
+            assert_eq!(metadata_shuffle.pool_size, 5);
+            assert_eq!(metadata_shuffle.remaining, 5);
+
+            // Provenance is checked in pages
+            let msg = QueryMsg::VerifyProvenance {
+                collection_id: 1,
+                start_after: None,
+                provenance_hash: None,
+                limit: Some(3),
+            };
+            let res: ResponseWrapper<ProvenanceResponse> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.metadata_id, 3);
+            assert_eq!(res.data.verified, None);
+
+            let msg = QueryMsg::VerifyProvenance {
+                collection_id: 1,
+                start_after: Some(res.data.metadata_id),
+                provenance_hash: Some(res.data.provenance_hash),
+                limit: Some(3),
+            };
+            let res: ResponseWrapper<ProvenanceResponse> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.metadata_id, 5);
+            assert_eq!(res.data.verified, Some(true));
+
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: Some(vec![1]),
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::MetadataIdNotAllowed {}.to_string()
+            );
+
+            // Public mints are reserved and drawn in a later block
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 3,
+                metadata_ids: None,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::ShuffledMint {
+                collection_id: 1,
+                address: USER.to_string(),
+            };
+            let res: ResponseWrapper<Option<ShuffledMint>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(
+                res.data,
+                Some(ShuffledMint {
+                    quantity: 3,
+                    height: app.block_info().height,
+                })
+            );
+
+            // Mints of the reveal block are not drawn
+            let (seeds, _) = seed_chain();
+            let reveal_msg = |seed: &str| ExecuteMsg::RevealShuffleSeed {
+                collection_id: 1,
+                seed: seed.to_string(),
+                limit: None,
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    minter_addr.clone(),
+                    &reveal_msg(&seeds[0]),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoShuffledMint {}.to_string()
+            );
+
+            let msg = ExecuteMsg::AdminMintBatch {
+                collection_id: 1,
+                recipients: vec![(USER.to_string(), None), (ADMIN.to_string(), None)],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            // Reserved ids cannot be drawn by other mints
+            let msg = ExecuteMsg::AdminMint {
+                collection_id: 1,
+                recipient: USER.to_string(),
+                metadata_id: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::MetadataPoolEmpty {}.to_string()
+            );
+
+            app.update_block(|block| block.height += 1);
+
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    minter_addr.clone(),
+                    &reveal_msg(&seeds[1]),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidShuffleSeed {}.to_string()
+            );
+
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    minter_addr.clone(),
+                    &reveal_msg(&seeds[0]),
+                    &[],
+                )
+                .unwrap();
+
+            // Revealed seed cannot be used again
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    minter_addr.clone(),
+                    &reveal_msg(&seeds[0]),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidShuffleSeed {}.to_string()
+            );
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(ADMIN),
+                    minter_addr.clone(),
+                    &reveal_msg(&seeds[1]),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoShuffledMint {}.to_string()
+            );
+
+            let mut metadata_ids: Vec<u32> = (1..=5)
+                .map(|token_id| {
+                    let msg = MetadataQueryMsg::Metadata { token_id };
+                    let res: ResponseWrapper<MetadataResponse> = app
+                        .wrap()
+                        .query_wasm_smart(metadata_addr.clone(), &msg)
+                        .unwrap();
+                    assert_eq!(res.data.metadata.meta_info, meta_info(res.data.metadata_id));
+                    res.data.metadata_id
+                })
+                .collect();
+            metadata_ids.sort_unstable();
+            assert_eq!(metadata_ids, vec![1, 2, 3, 4, 5]);
+
+            let msg = MetadataExecuteMsg::UpdateMetaInfo {
+                raw_metadata: true,
+                id: 2,
+                meta_info: meta_info(6),
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), metadata_addr, &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::VerifyProvenance {
+                collection_id: 1,
+                start_after: None,
+                provenance_hash: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<ProvenanceResponse> =
+                app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
+            assert_eq!(res.data.verified, Some(false));
+        }
+
+        #[test]
+        fn test_reservation_limits() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+
+            let _ = setup_shuffled_collection(&mut app, &minter_addr, Some(2));
+
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 2,
+                metadata_ids: None,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            // Token module limits are checked before the payment is taken
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().source().unwrap().to_string(),
+                TokenContractError::TokenLimitReached {}.to_string()
+            );
+
+            let msg = QueryMsg::MetadataShuffle { collection_id: 1 };
+            let res: ResponseWrapper<Option<MetadataShuffle>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.unwrap().pending, 2);
+
+            // Paid reservations are still drawn after the collection is archived
+            let msg = ExecuteMsg::ArchiveCollection { collection_id: 1 };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+            app.update_block(|block| block.height += 1);
+
+            let msg = ExecuteMsg::RevealShuffleSeed {
+                collection_id: 1,
+                seed: seed_chain().0[0].clone(),
+                limit: None,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::MetadataShuffle { collection_id: 1 };
+            let res: ResponseWrapper<Option<MetadataShuffle>> =
+                app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
+            let metadata_shuffle = res.data.unwrap();
+            assert_eq!(metadata_shuffle.pending, 0);
+            assert_eq!(metadata_shuffle.remaining, 3);
+        }
+
+        #[test]
+        fn test_invalid_metadata_shuffle() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+
+            let msg = create_collection_msg(
+                &mut app,
+                &minter_addr,
+                Collections::Komple,
+                MetadataShuffleInfo {
+                    provenance_hash: "invalid".to_string(),
+                    pool_size: 5,
+                    seed_commitment: seed_chain().1,
+                },
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidMetadataShuffle {}.to_string()
+            );
+
+            let msg = create_collection_msg(
+                &mut app,
+                &minter_addr,
+                Collections::Komple,
+                MetadataShuffleInfo {
+                    provenance_hash: provenance_hash(),
+                    pool_size: 0,
+                    seed_commitment: seed_chain().1,
+                },
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidMetadataShuffle {}.to_string()
+            );
+
+            // Standard collections cannot be shuffled
+            let msg = create_collection_msg(
+                &mut app,
+                &minter_addr,
+                Collections::Standard,
+                MetadataShuffleInfo {
+                    provenance_hash: provenance_hash(),
+                    pool_size: 5,
+                    seed_commitment: seed_chain().1,
+                },
+            );
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::InvalidMetadataShuffle {}.to_string()
+            );
+        }
+    }
+
     mod locks {
         use super::*;
 
@@ -286,12 +977,14 @@ mod actions {
                 let collection_config = CollectionConfig {
                     per_address_limit: None,
                     start_time: None,
+                    end_time: None,
                     max_token_limit: None,
                     ipfs_link: Some("some-link".to_string()),
                 };
                 let metadata_info = MetadataInfo {
                     instantiate_msg: MetadataInstantiateMsg {
                         metadata_type: MetadataType::Standard,
+                        reveal: None,
                     },
                     code_id: metadata_code_id,
                 };
@@ -308,6 +1001,7 @@ mod actions {
                     token_info: token_info.clone(),
                     fund_info,
                     linked_collections: None,
+                    metadata_shuffle: None,
                 };
                 let _ = app
                     .execute_contract(
@@ -350,6 +1044,7 @@ mod actions {
                     token_info,
                     fund_info,
                     linked_collections: None,
+                    metadata_shuffle: None,
                 };
                 app.execute_contract(
                     Addr::unchecked(ADMIN),
@@ -381,12 +1076,14 @@ mod actions {
                 let collection_config = CollectionConfig {
                     per_address_limit: None,
                     start_time: None,
+                    end_time: None,
                     max_token_limit: None,
                     ipfs_link: None,
                 };
                 let metadata_info = MetadataInfo {
                     instantiate_msg: MetadataInstantiateMsg {
                         metadata_type: MetadataType::Standard,
+                        reveal: None,
                     },
                     code_id: metadata_code_id,
                 };
@@ -403,6 +1100,7 @@ mod actions {
                     token_info,
                     fund_info,
                     linked_collections: None,
+                    metadata_shuffle: None,
                 };
                 let err = app
                     .execute_contract(Addr::unchecked(USER), minter_addr, &msg, &[])
@@ -435,89 +1133,242 @@ mod actions {
                 let collection_config = CollectionConfig {
                     per_address_limit: None,
                     start_time: None,
+                    end_time: None,
                     max_token_limit: None,
                     ipfs_link: Some("some-link".to_string()),
                 };
                 let metadata_info = MetadataInfo {
                     instantiate_msg: MetadataInstantiateMsg {
                         metadata_type: MetadataType::Standard,
+                        reveal: None,
                     },
                     code_id: metadata_code_id,
                 };
 
-                let fund_info = CollectionFundInfo {
-                    is_native: false,
-                    denom: "invalid".to_string(),
-                    cw20_address: Some(cw20_addr.to_string()),
-                };
-                let create_collection_msg = ExecuteMsg::CreateCollection {
-                    code_id: token_code_id,
-                    collection_config: collection_config.clone(),
-                    collection_info: collection_info.clone(),
-                    metadata_info: metadata_info.clone(),
-                    token_info: token_info.clone(),
-                    fund_info,
-                    linked_collections: None,
+                let fund_info = CollectionFundInfo {
+                    is_native: false,
+                    denom: "invalid".to_string(),
+                    cw20_address: Some(cw20_addr.to_string()),
+                };
+                let create_collection_msg = ExecuteMsg::CreateCollection {
+                    code_id: token_code_id,
+                    collection_config: collection_config.clone(),
+                    collection_info: collection_info.clone(),
+                    metadata_info: metadata_info.clone(),
+                    token_info: token_info.clone(),
+                    fund_info,
+                    linked_collections: None,
+                    metadata_shuffle: None,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        minter_addr.clone(),
+                        &create_collection_msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    FundsError::InvalidCw20Token {}.to_string()
+                );
+
+                let fund_info = CollectionFundInfo {
+                    is_native: false,
+                    denom: CW20_DENOM.to_string(),
+                    cw20_address: None,
+                };
+                let create_collection_msg = ExecuteMsg::CreateCollection {
+                    code_id: token_code_id,
+                    collection_config: collection_config.clone(),
+                    collection_info: collection_info.clone(),
+                    metadata_info: metadata_info.clone(),
+                    token_info: token_info.clone(),
+                    fund_info,
+                    linked_collections: None,
+                    metadata_shuffle: None,
+                };
+                let err = app
+                    .execute_contract(
+                        Addr::unchecked(ADMIN),
+                        minter_addr.clone(),
+                        &create_collection_msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    FundsError::InvalidCw20Token {}.to_string()
+                );
+            }
+
+            #[test]
+            fn test_public_happy_path() {
+                let mut app = mock_app();
+                let minter_addr = proper_instantiate(&mut app);
+
+                let msg = ExecuteMsg::UpdatePublicCollectionCreation {
+                    public_collection_creation: true,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                setup_collection(&mut app, &minter_addr, Addr::unchecked(USER), None);
+
+                // Public collections are blacklisted until whitelisted
+                let msg = QueryMsg::PendingCollections {
+                    start_after: None,
+                    limit: None,
+                };
+                let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                    .wrap()
+                    .query_wasm_smart(minter_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data.len(), 1);
+                assert_eq!(res.data[0].collection_id, 1);
+                assert_eq!(res.data[0].address, "contract1");
+
+                let msg = QueryMsg::Collections {
+                    blacklist: true,
+                    start_after: None,
+                    limit: None,
+                };
+                let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                    .wrap()
+                    .query_wasm_smart(minter_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data.len(), 1);
+
+                let msg = ExecuteMsg::UpdateCollectionStatus {
+                    collection_id: 1,
+                    is_blacklist: false,
+                };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let msg = QueryMsg::PendingCollections {
+                    start_after: None,
+                    limit: None,
+                };
+                let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                    .wrap()
+                    .query_wasm_smart(minter_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data.len(), 0);
+
+                let msg = QueryMsg::CollectionAddress { collection_id: 1 };
+                let res: ResponseWrapper<String> = app
+                    .wrap()
+                    .query_wasm_smart(minter_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data, "contract1");
+
+                // Admin collections are not moderated
+                setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+                let msg = QueryMsg::CollectionAddress { collection_id: 2 };
+                let res: ResponseWrapper<String> =
+                    app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
+                assert_eq!(res.data, "contract3");
+            }
+
+            #[test]
+            fn test_creator_collection_limit() {
+                let mut app = mock_app();
+                let minter_addr = proper_instantiate(&mut app);
+
+                let msg = ExecuteMsg::UpdatePublicCollectionCreation {
+                    public_collection_creation: true,
                 };
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                let msg = ExecuteMsg::UpdateCreatorCollectionLimit { limit: Some(1) };
                 let err = app
-                    .execute_contract(
-                        Addr::unchecked(ADMIN),
-                        minter_addr.clone(),
-                        &create_collection_msg,
-                        &[],
-                    )
+                    .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
                     .unwrap_err();
                 assert_eq!(
                     err.source().unwrap().to_string(),
-                    FundsError::InvalidCw20Token {}.to_string()
+                    ContractError::Unauthorized {}.to_string()
                 );
+                let _ = app
+                    .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                    .unwrap();
 
-                let fund_info = CollectionFundInfo {
-                    is_native: false,
-                    denom: CW20_DENOM.to_string(),
-                    cw20_address: None,
+                setup_collection(&mut app, &minter_addr, Addr::unchecked(USER), None);
+
+                let msg = QueryMsg::CreatorCollections {
+                    address: USER.to_string(),
                 };
-                let create_collection_msg = ExecuteMsg::CreateCollection {
+                let res: ResponseWrapper<u32> = app
+                    .wrap()
+                    .query_wasm_smart(minter_addr.clone(), &msg)
+                    .unwrap();
+                assert_eq!(res.data, 1);
+
+                let token_code_id = app.store_code(token_module());
+                let metadata_code_id = app.store_code(metadata_module());
+                let msg = ExecuteMsg::CreateCollection {
                     code_id: token_code_id,
-                    collection_config: collection_config.clone(),
-                    collection_info: collection_info.clone(),
-                    metadata_info: metadata_info.clone(),
-                    token_info: token_info.clone(),
-                    fund_info,
+                    collection_config: CollectionConfig {
+                        per_address_limit: None,
+                        start_time: None,
+                        end_time: None,
+                        max_token_limit: None,
+                        ipfs_link: Some("some-link".to_string()),
+                    },
+                    collection_info: CollectionInfo {
+                        collection_type: Collections::Standard,
+                        name: "Test Collection".to_string(),
+                        description: "Test Description".to_string(),
+                        image: "ipfs://xyz".to_string(),
+                        external_link: None,
+                    },
+                    metadata_info: MetadataInfo {
+                        instantiate_msg: MetadataInstantiateMsg {
+                            metadata_type: MetadataType::Standard,
+                            reveal: None,
+                        },
+                        code_id: metadata_code_id,
+                    },
+                    token_info: TokenInfo {
+                        symbol: "TEST".to_string(),
+                        minter: minter_addr.to_string(),
+                    },
+                    fund_info: CollectionFundInfo {
+                        is_native: true,
+                        denom: NATIVE_DENOM.to_string(),
+                        cw20_address: None,
+                    },
                     linked_collections: None,
+                    metadata_shuffle: None,
                 };
                 let err = app
-                    .execute_contract(
-                        Addr::unchecked(ADMIN),
-                        minter_addr.clone(),
-                        &create_collection_msg,
-                        &[],
-                    )
+                    .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
                     .unwrap_err();
                 assert_eq!(
                     err.source().unwrap().to_string(),
-                    FundsError::InvalidCw20Token {}.to_string()
+                    ContractError::CreatorCollectionLimitReached {}.to_string()
                 );
-            }
 
-            #[test]
-            fn test_public_happy_path() {
-                let mut app = mock_app();
-                let minter_addr = proper_instantiate(&mut app);
+                // Limit does not apply to the admin
+                setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+                setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
 
-                let msg = ExecuteMsg::UpdatePublicCollectionCreation {
-                    public_collection_creation: true,
-                };
+                let msg = ExecuteMsg::UpdateCreatorCollectionLimit { limit: None };
                 let _ = app
                     .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
                     .unwrap();
 
                 setup_collection(&mut app, &minter_addr, Addr::unchecked(USER), None);
 
-                let msg = QueryMsg::CollectionAddress { collection_id: 1 };
-                let res: ResponseWrapper<String> =
+                let msg = QueryMsg::CreatorCollectionLimit {};
+                let res: ResponseWrapper<Option<u32>> =
                     app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
-                assert_eq!(res.data, "contract1");
+                assert_eq!(res.data, None);
             }
         }
 
@@ -674,6 +1525,250 @@ mod actions {
         }
     }
 
+    mod update_collection_info {
+        use super::*;
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let msg = ExecuteMsg::UpdateCollectionInfo {
+                collection_id: 1,
+                collection_info: CollectionInfoMsg {
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: Some("https://example.com".to_string()),
+                },
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::CollectionInfo { collection_id: 1 };
+            let res: ResponseWrapper<CollectionInfo> =
+                app.wrap().query_wasm_smart(minter_addr, &msg).unwrap();
+            assert_eq!(
+                res.data,
+                CollectionInfo {
+                    collection_type: Collections::Standard,
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: Some("https://example.com".to_string()),
+                }
+            );
+        }
+
+        #[test]
+        fn test_creator_happy_path() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+
+            let msg = ExecuteMsg::UpdatePublicCollectionCreation {
+                public_collection_creation: true,
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(USER), None);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let msg = ExecuteMsg::UpdateCollectionInfo {
+                collection_id: 1,
+                collection_info: CollectionInfoMsg {
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: None,
+                },
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::CollectionInfo { collection_id: 1 };
+            let res: ResponseWrapper<CollectionInfo> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.name, "New Collection");
+
+            // Creator cannot update other collections
+            let msg = ExecuteMsg::UpdateCollectionInfo {
+                collection_id: 2,
+                collection_info: CollectionInfoMsg {
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: None,
+                },
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_absent_collection() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+
+            let msg = ExecuteMsg::UpdateCollectionInfo {
+                collection_id: 1,
+                collection_info: CollectionInfoMsg {
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: None,
+                },
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::CollectionIdNotFound {}.to_string()
+            );
+        }
+    }
+
+    mod archive_collection {
+        use super::*;
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let msg = ExecuteMsg::ArchiveCollection { collection_id: 1 };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::Collections {
+                blacklist: false,
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.len(), 1);
+            assert_eq!(res.data[0].collection_id, 2);
+
+            let msg = QueryMsg::ArchivedCollections {
+                start_after: None,
+                limit: None,
+            };
+            let res: ResponseWrapper<Vec<CollectionsResponse>> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.len(), 1);
+            assert_eq!(res.data[0].collection_id, 1);
+            assert_eq!(res.data[0].address, "contract1");
+
+            let msg = QueryMsg::MintLock { collection_id: 1 };
+            let res: ResponseWrapper<bool> = app
+                .wrap()
+                .query_wasm_smart(minter_addr.clone(), &msg)
+                .unwrap();
+            assert!(res.data);
+
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::LockedMint {}.to_string()
+            );
+
+            let msg = ExecuteMsg::AdminMint {
+                collection_id: 1,
+                recipient: USER.to_string(),
+                metadata_id: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::CollectionArchived {}.to_string()
+            );
+
+            // Archived collections are read-only
+            let msg = ExecuteMsg::UpdateCollectionMintLock {
+                collection_id: 1,
+                lock: false,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::CollectionArchived {}.to_string()
+            );
+
+            let msg = ExecuteMsg::UpdateCollectionInfo {
+                collection_id: 1,
+                collection_info: CollectionInfoMsg {
+                    name: "New Collection".to_string(),
+                    description: "New Description".to_string(),
+                    image: "ipfs://abc".to_string(),
+                    external_link: None,
+                },
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::CollectionArchived {}.to_string()
+            );
+
+            let msg = ExecuteMsg::ArchiveCollection { collection_id: 1 };
+            let err = app
+                .execute_contract(Addr::unchecked(ADMIN), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::CollectionArchived {}.to_string()
+            );
+        }
+
+        #[test]
+        fn test_invalid_admin() {
+            let mut app = mock_app();
+            let minter_addr = proper_instantiate(&mut app);
+            setup_collection(&mut app, &minter_addr, Addr::unchecked(ADMIN), None);
+
+            let msg = ExecuteMsg::ArchiveCollection { collection_id: 1 };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), minter_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+
     mod update_collection_status {
         use super::*;
 
@@ -199,12 +199,14 @@ pub fn create_collection(app: &mut App, mint_module_addr: Addr, token_module_cod
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -221,6 +223,7 @@ pub fn create_collection(app: &mut App, mint_module_addr: Addr, token_module_cod
         token_info,
         fund_info,
         linked_collections: None,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
@@ -230,7 +233,8 @@ pub fn create_collection(app: &mut App, mint_module_addr: Addr, token_module_cod
 pub fn mint_token(app: &mut App, mint_module_addr: Addr, collection_id: u32, sender: &str) {
     let msg = ExecuteMsg::Mint {
         collection_id,
-        metadata_id: None,
+        quantity: 1,
+        metadata_ids: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(sender), mint_module_addr, &msg, &[])
@@ -0,0 +1,455 @@
+use cosmwasm_std::{coin, to_binary, Addr, Coin, Empty, Timestamp, Uint128};
+use cw_multi_test::{App, AppBuilder, Contract, ContractWrapper, Executor};
+use komple_framework_hub_module::msg::{
+    ExecuteMsg as HubExecuteMsg, InstantiateMsg as HubInstantiateMsg,
+};
+use komple_framework_hub_module::state::HubInfo;
+use komple_framework_metadata_module::msg::InstantiateMsg as MetadataInstantiateMsg;
+use komple_framework_mint_module::msg::{CollectionFundInfo, ExecuteMsg, MintPhaseMsg, QueryMsg};
+use komple_framework_mint_module::state::{CollectionInfo, Eligibility, MintPhase};
+use komple_framework_mint_module::ContractError;
+use komple_framework_token_module::msg::{MetadataInfo, TokenInfo};
+use komple_framework_token_module::state::CollectionConfig;
+use komple_framework_types::modules::metadata::Metadata as MetadataType;
+use komple_framework_types::modules::mint::Collections;
+use komple_framework_types::modules::Modules;
+use komple_framework_types::shared::query::ResponseWrapper;
+use komple_framework_types::shared::RegisterMsg;
+use komple_framework_utils::storage::StorageHelper;
+use sha2::{Digest, Sha256};
+
+pub const USER: &str = "juno..user";
+pub const USER2: &str = "juno..user2";
+pub const ADMIN: &str = "juno..admin";
+pub const NATIVE_DENOM: &str = "native_denom";
+
+pub fn hub_module() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        komple_framework_hub_module::contract::execute,
+        komple_framework_hub_module::contract::instantiate,
+        komple_framework_hub_module::contract::query,
+    )
+    .with_reply(komple_framework_hub_module::contract::reply);
+    Box::new(contract)
+}
+
+pub fn mint_module() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        komple_framework_mint_module::contract::execute,
+        komple_framework_mint_module::contract::instantiate,
+        komple_framework_mint_module::contract::query,
+    )
+    .with_reply(komple_framework_mint_module::contract::reply);
+    Box::new(contract)
+}
+
+pub fn token_module() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        komple_framework_token_module::contract::execute,
+        komple_framework_token_module::contract::instantiate,
+        komple_framework_token_module::contract::query,
+    )
+    .with_reply(komple_framework_token_module::contract::reply);
+    Box::new(contract)
+}
+
+pub fn metadata_module() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        komple_framework_metadata_module::contract::execute,
+        komple_framework_metadata_module::contract::instantiate,
+        komple_framework_metadata_module::contract::query,
+    );
+    Box::new(contract)
+}
+
+pub fn mock_app() -> App {
+    AppBuilder::new().build(|router, _, storage| {
+        for addr in [USER, USER2] {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(addr),
+                    vec![Coin {
+                        denom: NATIVE_DENOM.to_string(),
+                        amount: Uint128::new(1_000),
+                    }],
+                )
+                .unwrap();
+        }
+    })
+}
+
+fn setup_mint_module(app: &mut App) -> Addr {
+    let hub_code_id = app.store_code(hub_module());
+    let msg = HubInstantiateMsg {
+        hub_info: HubInfo {
+            name: "Test Hub".to_string(),
+            description: "Test Hub".to_string(),
+            image: "https://example.com/image.png".to_string(),
+            external_link: None,
+        },
+        marbu_fee_module: None,
+    };
+    let register_msg = RegisterMsg {
+        admin: ADMIN.to_string(),
+        data: Some(to_binary(&msg).unwrap()),
+    };
+    let hub_addr = app
+        .instantiate_contract(
+            hub_code_id,
+            Addr::unchecked(ADMIN),
+            &register_msg,
+            &[],
+            "test",
+            None,
+        )
+        .unwrap();
+
+    let mint_code_id = app.store_code(mint_module());
+    let msg = HubExecuteMsg::RegisterModule {
+        module: Modules::Mint.to_string(),
+        msg: Some(
+            to_binary(&RegisterMsg {
+                admin: ADMIN.to_string(),
+                data: None,
+            })
+            .unwrap(),
+        ),
+        code_id: mint_code_id,
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), hub_addr.clone(), &msg, &[])
+        .unwrap();
+
+    StorageHelper::query_module_address(&app.wrap(), &hub_addr, Modules::Mint.to_string()).unwrap()
+}
+
+fn create_collection(app: &mut App, mint_module_addr: &Addr) {
+    let token_code_id = app.store_code(token_module());
+    let metadata_code_id = app.store_code(metadata_module());
+    let msg = ExecuteMsg::CreateCollection {
+        code_id: token_code_id,
+        collection_info: CollectionInfo {
+            collection_type: Collections::Standard,
+            name: "Test Collection".to_string(),
+            description: "Test Collection".to_string(),
+            image: "https://image.com".to_string(),
+            external_link: None,
+        },
+        collection_config: CollectionConfig {
+            per_address_limit: None,
+            start_time: None,
+            end_time: None,
+            max_token_limit: None,
+            ipfs_link: Some("some-link".to_string()),
+        },
+        token_info: TokenInfo {
+            symbol: "TEST".to_string(),
+            minter: mint_module_addr.to_string(),
+        },
+        metadata_info: MetadataInfo {
+            instantiate_msg: MetadataInstantiateMsg {
+                metadata_type: MetadataType::Standard,
+                reveal: None,
+            },
+            code_id: metadata_code_id,
+        },
+        fund_info: CollectionFundInfo {
+            is_native: true,
+            denom: NATIVE_DENOM.to_string(),
+            cw20_address: None,
+        },
+        linked_collections: None,
+        metadata_shuffle: None,
+    };
+    app.execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+        .unwrap();
+}
+
+fn phase(name: &str, start_time: Timestamp, end_time: Option<Timestamp>) -> MintPhaseMsg {
+    MintPhaseMsg {
+        name: name.to_string(),
+        start_time,
+        end_time,
+        price: Uint128::zero(),
+        fund_info: None,
+        per_address_limit: None,
+        max_token_limit: None,
+        eligibility: None,
+    }
+}
+
+fn hash(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(data).into()
+}
+
+// Merkle tree with the user addresses as leaves
+fn merkle_root_and_proof() -> (String, Vec<String>) {
+    let leaf = hash(USER.as_bytes());
+    let sibling = hash(USER2.as_bytes());
+    let root = match leaf <= sibling {
+        true => hash(&[leaf, sibling].concat()),
+        false => hash(&[sibling, leaf].concat()),
+    };
+    (hex::encode(root), vec![hex::encode(sibling)])
+}
+
+mod actions {
+    use super::*;
+
+    mod mint_phases {
+        use super::*;
+
+        #[test]
+        fn test_happy_path() {
+            let mut app = mock_app();
+            let mint_module_addr = setup_mint_module(&mut app);
+            create_collection(&mut app, &mint_module_addr);
+
+            let now = app.block_info().time;
+            let (root, proof) = merkle_root_and_proof();
+
+            let mut og_phase = phase("og", now.plus_seconds(10), Some(now.plus_seconds(20)));
+            og_phase.price = Uint128::new(10);
+            og_phase.per_address_limit = Some(1);
+            og_phase.eligibility = Some(Eligibility::MerkleRoot { root });
+            let mut public_phase = phase("public", now.plus_seconds(20), None);
+            public_phase.price = Uint128::new(20);
+            public_phase.max_token_limit = Some(2);
+
+            let msg = ExecuteMsg::UpdateMintPhases {
+                collection_id: 1,
+                phases: vec![og_phase, public_phase],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = QueryMsg::Phases { collection_id: 1 };
+            let res: ResponseWrapper<Vec<MintPhase>> = app
+                .wrap()
+                .query_wasm_smart(mint_module_addr.clone(), &msg)
+                .unwrap();
+            assert_eq!(res.data.len(), 2);
+            assert_eq!(res.data[0].name, "og");
+            assert_eq!(res.data[1].name, "public");
+
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(10, NATIVE_DENOM)],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NoActivePhase {}.to_string()
+            );
+
+            app.update_block(|block| block.time = block.time.plus_seconds(10));
+
+            // Merkle phase needs a proof
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(10, NATIVE_DENOM)],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NotEligible {}.to_string()
+            );
+
+            let msg = ExecuteMsg::MintWithProof {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+                proof,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(10, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(10, NATIVE_DENOM)],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::PhaseAddressLimitReached {}.to_string()
+            );
+
+            app.update_block(|block| block.time = block.time.plus_seconds(10));
+
+            // Public phase uses its own price
+            let msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(10, NATIVE_DENOM)],
+                )
+                .unwrap_err();
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(20, NATIVE_DENOM)],
+                )
+                .unwrap();
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER2),
+                    mint_module_addr.clone(),
+                    &msg,
+                    &[coin(20, NATIVE_DENOM)],
+                )
+                .unwrap();
+
+            let err = app
+                .execute_contract(
+                    Addr::unchecked(USER2),
+                    mint_module_addr,
+                    &msg,
+                    &[coin(20, NATIVE_DENOM)],
+                )
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::PhaseTokenLimitReached {}.to_string()
+            );
+
+            let res = app.wrap().query_balance(ADMIN, NATIVE_DENOM).unwrap();
+            assert_eq!(res.amount, Uint128::new(50));
+        }
+
+        #[test]
+        fn test_holder_eligibility() {
+            let mut app = mock_app();
+            let mint_module_addr = setup_mint_module(&mut app);
+            create_collection(&mut app, &mint_module_addr);
+            create_collection(&mut app, &mint_module_addr);
+
+            let now = app.block_info().time;
+            let mut holder_phase = phase("holder", now, None);
+            holder_phase.eligibility = Some(Eligibility::Holder { collection_id: 1 });
+            let msg = ExecuteMsg::UpdateMintPhases {
+                collection_id: 2,
+                phases: vec![holder_phase],
+            };
+            let _ = app
+                .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                .unwrap();
+
+            let msg = ExecuteMsg::Mint {
+                collection_id: 2,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::NotEligible {}.to_string()
+            );
+
+            let mint_msg = ExecuteMsg::Mint {
+                collection_id: 1,
+                quantity: 1,
+                metadata_ids: None,
+            };
+            let _ = app
+                .execute_contract(
+                    Addr::unchecked(USER),
+                    mint_module_addr.clone(),
+                    &mint_msg,
+                    &[],
+                )
+                .unwrap();
+
+            let _ = app
+                .execute_contract(Addr::unchecked(USER), mint_module_addr, &msg, &[])
+                .unwrap();
+        }
+
+        #[test]
+        fn test_invalid_phases() {
+            let mut app = mock_app();
+            let mint_module_addr = setup_mint_module(&mut app);
+            create_collection(&mut app, &mint_module_addr);
+
+            let now = app.block_info().time;
+
+            let invalid_phases = vec![
+                // Overlapping phases
+                vec![
+                    phase("og", now, Some(now.plus_seconds(20))),
+                    phase("public", now.plus_seconds(10), None),
+                ],
+                // Duplicate names
+                vec![
+                    phase("og", now, Some(now.plus_seconds(10))),
+                    phase("og", now.plus_seconds(10), None),
+                ],
+                // End time before start time
+                vec![phase("og", now.plus_seconds(10), Some(now))],
+                // Invalid merkle root
+                vec![MintPhaseMsg {
+                    eligibility: Some(Eligibility::MerkleRoot {
+                        root: "root".to_string(),
+                    }),
+                    ..phase("og", now, None)
+                }],
+            ];
+            for phases in invalid_phases {
+                let msg = ExecuteMsg::UpdateMintPhases {
+                    collection_id: 1,
+                    phases,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(ADMIN), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidMintPhases {}.to_string()
+                );
+            }
+
+            let msg = ExecuteMsg::UpdateMintPhases {
+                collection_id: 1,
+                phases: vec![phase("og", now, None)],
+            };
+            let err = app
+                .execute_contract(Addr::unchecked(USER), mint_module_addr, &msg, &[])
+                .unwrap_err();
+            assert_eq!(
+                err.source().unwrap().to_string(),
+                ContractError::Unauthorized {}.to_string()
+            );
+        }
+    }
+}
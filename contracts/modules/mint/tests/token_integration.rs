@@ -8,6 +8,7 @@ use komple_framework_metadata_module::{
 use komple_framework_mint_module::{
     msg::{CollectionFundInfo, ExecuteMsg as MintExecuteMsg},
     state::CollectionInfo,
+    ContractError as MintContractError,
 };
 use komple_framework_token_module::msg::{ExecuteMsg, MetadataInfo, QueryMsg, TokenInfo};
 use komple_framework_token_module::state::{CollectionConfig, Config as TokenConfig};
@@ -53,12 +54,14 @@ mod initialization {
         let collection_config = CollectionConfig {
             per_address_limit: Some(5),
             start_time: Some(app.block_info().time.plus_seconds(1)),
+            end_time: None,
             max_token_limit: Some(100),
             ipfs_link: Some("some-link".to_string()),
         };
         let metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Standard,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -81,6 +84,7 @@ mod initialization {
             token_info,
             metadata_info,
             linked_collections: None,
+            metadata_shuffle: None,
         };
         app.execute_contract(
             Addr::unchecked(ADMIN),
@@ -124,12 +128,14 @@ mod initialization {
         let mut collection_config = CollectionConfig {
             per_address_limit: Some(5),
             start_time: Some(app.block_info().time),
+            end_time: None,
             max_token_limit: Some(100),
             ipfs_link: Some("some-link".to_string()),
         };
         let metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Standard,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -151,6 +157,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -184,6 +191,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -227,12 +235,14 @@ mod initialization {
         let collection_config = CollectionConfig {
             per_address_limit: Some(5),
             start_time: Some(app.block_info().time.plus_seconds(1)),
+            end_time: None,
             max_token_limit: Some(0),
             ipfs_link: Some("some-link".to_string()),
         };
         let metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Standard,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -254,6 +264,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -297,12 +308,14 @@ mod initialization {
         let collection_config = CollectionConfig {
             per_address_limit: Some(0),
             start_time: Some(app.block_info().time.plus_seconds(1)),
+            end_time: None,
             max_token_limit: Some(100),
             ipfs_link: Some("some-link".to_string()),
         };
         let metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Standard,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -324,6 +337,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -367,12 +381,14 @@ mod initialization {
         let collection_config = CollectionConfig {
             per_address_limit: Some(5),
             start_time: Some(app.block_info().time.plus_seconds(1)),
+            end_time: None,
             max_token_limit: Some(100),
             ipfs_link: None,
         };
         let metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Standard,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -394,6 +410,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -437,12 +454,14 @@ mod initialization {
         let collection_config = CollectionConfig {
             per_address_limit: Some(5),
             start_time: Some(app.block_info().time.plus_seconds(1)),
+            end_time: None,
             max_token_limit: Some(100),
             ipfs_link: Some("some-link".to_string()),
         };
         let mut metadata_info = MetadataInfo {
             instantiate_msg: MetadataInstantiateMsg {
                 metadata_type: MetadataType::Dynamic,
+                reveal: None,
             },
             code_id: metadata_code_id,
         };
@@ -464,6 +483,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info: metadata_info.clone(),
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -497,6 +517,7 @@ mod initialization {
             token_info: token_info.clone(),
             metadata_info,
             linked_collections: None,
+            metadata_shuffle: None,
         };
         let err = app
             .execute_contract(
@@ -638,7 +659,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -749,6 +771,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: Some(5),
                                 start_time: None,
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: None,
                             },
@@ -789,6 +812,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: Some(0),
                                 start_time: None,
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: None,
                             },
@@ -823,6 +847,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: Some(app.block_info().time.plus_seconds(5)),
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: None,
                             },
@@ -871,6 +896,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: Some(app.block_info().time.plus_seconds(5)),
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: None,
                             },
@@ -896,6 +922,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: Some(genesis_time.plus_seconds(2)),
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: None,
                             },
@@ -911,6 +938,171 @@ mod actions {
                 }
             }
 
+            mod end_time {
+                use super::*;
+
+                #[test]
+                fn test_happy_path() {
+                    let mut app = mock_app();
+                    let (mint_module_addr, token_module_addr) = proper_instantiate(
+                        &mut app,
+                        None,
+                        None,
+                        None,
+                        Some("some-link".to_string()),
+                    );
+
+                    let end_time = app.block_info().time.plus_seconds(10);
+                    let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                        msg: ExecuteMsg::UpdateCollectionConfig {
+                            collection_config: CollectionConfig {
+                                per_address_limit: None,
+                                start_time: None,
+                                end_time: Some(end_time),
+                                max_token_limit: None,
+                                ipfs_link: None,
+                            },
+                        },
+                    };
+                    let _ = app
+                        .execute_contract(
+                            Addr::unchecked(ADMIN),
+                            token_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap();
+
+                    let msg = MintExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 2,
+                        metadata_ids: None,
+                    };
+                    let _ = app
+                        .execute_contract(
+                            Addr::unchecked(USER),
+                            mint_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap();
+
+                    app.update_block(|block| block.time = end_time);
+
+                    let err = app
+                        .execute_contract(Addr::unchecked(USER), mint_module_addr, &msg, &[])
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().source().unwrap().to_string(),
+                        ContractError::MintingEnded {}.to_string()
+                    );
+
+                    // Max token limit can only be lowered to the minted amount
+                    let mut collection_config = CollectionConfig {
+                        per_address_limit: None,
+                        start_time: None,
+                        end_time: Some(end_time),
+                        max_token_limit: Some(5),
+                        ipfs_link: None,
+                    };
+                    let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                        msg: ExecuteMsg::UpdateCollectionConfig {
+                            collection_config: collection_config.clone(),
+                        },
+                    };
+                    let err = app
+                        .execute_contract(
+                            Addr::unchecked(ADMIN),
+                            token_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().to_string(),
+                        ContractError::InvalidMaxTokenLimit {}.to_string()
+                    );
+
+                    collection_config.max_token_limit = Some(2);
+                    let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                        msg: ExecuteMsg::UpdateCollectionConfig { collection_config },
+                    };
+                    let _ = app
+                        .execute_contract(
+                            Addr::unchecked(ADMIN),
+                            token_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap();
+
+                    let msg = Cw721QueryMsg::Extension {
+                        msg: QueryMsg::Config {},
+                    };
+                    let res: ResponseWrapper<TokenConfig> = app
+                        .wrap()
+                        .query_wasm_smart(token_module_addr, &msg)
+                        .unwrap();
+                    assert_eq!(res.data.end_time, Some(end_time));
+                    assert_eq!(res.data.max_token_limit, Some(2));
+                }
+
+                #[test]
+                fn test_invalid_time() {
+                    let mut app = mock_app();
+                    let (_, token_module_addr) = proper_instantiate(
+                        &mut app,
+                        None,
+                        None,
+                        None,
+                        Some("some-link".to_string()),
+                    );
+
+                    let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                        msg: ExecuteMsg::UpdateCollectionConfig {
+                            collection_config: CollectionConfig {
+                                per_address_limit: None,
+                                start_time: None,
+                                end_time: Some(app.block_info().time),
+                                max_token_limit: None,
+                                ipfs_link: None,
+                            },
+                        },
+                    };
+                    let err = app
+                        .execute_contract(
+                            Addr::unchecked(ADMIN),
+                            token_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().to_string(),
+                        ContractError::InvalidEndTime {}.to_string()
+                    );
+
+                    let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                        msg: ExecuteMsg::UpdateCollectionConfig {
+                            collection_config: CollectionConfig {
+                                per_address_limit: None,
+                                start_time: Some(app.block_info().time.plus_seconds(10)),
+                                end_time: Some(app.block_info().time.plus_seconds(5)),
+                                max_token_limit: None,
+                                ipfs_link: None,
+                            },
+                        },
+                    };
+                    let err = app
+                        .execute_contract(Addr::unchecked(ADMIN), token_module_addr, &msg, &[])
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().to_string(),
+                        ContractError::InvalidEndTime {}.to_string()
+                    );
+                }
+            }
+
             mod max_token_limit {
                 use super::*;
 
@@ -930,6 +1122,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: None,
+                                end_time: None,
                                 max_token_limit: Some(5),
                                 ipfs_link: None,
                             },
@@ -970,6 +1163,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: None,
+                                end_time: None,
                                 max_token_limit: Some(0),
                                 ipfs_link: None,
                             },
@@ -1004,6 +1198,7 @@ mod actions {
                             collection_config: CollectionConfig {
                                 per_address_limit: None,
                                 start_time: None,
+                                end_time: None,
                                 max_token_limit: None,
                                 ipfs_link: Some("other-link".to_string()),
                             },
@@ -1040,6 +1235,7 @@ mod actions {
                         collection_config: CollectionConfig {
                             per_address_limit: Some(5),
                             start_time: None,
+                            end_time: None,
                             max_token_limit: None,
                             ipfs_link: None,
                         },
@@ -1070,7 +1266,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1099,7 +1296,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1170,7 +1368,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1207,7 +1406,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr, &msg, &[])
@@ -1240,7 +1440,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1281,7 +1482,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1352,7 +1554,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1413,7 +1616,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let err = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1437,7 +1641,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1445,7 +1650,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(RANDOM), mint_module_addr.clone(), &msg, &[])
@@ -1453,7 +1659,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let err = app
                     .execute_contract(
@@ -1482,7 +1689,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1490,7 +1698,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let _ = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -1498,8 +1707,49 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().source().unwrap().to_string(),
+                    ContractError::TokenLimitReached {}.to_string()
+                );
+            }
+
+            #[test]
+            fn test_batch_mint() {
+                let mut app = mock_app();
+                let (mint_module_addr, token_module_addr) = proper_instantiate(
+                    &mut app,
+                    Some(3),
+                    None,
+                    None,
+                    Some("some-link".to_string()),
+                );
+
+                let msg = MintExecuteMsg::Mint {
+                    collection_id: 1,
+                    quantity: 2,
+                    metadata_ids: None,
                 };
+                let _ = app
+                    .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
+                    .unwrap();
+
+                for token_id in 1..=2 {
+                    let res = StorageHelper::query_token_owner(
+                        &app.wrap(),
+                        &token_module_addr,
+                        &token_id,
+                    )
+                    .unwrap();
+                    assert_eq!(res, Addr::unchecked(USER));
+                }
+
+                // Per address limit is checked for the whole quantity
                 let err = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
                     .unwrap_err();
@@ -1507,6 +1757,132 @@ mod actions {
                     err.source().unwrap().source().unwrap().to_string(),
                     ContractError::TokenLimitReached {}.to_string()
                 );
+
+                let invalid_msgs = vec![
+                    MintExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 0,
+                        metadata_ids: None,
+                    },
+                    MintExecuteMsg::Mint {
+                        collection_id: 1,
+                        quantity: 2,
+                        metadata_ids: Some(vec![1]),
+                    },
+                ];
+                for msg in invalid_msgs {
+                    let err = app
+                        .execute_contract(
+                            Addr::unchecked(RANDOM),
+                            mint_module_addr.clone(),
+                            &msg,
+                            &[],
+                        )
+                        .unwrap_err();
+                    assert_eq!(
+                        err.source().unwrap().to_string(),
+                        MintContractError::InvalidQuantity {}.to_string()
+                    );
+                }
+            }
+
+            #[test]
+            fn test_reserved_mint() {
+                let mut app = mock_app();
+                let (mint_module_addr, token_module_addr) = proper_instantiate(
+                    &mut app,
+                    Some(3),
+                    None,
+                    None,
+                    Some("some-link".to_string()),
+                );
+
+                // Only the minter can reserve and mint the reserved tokens
+                let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                    msg: ExecuteMsg::ReserveMint {
+                        owner: USER.to_string(),
+                        quantity: 2,
+                    },
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), token_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+                let _ = app
+                    .execute_contract(
+                        mint_module_addr.clone(),
+                        token_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap();
+
+                // Reservations count against the per address limit
+                let err = app
+                    .execute_contract(
+                        mint_module_addr.clone(),
+                        token_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::TokenLimitReached {}.to_string()
+                );
+
+                let res = StorageHelper::query_token_owner(&app.wrap(), &token_module_addr, &1);
+                assert!(res.is_err());
+
+                let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                    msg: ExecuteMsg::MintReserved {
+                        owner: USER.to_string(),
+                        metadata_ids: vec![1, 2, 3],
+                    },
+                };
+                let err = app
+                    .execute_contract(
+                        mint_module_addr.clone(),
+                        token_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::InvalidQuantity {}.to_string()
+                );
+
+                let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+                    msg: ExecuteMsg::MintReserved {
+                        owner: USER.to_string(),
+                        metadata_ids: vec![1],
+                    },
+                };
+                let err = app
+                    .execute_contract(Addr::unchecked(USER), token_module_addr.clone(), &msg, &[])
+                    .unwrap_err();
+                assert_eq!(
+                    err.source().unwrap().to_string(),
+                    ContractError::Unauthorized {}.to_string()
+                );
+                let _ = app
+                    .execute_contract(
+                        mint_module_addr.clone(),
+                        token_module_addr.clone(),
+                        &msg,
+                        &[],
+                    )
+                    .unwrap();
+
+                let res =
+                    StorageHelper::query_token_owner(&app.wrap(), &token_module_addr, &1).unwrap();
+                assert_eq!(res, Addr::unchecked(USER));
+                let res = StorageHelper::query_token_owner(&app.wrap(), &token_module_addr, &2);
+                assert!(res.is_err());
             }
 
             #[test]
@@ -1523,7 +1899,8 @@ mod actions {
 
                 let msg = MintExecuteMsg::Mint {
                     collection_id: 1,
-                    metadata_id: None,
+                    quantity: 1,
+                    metadata_ids: None,
                 };
                 let err = app
                     .execute_contract(Addr::unchecked(USER), mint_module_addr.clone(), &msg, &[])
@@ -127,7 +127,8 @@ mod actions {
 
             let msg = MintExecuteMsg::Mint {
                 collection_id: 1,
-                metadata_id: None,
+                quantity: 1,
+                metadata_ids: None,
             };
 
             let _ = app
@@ -23,7 +23,7 @@ use crate::msg::{
 };
 use crate::state::{
     CollectionConfig, Config, COLLECTION_TYPE, CONFIG, LOCKS, MINTED_TOKENS_PER_ADDR, OPERATORS,
-    PARENT_ADDR, SUB_MODULES, TOKEN_IDS, TOKEN_LOCKS,
+    PARENT_ADDR, RESERVED_TOKEN_IDS, SUB_MODULES, TOKEN_IDS, TOKEN_LOCKS,
 };
 
 use cw721::ContractInfoResponse;
@@ -65,6 +65,11 @@ pub fn instantiate(
     {
         return Err(ContractError::InvalidStartTime {});
     };
+    check_end_time(
+        &env,
+        data.collection_config.start_time,
+        data.collection_config.end_time,
+    )?;
     if data.collection_config.max_token_limit.is_some()
         && data.collection_config.max_token_limit.unwrap() <= 0
     {
@@ -95,6 +100,7 @@ pub fn instantiate(
         admin,
         creator,
         start_time: data.collection_config.start_time,
+        end_time: data.collection_config.end_time,
         max_token_limit: data.collection_config.max_token_limit,
         per_address_limit: data.collection_config.per_address_limit,
         ipfs_link: data.collection_config.ipfs_link,
@@ -178,6 +184,18 @@ pub fn execute(
             TokenExecuteMsg::Mint { owner, metadata_id } => {
                 execute_mint(deps, env, info, owner, metadata_id)
             }
+            TokenExecuteMsg::MintBatch {
+                owner,
+                quantity,
+                metadata_ids,
+            } => execute_mint_batch(deps, env, info, owner, quantity, metadata_ids),
+            TokenExecuteMsg::ReserveMint { owner, quantity } => {
+                execute_reserve_mint(deps, env, info, owner, quantity)
+            }
+            TokenExecuteMsg::MintReserved {
+                owner,
+                metadata_ids,
+            } => execute_mint_reserved(deps, env, info, owner, metadata_ids),
             TokenExecuteMsg::Burn { token_id } => execute_burn(deps, env, info, token_id),
             TokenExecuteMsg::TransferNft {
                 token_id,
@@ -315,35 +333,211 @@ pub fn execute_update_token_locks(
 }
 
 pub fn execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     owner: String,
     metadata_id: Option<u32>,
 ) -> Result<Response, ContractError> {
+    let token_id = check_mint(&mut deps, &env, &owner, 1)?;
+
+    let (res, msgs) = mint_token(deps, &env, &info, &owner, token_id, metadata_id)?;
+
+    Ok(res
+        .add_messages(msgs)
+        .add_attribute("name", "komple_framework")
+        .add_attribute("module", "token")
+        .add_attribute("action", "mint")
+        .add_event(
+            EventHelper::new("token_mint")
+                .add_attribute("token_id", token_id.to_string())
+                .add_attribute("owner", owner)
+                .check_add_attribute(
+                    &metadata_id,
+                    "metadata_id",
+                    metadata_id.unwrap_or(0).to_string(),
+                )
+                .get(),
+        ))
+}
+
+pub fn execute_mint_batch(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    quantity: u32,
+    metadata_ids: Option<Vec<u32>>,
+) -> Result<Response, ContractError> {
+    if quantity == 0 || matches!(&metadata_ids, Some(ids) if ids.len() != quantity as usize) {
+        return Err(ContractError::InvalidQuantity {});
+    }
+
+    let first_token_id = check_mint(&mut deps, &env, &owner, quantity)?;
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut token_ids: Vec<String> = vec![];
+    for index in 0..quantity {
+        let token_id = first_token_id + index;
+        let metadata_id = metadata_ids.as_ref().map(|ids| ids[index as usize]);
+
+        let (_, mut token_msgs) =
+            mint_token(deps.branch(), &env, &info, &owner, token_id, metadata_id)?;
+        msgs.append(&mut token_msgs);
+        token_ids.push(token_id.to_string());
+    }
+
+    let metadata_ids = metadata_ids.map(|ids| {
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    });
+
+    Ok(ResponseHelper::new_module("token", "mint_batch")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("token_mint_batch")
+                .add_attribute("owner", owner)
+                .add_attribute("quantity", quantity.to_string())
+                .add_attribute("token_ids", token_ids.join(","))
+                .check_add_attribute(
+                    &metadata_ids,
+                    "metadata_ids",
+                    metadata_ids.clone().unwrap_or_default(),
+                )
+                .get(),
+        ))
+}
+
+pub fn execute_reserve_mint(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    quantity: u32,
+) -> Result<Response, ContractError> {
+    let minter = Cw721Contract::default().minter.load(deps.storage)?;
+    if info.sender != minter {
+        return Err(ContractError::Unauthorized {});
+    }
+    if quantity == 0 {
+        return Err(ContractError::InvalidQuantity {});
+    }
+
+    let owner = deps.api.addr_validate(&owner)?;
+    let first_token_id = check_mint(&mut deps, &env, owner.as_str(), quantity)?;
+
+    let mut reserved_ids = RESERVED_TOKEN_IDS
+        .may_load(deps.storage, owner.as_str())?
+        .unwrap_or_default();
+    reserved_ids.extend(first_token_id..first_token_id + quantity);
+    RESERVED_TOKEN_IDS.save(deps.storage, owner.as_str(), &reserved_ids)?;
+
+    let token_ids = (first_token_id..first_token_id + quantity)
+        .map(|token_id| token_id.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+
+    Ok(
+        ResponseHelper::new_module("token", "reserve_mint").add_event(
+            EventHelper::new("token_reserve_mint")
+                .add_attribute("owner", owner)
+                .add_attribute("quantity", quantity.to_string())
+                .add_attribute("token_ids", token_ids)
+                .get(),
+        ),
+    )
+}
+
+pub fn execute_mint_reserved(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    metadata_ids: Vec<u32>,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let mut reserved_ids = RESERVED_TOKEN_IDS
+        .may_load(deps.storage, owner.as_str())?
+        .unwrap_or_default();
+    if metadata_ids.is_empty() || metadata_ids.len() > reserved_ids.len() {
+        return Err(ContractError::InvalidQuantity {});
+    }
+
+    // Reserved tokens were checked on reservation, only the minter is checked here
+    let token_ids: Vec<u32> = reserved_ids.drain(..metadata_ids.len()).collect();
+    match reserved_ids.is_empty() {
+        true => RESERVED_TOKEN_IDS.remove(deps.storage, owner.as_str()),
+        false => RESERVED_TOKEN_IDS.save(deps.storage, owner.as_str(), &reserved_ids)?,
+    };
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    for (token_id, metadata_id) in token_ids.iter().zip(metadata_ids.iter()) {
+        let (_, mut token_msgs) = mint_token(
+            deps.branch(),
+            &env,
+            &info,
+            owner.as_str(),
+            *token_id,
+            Some(*metadata_id),
+        )?;
+        msgs.append(&mut token_msgs);
+    }
+
+    let join_ids = |ids: &[u32]| {
+        ids.iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+
+    Ok(ResponseHelper::new_module("token", "mint_reserved")
+        .add_messages(msgs)
+        .add_event(
+            EventHelper::new("token_mint_reserved")
+                .add_attribute("owner", owner)
+                .add_attribute("token_ids", join_ids(&token_ids))
+                .add_attribute("metadata_ids", join_ids(&metadata_ids))
+                .get(),
+        ))
+}
+
+// Checks the locks and limits for minting the quantity to the owner
+// Saves the new token counts and returns the first token id to mint
+fn check_mint(
+    deps: &mut DepsMut,
+    env: &Env,
+    owner: &str,
+    quantity: u32,
+) -> Result<u32, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let collection_type = COLLECTION_TYPE.load(deps.storage)?;
 
     let total_minted = MINTED_TOKENS_PER_ADDR
-        .may_load(deps.storage, &owner)?
+        .may_load(deps.storage, owner)?
         .unwrap_or(0);
-    let token_id = (TOKEN_IDS.load(deps.storage)?) + 1;
+    let first_token_id = (TOKEN_IDS.load(deps.storage)?) + 1;
+    let last_token_id = first_token_id + quantity - 1;
 
     let locks = LOCKS.load(deps.storage)?;
     if locks.mint_lock {
         return Err(ContractError::MintLocked {});
     }
 
-    let token_lock = TOKEN_LOCKS.may_load(deps.storage, &token_id.to_string())?;
-    if token_lock.is_some() && token_lock.unwrap().mint_lock {
-        return Err(ContractError::MintLocked {});
+    for token_id in first_token_id..=last_token_id {
+        let token_lock = TOKEN_LOCKS.may_load(deps.storage, &token_id.to_string())?;
+        if token_lock.is_some() && token_lock.unwrap().mint_lock {
+            return Err(ContractError::MintLocked {});
+        }
     }
 
-    if config.max_token_limit.is_some() && token_id > config.max_token_limit.unwrap() {
+    if config.max_token_limit.is_some() && last_token_id > config.max_token_limit.unwrap() {
         return Err(ContractError::TokenLimitReached {});
     }
 
-    if config.per_address_limit.is_some() && total_minted + 1 > config.per_address_limit.unwrap() {
+    if config.per_address_limit.is_some()
+        && total_minted + quantity > config.per_address_limit.unwrap()
+    {
         return Err(ContractError::TokenLimitReached {});
     }
 
@@ -351,33 +545,51 @@ pub fn execute_mint(
         return Err(ContractError::MintingNotStarted {});
     }
 
+    if is_ended(env, &config) {
+        return Err(ContractError::MintingEnded {});
+    }
+
     // Whitelist checks
     let sub_modules = SUB_MODULES.load(deps.storage)?;
     if let Some(whitelist_addr) = sub_modules.whitelist {
         let whitelist_config =
             KompleWhitelistHelper::new(whitelist_addr).query_config(&deps.querier)?;
 
-        if total_minted + 1 > (whitelist_config.per_address_limit as u32) {
+        if total_minted + quantity > (whitelist_config.per_address_limit as u32) {
             return Err(ContractError::TokenLimitReached {});
         }
     };
 
-    let mint_msg = MintMsg {
-        token_id: token_id.to_string(),
-        owner: owner.clone(),
-        token_uri: None,
-        extension: Empty {},
-    };
+    MINTED_TOKENS_PER_ADDR.save(deps.storage, owner, &(total_minted + quantity))?;
+    TOKEN_IDS.save(deps.storage, &last_token_id)?;
+
+    Ok(first_token_id)
+}
 
-    MINTED_TOKENS_PER_ADDR.save(deps.storage, &owner, &(total_minted + 1))?;
-    TOKEN_IDS.save(deps.storage, &token_id)?;
+// Mints the token and creates the metadata messages for it
+fn mint_token(
+    deps: DepsMut,
+    env: &Env,
+    info: &MessageInfo,
+    owner: &str,
+    token_id: u32,
+    metadata_id: Option<u32>,
+) -> Result<(Response, Vec<CosmosMsg>), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let collection_type = COLLECTION_TYPE.load(deps.storage)?;
 
     let sub_modules = SUB_MODULES.load(deps.storage)?;
     if sub_modules.metadata.is_none() {
         return Err(ContractError::MetadataContractNotFound {});
     };
 
-    let res = Cw721Contract::default().mint(deps, env, info, mint_msg);
+    let mint_msg = MintMsg {
+        token_id: token_id.to_string(),
+        owner: owner.to_string(),
+        token_uri: None,
+        extension: Empty {},
+    };
+    let res = Cw721Contract::default().mint(deps, env.clone(), info.clone(), mint_msg)?;
 
     let mut msgs: Vec<CosmosMsg> = vec![];
 
@@ -407,25 +619,7 @@ pub fn execute_mint(
         .link_metadata_msg(token_id, metadata_id)?;
     msgs.push(msg.into());
 
-    match res {
-        Ok(res) => Ok(res
-            .add_messages(msgs)
-            .add_attribute("name", "komple_framework")
-            .add_attribute("module", "token")
-            .add_attribute("action", "mint")
-            .add_event(
-                EventHelper::new("token_mint")
-                    .add_attribute("token_id", token_id.to_string())
-                    .add_attribute("owner", owner)
-                    .check_add_attribute(
-                        &metadata_id,
-                        "metadata_id",
-                        metadata_id.unwrap_or(0).to_string(),
-                    )
-                    .get(),
-            )),
-        Err(e) => Err(e.into()),
-    }
+    Ok((res, msgs))
 }
 
 pub fn execute_burn(
@@ -620,6 +814,25 @@ fn execute_update_collection_config(
         operators,
     )?;
 
+    // After the end time only the unminted supply can be burned
+    // by lowering the max token limit to the minted amount
+    if is_ended(&env, &config) {
+        let token_ids = TOKEN_IDS.load(deps.storage)?;
+        if collection_config.max_token_limit != Some(token_ids) {
+            return Err(ContractError::InvalidMaxTokenLimit {});
+        }
+        config.max_token_limit = Some(token_ids);
+        CONFIG.save(deps.storage, &config)?;
+
+        return Ok(
+            ResponseHelper::new_module("token", "update_collection_config").add_event(
+                EventHelper::new("token_update_collection_config")
+                    .add_attribute("max_token_limit", token_ids.to_string())
+                    .get(),
+            ),
+        );
+    }
+
     // Start time
     if config.start_time.is_some() && env.block.time >= config.start_time.unwrap() {
         return Err(ContractError::AlreadyStarted {});
@@ -634,6 +847,10 @@ fn execute_update_collection_config(
         None => config.start_time = None,
     };
 
+    // End time
+    check_end_time(&env, config.start_time, collection_config.end_time)?;
+    config.end_time = collection_config.end_time;
+
     // Per address limit
     if collection_config.per_address_limit.is_some()
         && collection_config.per_address_limit.unwrap() <= 0
@@ -671,6 +888,14 @@ fn execute_update_collection_config(
                         .unwrap_or(Timestamp::from_nanos(0))
                         .to_string(),
                 )
+                .check_add_attribute(
+                    &config.end_time,
+                    "end_time",
+                    config
+                        .end_time
+                        .unwrap_or(Timestamp::from_nanos(0))
+                        .to_string(),
+                )
                 .check_add_attribute(
                     &config.max_token_limit,
                     "max_token_limit",
@@ -690,6 +915,26 @@ fn execute_update_collection_config(
         ))
 }
 
+// End time must be in the future and after the start time
+fn check_end_time(
+    env: &Env,
+    start_time: Option<Timestamp>,
+    end_time: Option<Timestamp>,
+) -> Result<(), ContractError> {
+    if let Some(end_time) = end_time {
+        if env.block.time >= end_time
+            || matches!(start_time, Some(start_time) if start_time >= end_time)
+        {
+            return Err(ContractError::InvalidEndTime {});
+        }
+    }
+    Ok(())
+}
+
+fn is_ended(env: &Env, config: &Config) -> bool {
+    matches!(config.end_time, Some(end_time) if env.block.time >= end_time)
+}
+
 fn execute_init_whitelist_module(
     deps: DepsMut,
     env: Env,
@@ -33,6 +33,9 @@ pub enum ContractError {
     #[error("Token limit is exceeded")]
     TokenLimitReached {},
 
+    #[error("Invalid mint quantity")]
+    InvalidQuantity {},
+
     #[error("Token not found")]
     TokenNotFound {},
 
@@ -48,6 +51,12 @@ pub enum ContractError {
     #[error("Minting has already started")]
     AlreadyStarted {},
 
+    #[error("Invalid end time")]
+    InvalidEndTime {},
+
+    #[error("Minting has ended")]
+    MintingEnded {},
+
     #[error("Description too long")]
     DescriptionTooLong {},
 
@@ -73,4 +73,49 @@ impl KompleTokenModule {
             funds: vec![],
         })
     }
+
+    pub fn mint_batch_msg(
+        &self,
+        owner: String,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+    ) -> StdResult<WasmMsg> {
+        let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+            msg: ExecuteMsg::MintBatch {
+                owner,
+                quantity,
+                metadata_ids,
+            },
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+
+    pub fn reserve_mint_msg(&self, owner: String, quantity: u32) -> StdResult<WasmMsg> {
+        let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+            msg: ExecuteMsg::ReserveMint { owner, quantity },
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
+
+    pub fn mint_reserved_msg(&self, owner: String, metadata_ids: Vec<u32>) -> StdResult<WasmMsg> {
+        let msg: Cw721ExecuteMsg<Empty, ExecuteMsg> = Cw721ExecuteMsg::Extension {
+            msg: ExecuteMsg::MintReserved {
+                owner,
+                metadata_ids,
+            },
+        };
+        Ok(WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_binary(&msg)?,
+            funds: vec![],
+        })
+    }
 }
@@ -47,6 +47,32 @@ pub enum ExecuteMsg {
         owner: String,
         metadata_id: Option<u32>,
     },
+    /// Minter message.
+    ///
+    /// Mint multiple tokens to the owner at once.
+    /// Limits are checked against the whole quantity.
+    /// Metadata ids must match the quantity if provided.
+    MintBatch {
+        owner: String,
+        quantity: u32,
+        metadata_ids: Option<Vec<u32>>,
+    },
+    /// Minter message.
+    ///
+    /// Reserve token ids for the owner to be minted later with `MintReserved`.
+    /// Locks, limits and minting times are checked on the reservation.
+    ReserveMint {
+        owner: String,
+        quantity: u32,
+    },
+    /// Minter message.
+    ///
+    /// Mint the reserved tokens of the owner in reservation order.
+    /// One token is minted for each metadata id.
+    MintReserved {
+        owner: String,
+        metadata_ids: Vec<u32>,
+    },
     Burn {
         token_id: String,
     },
@@ -8,8 +8,8 @@ use cw_storage_plus::{Item, Map};
 use komple_framework_types::modules::mint::Collections;
 use komple_framework_types::modules::token::{
     Locks, SubModules, COLLECTION_TYPE_NAMESPACE, LOCKS_NAMESPACE,
-    MINTED_TOKENS_PER_ADDR_NAMESPACE, SUB_MODULES_NAMESPACE, TOKEN_IDS_NAMESPACE,
-    TOKEN_LOCKS_NAMESPACE,
+    MINTED_TOKENS_PER_ADDR_NAMESPACE, RESERVED_TOKEN_IDS_NAMESPACE, SUB_MODULES_NAMESPACE,
+    TOKEN_IDS_NAMESPACE, TOKEN_LOCKS_NAMESPACE,
 };
 
 #[cw_serde]
@@ -18,6 +18,7 @@ pub struct Config {
     pub creator: Addr,
     pub per_address_limit: Option<u32>,
     pub start_time: Option<Timestamp>,
+    pub end_time: Option<Timestamp>,
     pub max_token_limit: Option<u32>,
     pub ipfs_link: Option<String>,
 }
@@ -27,6 +28,8 @@ pub const CONFIG: Item<Config> = Item::new(CONFIG_NAMESPACE);
 pub struct CollectionConfig {
     pub per_address_limit: Option<u32>,
     pub start_time: Option<Timestamp>,
+    /// Minting is closed at the end time.
+    pub end_time: Option<Timestamp>,
     pub max_token_limit: Option<u32>,
     pub ipfs_link: Option<String>,
 }
@@ -46,6 +49,9 @@ pub const TOKEN_IDS: Item<u32> = Item::new(TOKEN_IDS_NAMESPACE);
 /// Total number of tokens minted per address.
 pub const MINTED_TOKENS_PER_ADDR: Map<&str, u32> = Map::new(MINTED_TOKENS_PER_ADDR_NAMESPACE);
 
+/// Token ids reserved per address that are not minted yet.
+pub const RESERVED_TOKEN_IDS: Map<&str, Vec<u32>> = Map::new(RESERVED_TOKEN_IDS_NAMESPACE);
+
 /// Address of the mint module.
 pub const PARENT_ADDR: Item<Addr> = Item::new(PARENT_ADDR_NAMESPACE);
 
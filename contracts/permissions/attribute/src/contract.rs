@@ -95,9 +95,15 @@ pub fn execute_check(
             return Err(ContractError::MetadataNotFound {});
         };
 
+        // Tokens are served the unrevealed metadata until the reveal
+        let metadata_module = KompleMetadataModule(sub_modules.metadata.unwrap());
+        let reveal = metadata_module.query_reveal(&deps.querier)?;
+        if matches!(reveal, Some(reveal) if reveal.metadata_root.is_none()) {
+            return Err(ContractError::MetadataNotRevealed {});
+        }
+
         // Query token metadata to get attributes
-        let response = KompleMetadataModule(sub_modules.metadata.unwrap())
-            .query_metadata(&deps.querier, msg.token_id)?;
+        let response = metadata_module.query_metadata(&deps.querier, msg.token_id)?;
         let attributes = response.metadata.attributes;
 
         // Get the attribute value
@@ -10,6 +10,9 @@ pub enum ContractError {
     #[error("Metadata not found")]
     MetadataNotFound {},
 
+    #[error("Metadata is not revealed")]
+    MetadataNotRevealed {},
+
     #[error("Attribute not found")]
     AttributeNotFound {},
 
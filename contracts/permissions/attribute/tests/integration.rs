@@ -195,12 +195,14 @@ pub fn create_collection(app: &mut App, mint_module_addr: Addr, token_module_cod
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Dynamic,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -217,6 +219,7 @@ pub fn create_collection(app: &mut App, mint_module_addr: Addr, token_module_cod
         token_info,
         fund_info,
         linked_collections: None,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
@@ -232,7 +235,8 @@ pub fn mint_token(
 ) {
     let msg = MintExecuteMsg::Mint {
         collection_id,
-        metadata_id: Some(metadata_id),
+        quantity: 1,
+        metadata_ids: Some(vec![metadata_id]),
     };
     let _ = app
         .execute_contract(Addr::unchecked(sender), mint_module_addr, &msg, &[])
@@ -208,12 +208,14 @@ pub fn create_collection(
     let collection_config = CollectionConfig {
         per_address_limit: None,
         start_time: None,
+        end_time: None,
         max_token_limit: None,
         ipfs_link: Some("some-link".to_string()),
     };
     let metadata_info = MetadataInfo {
         instantiate_msg: MetadataInstantiateMsg {
             metadata_type: MetadataType::Standard,
+            reveal: None,
         },
         code_id: metadata_code_id,
     };
@@ -230,6 +232,7 @@ pub fn create_collection(
         token_info,
         fund_info,
         linked_collections,
+        metadata_shuffle: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(ADMIN), mint_module_addr, &msg, &[])
@@ -239,7 +242,8 @@ pub fn create_collection(
 pub fn mint_token(app: &mut App, mint_module_addr: Addr, collection_id: u32, sender: &str) {
     let msg = MintExecuteMsg::Mint {
         collection_id,
-        metadata_id: None,
+        quantity: 1,
+        metadata_ids: None,
     };
     let _ = app
         .execute_contract(Addr::unchecked(sender), mint_module_addr, &msg, &[])
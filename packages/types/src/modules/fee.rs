@@ -1,23 +1,99 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Timestamp, Uint128};
 
 /// The different types of fees.
 ///
-/// Currently only percentage and fixed fees are supported.
+/// Tiered fees are percentages that depend on the sale amount.
 #[cw_serde]
 pub enum Fees {
     Fixed,
     Percentage,
+    Tiered,
 }
 impl Fees {
     pub fn as_str(&self) -> &'static str {
         match self {
             Fees::Fixed => "fixed",
             Fees::Percentage => "percentage",
+            Fees::Tiered => "tiered",
         }
     }
 }
 
+/// The different ways of delivering distributed fees.
+///
+/// `Push` sends the funds to the payment addresses on every distribution.
+/// `Pull` credits the payment addresses and lets them claim the funds later.
+#[cw_serde]
+pub enum DistributionModes {
+    Push,
+    Pull,
+}
+impl DistributionModes {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistributionModes::Push => "push",
+            DistributionModes::Pull => "pull",
+        }
+    }
+}
+
+/// The ways of handling the rounding remainder of percentage distributions.
+///
+/// `Keep` leaves the remainder in the fee module as dust.
+/// `FeeName` adds the remainder to the payment of the given fee name.
+/// `RoundRobin` spreads the remainder one by one, continuing from where the last distribution stopped.
+/// `Refund` sends the remainder back to the sender.
+#[cw_serde]
+pub enum RemainderPolicies {
+    Keep,
+    FeeName { fee_name: String },
+    RoundRobin,
+    Refund,
+}
+impl RemainderPolicies {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RemainderPolicies::Keep => "keep",
+            RemainderPolicies::FeeName { .. } => "fee_name",
+            RemainderPolicies::RoundRobin => "round_robin",
+            RemainderPolicies::Refund => "refund",
+        }
+    }
+}
+
+/// The holding conditions for the fee discounts.
+///
+/// `MinTokens` matches holding at least the amount of tokens from the collection.
+/// `Trait` matches holding any token from the collection with the trait.
+///
+/// Fee module checks at most 30 tokens of an address,
+/// so `MinTokens` amount cannot be higher than 30.
+#[cw_serde]
+pub enum DiscountConditions {
+    MinTokens { amount: u32 },
+    Trait { trait_type: String, value: String },
+}
+impl DiscountConditions {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscountConditions::MinTokens { .. } => "min_tokens",
+            DiscountConditions::Trait { .. } => "trait",
+        }
+    }
+}
+
+/// A discount rule for a fee.
+///
+/// Holders of the collection matching the condition
+/// get the value as a percentage reduction on the fee.
+#[cw_serde]
+pub struct DiscountRule {
+    pub collection_addr: String,
+    pub condition: DiscountConditions,
+    pub value: Decimal,
+}
+
 /// The different type of mint fees to be used in mint module.
 ///
 /// This is used for convinience when setting the fee configuration.
@@ -26,6 +102,8 @@ pub enum MintFees {
     Price,
     Whitelist,
     Royalty,
+    Revenue,
+    Creation,
 }
 impl MintFees {
     pub fn as_str(&self) -> &'static str {
@@ -33,6 +111,8 @@ impl MintFees {
             MintFees::Price => "price",
             MintFees::Whitelist => "whitelist",
             MintFees::Royalty => "royalty",
+            MintFees::Revenue => "revenue",
+            MintFees::Creation => "creation",
         }
     }
     pub fn new_price(collection_id: u32) -> String {
@@ -44,6 +124,32 @@ impl MintFees {
     pub fn new_royalty(collection_id: u32) -> String {
         format!("{}:{}", MintFees::Royalty.as_str(), collection_id)
     }
+    /// Module name of the percentage fees that split the mint revenue of a collection.
+    pub fn new_revenue(collection_id: u32) -> String {
+        format!("{}:{}", MintFees::Revenue.as_str(), collection_id)
+    }
+    /// Module name of the fixed fees paid for public collection creation.
+    pub fn new_creation() -> String {
+        MintFees::Creation.as_str().to_string()
+    }
+}
+
+/// The different type of merge fees to be used in merge module.
+///
+/// This is used for convenience when setting the fee configuration.
+#[cw_serde]
+pub enum MergeFees {
+    Price,
+}
+impl MergeFees {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeFees::Price => "price",
+        }
+    }
+    pub fn new_price(recipe_id: u32) -> String {
+        format!("{}:{}", MergeFees::Price.as_str(), recipe_id)
+    }
 }
 
 /// The different type of marketplace fees to be used in marketplace module.
@@ -75,6 +181,25 @@ pub struct PercentagePayment {
     pub address: Option<String>,
     /// Value is the percentage value.
     pub value: Decimal,
+    /// Group is a module name on the fee module used instead of the address.
+    /// Payments are split between the percentage fees of the group.
+    pub group: Option<String>,
+}
+
+/// A share of the mint revenue split of a collection.
+///
+/// Shares are saved as the percentage fees of the collection revenue module name.
+#[cw_serde]
+pub struct RevenueShare {
+    /// Name is used as the fee name of the share.
+    pub name: String,
+    /// Address is the payment address.
+    pub address: Option<String>,
+    /// Share is the part of the revenue paid for the share.
+    pub share: Decimal,
+    /// Group is a module name on the fee module used instead of the address.
+    /// Payments are split between the percentage fees of the group.
+    pub group: Option<String>,
 }
 
 /// The payment configuration for a fixed fee.
@@ -87,6 +212,76 @@ pub struct FixedPayment {
     pub address: Option<String>,
     /// Value is the integer value.
     pub value: Uint128,
+    /// Group is a module name on the fee module used instead of the address.
+    /// Payments are split between the percentage fees of the group.
+    pub group: Option<String>,
+}
+
+/// A single tier of a tiered fee.
+///
+/// The tier is used for sale amounts that are equal to or higher than `min_amount`.
+#[cw_serde]
+pub struct FeeTier {
+    pub min_amount: Uint128,
+    /// Value is the percentage value.
+    pub value: Decimal,
+}
+
+/// The payment configuration for a tiered fee.
+///
+/// This is saved to storage for a module and fee name.
+/// Tiers are sorted by `min_amount` and the first tier starts from zero.
+#[cw_serde]
+pub struct TieredPayment {
+    /// Address is the payment address.
+    /// If the address is empty, custom payment addresses are used for distribution.
+    pub address: Option<String>,
+    pub tiers: Vec<FeeTier>,
+    /// Lowest absolute fee amount.
+    pub min: Option<Uint128>,
+    /// Highest absolute fee amount.
+    pub max: Option<Uint128>,
+}
+impl TieredPayment {
+    /// Returns the tier used for the sale amount.
+    pub fn tier(&self, sale_amount: Uint128) -> Option<&FeeTier> {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_amount <= sale_amount)
+    }
+
+    /// Returns the fee amount for the sale amount with the caps applied.
+    pub fn amount(&self, sale_amount: Uint128) -> Uint128 {
+        let mut amount = match self.tier(sale_amount) {
+            Some(tier) => tier.value * sale_amount,
+            None => Uint128::zero(),
+        };
+        if let Some(min) = self.min {
+            amount = amount.max(min);
+        }
+        if let Some(max) = self.max {
+            amount = amount.min(max);
+        }
+        amount
+    }
+}
+
+/// A fee change waiting for its activation time.
+///
+/// Data is the new payment configuration of the fee type.
+/// If the data is empty, the fee is removed.
+#[cw_serde]
+pub struct PendingFee {
+    pub fee_type: Fees,
+    pub data: Option<Binary>,
+    pub activation_time: Timestamp,
+}
+impl PendingFee {
+    /// Returns true if the change is for the fee type and the activation time has passed.
+    pub fn is_due(&self, fee_type: &Fees, time: Timestamp) -> bool {
+        &self.fee_type == fee_type && self.activation_time <= time
+    }
 }
 
 /// The fund information for native and cw20 tokens.
@@ -99,6 +294,40 @@ pub struct FundInfo {
 
 pub const FIXED_FEES_NAMESPACE: &str = "fixed_fees";
 
+pub const FIXED_FEE_DENOMS_NAMESPACE: &str = "fixed_fee_denoms";
+
 pub const PERCENTAGE_FEES_NAMESPACE: &str = "percentage_fees";
 
+pub const TIERED_FEES_NAMESPACE: &str = "tiered_fees";
+
 pub const FUND_INFO_NAMESPACE: &str = "fund_info";
+
+pub const DISTRIBUTION_MODES_NAMESPACE: &str = "distribution_modes";
+
+pub const CLAIMABLE_BALANCES_NAMESPACE: &str = "claimable_balances";
+
+pub const REMAINDER_POLICIES_NAMESPACE: &str = "remainder_policies";
+
+pub const REMAINDER_CURSORS_NAMESPACE: &str = "remainder_cursors";
+
+pub const DUST_NAMESPACE: &str = "dust";
+
+pub const DISCOUNTS_NAMESPACE: &str = "discounts";
+
+pub const NOTICE_PERIODS_NAMESPACE: &str = "notice_periods";
+
+pub const PENDING_FEES_NAMESPACE: &str = "pending_fees";
+
+pub const PENDING_FIXED_FEE_DENOMS_NAMESPACE: &str = "pending_fixed_fee_denoms";
+
+pub const PENDING_NOTICE_PERIODS_NAMESPACE: &str = "pending_notice_periods";
+
+pub const FEE_REVENUES_NAMESPACE: &str = "fee_revenues";
+
+pub const DAILY_FEE_REVENUES_NAMESPACE: &str = "daily_fee_revenues";
+
+pub const RECIPIENT_REVENUES_NAMESPACE: &str = "recipient_revenues";
+
+pub const DAILY_RECIPIENT_REVENUES_NAMESPACE: &str = "daily_recipient_revenues";
+
+pub const EXEMPTIONS_NAMESPACE: &str = "exemptions";
@@ -0,0 +1,5 @@
+pub const RECIPES_NAMESPACE: &str = "recipes";
+
+pub const RECIPE_ID_NAMESPACE: &str = "recipe_id";
+
+pub const RECIPE_USES_NAMESPACE: &str = "recipe_uses";
@@ -40,3 +40,7 @@ pub const LINKED_METADATA_NAMESPACE: &str = "linked_metadata";
 pub const DYNAMIC_LINKED_METADATA_NAMESPACE: &str = "dynamic_linked_metadata";
 
 pub const COLLECTION_ADDR_NAMESPACE: &str = "collection_addr";
+
+pub const REVEAL_NAMESPACE: &str = "reveal";
+
+pub const REVEAL_PROGRESS_NAMESPACE: &str = "reveal_progress";
@@ -41,3 +41,45 @@ pub const COLLECTION_INFO_NAMESPACE: &str = "collection_info";
 pub const CREATORS_NAMESPACE: &str = "creators";
 
 pub const MINT_LOCKS_NAMESPACE: &str = "mint_locks";
+
+pub const MINT_PHASES_NAMESPACE: &str = "mint_phases";
+
+pub const PHASE_MINTS_NAMESPACE: &str = "phase_mints";
+
+pub const PHASE_ADDR_MINTS_NAMESPACE: &str = "phase_addr_mints";
+
+pub const MINT_BATCHES_NAMESPACE: &str = "mint_batches";
+
+pub const DUTCH_AUCTIONS_NAMESPACE: &str = "dutch_auctions";
+
+pub const DUTCH_AUCTION_CLEARING_PRICES_NAMESPACE: &str = "dutch_auction_clearing_prices";
+
+pub const DUTCH_AUCTION_MINTS_NAMESPACE: &str = "dutch_auction_mints";
+
+pub const DUTCH_AUCTION_UNSETTLED_NAMESPACE: &str = "dutch_auction_unsettled";
+
+pub const BONDING_CURVES_NAMESPACE: &str = "bonding_curves";
+
+pub const BONDING_CURVE_RESERVES_NAMESPACE: &str = "bonding_curve_reserves";
+
+pub const BONDING_CURVE_SUPPLIES_NAMESPACE: &str = "bonding_curve_supplies";
+
+pub const METADATA_SHUFFLES_NAMESPACE: &str = "metadata_shuffles";
+
+pub const SHUFFLED_MINTS_NAMESPACE: &str = "shuffled_mints";
+
+pub const METADATA_SHUFFLE_POOL_NAMESPACE: &str = "metadata_shuffle_pool";
+
+pub const VOUCHER_KEYS_NAMESPACE: &str = "voucher_keys";
+
+pub const VOUCHER_NONCES_NAMESPACE: &str = "voucher_nonces";
+
+pub const CREATOR_COLLECTIONS_NAMESPACE: &str = "creator_collections";
+
+pub const CREATOR_COLLECTION_LIMIT_NAMESPACE: &str = "creator_collection_limit";
+
+pub const CREATION_FEE_FUND_INFO_NAMESPACE: &str = "creation_fee_fund_info";
+
+pub const PENDING_COLLECTIONS_NAMESPACE: &str = "pending_collections";
+
+pub const ARCHIVED_COLLECTIONS_NAMESPACE: &str = "archived_collections";
@@ -4,6 +4,7 @@ use std::fmt;
 pub mod fee;
 pub mod hub;
 pub mod marketplace;
+pub mod merge;
 pub mod metadata;
 pub mod mint;
 pub mod permission;
@@ -27,4 +27,6 @@ pub const SUB_MODULES_NAMESPACE: &str = "sub_modules";
 
 pub const MINTED_TOKENS_PER_ADDR_NAMESPACE: &str = "minted_tokens_per_addr";
 
+pub const RESERVED_TOKEN_IDS_NAMESPACE: &str = "reserved_token_ids";
+
 pub const COLLECTION_TYPE_NAMESPACE: &str = "collection_type";
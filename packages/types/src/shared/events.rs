@@ -7,6 +7,13 @@ impl MergeEventAttributes {
         let value = format!("{}:{}", collection_id, token_id);
         Attribute::new("burn_ids", value)
     }
+    pub fn new_mint_id_attribute(collection_id: u32, metadata_id: Option<u32>) -> Attribute {
+        let value = match metadata_id {
+            Some(metadata_id) => format!("{}:{}", collection_id, metadata_id),
+            None => collection_id.to_string(),
+        };
+        Attribute::new("mint_ids", value)
+    }
 }
 
 /// Event attributes for the metadata module.
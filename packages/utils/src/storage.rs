@@ -1,15 +1,21 @@
-use cosmwasm_std::{from_slice, Addr, Empty, QuerierWrapper, StdError, StdResult};
+use cosmwasm_std::{
+    from_binary, from_slice, Addr, Binary, Empty, QuerierWrapper, StdError, StdResult, Timestamp,
+};
 use cw721_base::state::TokenInfo;
 use cw_storage_plus::Path;
 use komple_framework_types::modules::fee::{
-    FixedPayment, PercentagePayment, FIXED_FEES_NAMESPACE, PERCENTAGE_FEES_NAMESPACE,
+    Fees, FixedPayment, PendingFee, PercentagePayment, FIXED_FEES_NAMESPACE,
+    PENDING_FEES_NAMESPACE, PERCENTAGE_FEES_NAMESPACE,
+};
+use komple_framework_types::modules::metadata::{
+    METADATA_ID_NAMESPACE, METADATA_NAMESPACE, REVEAL_NAMESPACE,
 };
 use komple_framework_types::modules::mint::{
     COLLECTION_ADDRS_NAMESPACE, LINKED_COLLECTIONS_NAMESPACE,
 };
 use komple_framework_types::modules::token::{
     Locks, SubModules, LOCKS_NAMESPACE, SUB_MODULES_NAMESPACE, TOKENS_NAMESPACE,
-    TOKEN_LOCKS_NAMESPACE,
+    TOKEN_IDS_NAMESPACE, TOKEN_LOCKS_NAMESPACE,
 };
 use komple_framework_types::modules::MODULES_NAMESPACE;
 use schemars::JsonSchema;
@@ -19,6 +25,12 @@ use std::{ops::Deref, str::from_utf8};
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct StorageHelper();
 
+// Part of the metadata module reveal used to mask the raw metadata
+#[derive(Deserialize)]
+struct RevealState {
+    metadata_root: Option<String>,
+}
+
 impl StorageHelper {
     // namespace -> storage key
     // key_name -> item key
@@ -145,17 +157,32 @@ impl StorageHelper {
         }
     }
 
+    // Due pending changes of the fee take precedence over the saved fee
+    // as they are only saved on the next distribution of the fee module
     pub fn query_fixed_fee(
         querier: &QuerierWrapper,
         fee_module_addr: &Addr,
         module_name: String,
         fee_name: String,
+        block_time: Timestamp,
     ) -> StdResult<FixedPayment> {
-        let key = Self::get_map_storage_key(
-            FIXED_FEES_NAMESPACE,
-            &[module_name.as_bytes(), fee_name.as_bytes()],
-        )?;
-        let res = Self::query_storage::<FixedPayment>(querier, fee_module_addr, &key)?;
+        let res = match Self::query_due_pending_fee(
+            querier,
+            fee_module_addr,
+            &Fees::Fixed,
+            &module_name,
+            &fee_name,
+            block_time,
+        )? {
+            Some(data) => data.map(|data| from_binary(&data)).transpose()?,
+            None => {
+                let key = Self::get_map_storage_key(
+                    FIXED_FEES_NAMESPACE,
+                    &[module_name.as_bytes(), fee_name.as_bytes()],
+                )?;
+                Self::query_storage::<FixedPayment>(querier, fee_module_addr, &key)?
+            }
+        };
         match res {
             Some(res) => Ok(res),
             None => Err(StdError::NotFound {
@@ -169,12 +196,25 @@ impl StorageHelper {
         fee_module_addr: &Addr,
         module_name: String,
         fee_name: String,
+        block_time: Timestamp,
     ) -> StdResult<PercentagePayment> {
-        let key = Self::get_map_storage_key(
-            PERCENTAGE_FEES_NAMESPACE,
-            &[module_name.as_bytes(), fee_name.as_bytes()],
-        )?;
-        let res = Self::query_storage::<PercentagePayment>(querier, fee_module_addr, &key)?;
+        let res = match Self::query_due_pending_fee(
+            querier,
+            fee_module_addr,
+            &Fees::Percentage,
+            &module_name,
+            &fee_name,
+            block_time,
+        )? {
+            Some(data) => data.map(|data| from_binary(&data)).transpose()?,
+            None => {
+                let key = Self::get_map_storage_key(
+                    PERCENTAGE_FEES_NAMESPACE,
+                    &[module_name.as_bytes(), fee_name.as_bytes()],
+                )?;
+                Self::query_storage::<PercentagePayment>(querier, fee_module_addr, &key)?
+            }
+        };
         match res {
             Some(res) => Ok(res),
             None => Err(StdError::NotFound {
@@ -183,6 +223,33 @@ impl StorageHelper {
         }
     }
 
+    // Returns the data of the pending fee change if it is due
+    // Empty data means the fee is removed
+    fn query_due_pending_fee(
+        querier: &QuerierWrapper,
+        fee_module_addr: &Addr,
+        fee_type: &Fees,
+        module_name: &str,
+        fee_name: &str,
+        block_time: Timestamp,
+    ) -> StdResult<Option<Option<Binary>>> {
+        let key = Self::get_map_storage_key(
+            PENDING_FEES_NAMESPACE,
+            &[
+                module_name.as_bytes(),
+                fee_type.as_str().as_bytes(),
+                fee_name.as_bytes(),
+            ],
+        )?;
+        let res = Self::query_storage::<PendingFee>(querier, fee_module_addr, &key)?;
+        match res {
+            Some(pending_fee) if pending_fee.is_due(fee_type, block_time) => {
+                Ok(Some(pending_fee.data))
+            }
+            _ => Ok(None),
+        }
+    }
+
     pub fn query_token_sub_modules(
         querier: &QuerierWrapper,
         token_module_addr: &Addr,
@@ -196,4 +263,42 @@ impl StorageHelper {
             }),
         }
     }
+
+    pub fn query_token_ids(querier: &QuerierWrapper, token_module_addr: &Addr) -> StdResult<u32> {
+        let res = Self::query_storage::<u32>(querier, token_module_addr, TOKEN_IDS_NAMESPACE)?;
+        match res {
+            Some(res) => Ok(res),
+            None => Err(StdError::NotFound {
+                kind: "Token ids".to_string(),
+            }),
+        }
+    }
+
+    pub fn query_metadata_id(
+        querier: &QuerierWrapper,
+        metadata_module_addr: &Addr,
+    ) -> StdResult<u32> {
+        let res = Self::query_storage::<u32>(querier, metadata_module_addr, METADATA_ID_NAMESPACE)?;
+        Ok(res.unwrap_or(0))
+    }
+
+    // Metadata ids are not valid utf8 after 127
+    // so the key bytes are used directly instead of get_map_storage_key
+    // Raw metadata is not returned until the metadata is revealed
+    pub fn query_raw_metadata_bytes(
+        querier: &QuerierWrapper,
+        metadata_module_addr: &Addr,
+        metadata_id: u32,
+    ) -> StdResult<Option<Binary>> {
+        let reveal =
+            Self::query_storage::<RevealState>(querier, metadata_module_addr, REVEAL_NAMESPACE)?;
+        if matches!(reveal, Some(reveal) if reveal.metadata_root.is_none()) {
+            return Ok(None);
+        }
+
+        let path: Path<Vec<u32>> =
+            Path::new(METADATA_NAMESPACE.as_bytes(), &[&metadata_id.to_be_bytes()]);
+        let data = querier.query_wasm_raw(metadata_module_addr, path.deref())?;
+        Ok(data.map(Binary::from))
+    }
 }